@@ -8,7 +8,7 @@ use halo2::plonk::Error;
 use integer::halo2::circuit::Value;
 use integer::maingate::{MainGateInstructions, RegionCtx};
 use integer::{IntegerInstructions, Range};
-use maingate::{AssignedCondition, MainGate};
+use maingate::{AssignedCondition, AssignedValue, MainGate, PublicInputChip};
 use std::collections::BTreeMap;
 use std::rc::Rc;
 
@@ -127,6 +127,49 @@ impl<C: CurveAffine, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         Ok(())
     }
 
+    /// Expose `AssignedPoint` as Public Input where limbs of both coordinates
+    /// are packed together into as few native field elements as possible. Use
+    /// `Point::packed` to build the matching public inputs off-circuit.
+    pub fn expose_public_packed(
+        &self,
+        mut layouter: impl Layouter<C::Scalar>,
+        point: &AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        offset: usize,
+    ) -> Result<(), Error> {
+        let packed = layouter.assign_region(
+            || "pack point",
+            |region| {
+                let ctx = &mut RegionCtx::new(region, 0);
+                self.integer_chip().pack(ctx, &[point.x(), point.y()])
+            },
+        )?;
+        let main_gate = self.main_gate();
+        for (i, packed) in packed.into_iter().enumerate() {
+            main_gate.expose_public(layouter.namespace(|| "packed coords"), packed, offset + i)?;
+        }
+        Ok(())
+    }
+
+    /// Expose `AssignedPoint` as Public Input to the named slot of the public
+    /// input layout. Slot is expected to be allocated for limbs of `x` and `y`
+    /// coordinates as in `Point::public`
+    pub fn expose_public_slot(
+        &self,
+        layouter: impl Layouter<C::Scalar>,
+        public_input_chip: &PublicInputChip<C::Scalar>,
+        name: &str,
+        point: &AssignedPoint<C::Base, C::Scalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let limbs: Vec<AssignedValue<C::Scalar>> = point
+            .x()
+            .limbs()
+            .iter()
+            .chain(point.y().limbs().iter())
+            .map(|limb| limb.into())
+            .collect();
+        public_input_chip.expose(layouter, name, &limbs)
+    }
+
     /// Takes `Point` and assign its coordiantes as constant
     /// Returned as `AssignedPoint`
     pub fn assign_constant(
@@ -355,10 +398,10 @@ mod tests {
     use integer::halo2::group::Curve;
     use integer::halo2::group::Group;
     use integer::maingate::RegionCtx;
-    use maingate::mock_prover_verify;
+    use maingate::{mock_prover_verify, mock_prover_verify_fails};
     use maingate::{
-        AssignedValue, MainGate, MainGateConfig, MainGateInstructions, RangeChip, RangeConfig,
-        RangeInstructions,
+        AssignedValue, MainGate, MainGateConfig, MainGateInstructions, PublicInputChip,
+        PublicInputConfig, PublicInputLayout, RangeChip, RangeConfig, RangeInstructions,
     };
     use paste::paste;
     use rand_core::OsRng;
@@ -617,6 +660,210 @@ mod tests {
         run::<Vesta>();
     }
 
+    #[derive(Clone, Debug)]
+    struct TestPublicInputConfig {
+        circuit_config: TestCircuitConfig,
+        public_input_config: PublicInputConfig,
+    }
+
+    fn public_input_layout() -> PublicInputLayout {
+        let mut layout = PublicInputLayout::new(2);
+        layout
+            .point("sum", 1, NUMBER_OF_LIMBS)
+            .point("double", 0, NUMBER_OF_LIMBS);
+        layout
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccPublicInputSlot<C: CurveAffine> {
+        a: Value<C>,
+        b: Value<C>,
+    }
+
+    impl<C: CurveAffine> Circuit<C::Scalar> for TestEccPublicInputSlot<C> {
+        type Config = TestPublicInputConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::Scalar>) -> Self::Config {
+            let circuit_config = TestCircuitConfig::new::<C>(meta);
+            let public_input_config =
+                PublicInputChip::configure(meta, &circuit_config.main_gate_config, 2);
+            TestPublicInputConfig {
+                circuit_config,
+                public_input_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<C::Scalar>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.circuit_config.ecc_chip_config();
+            let ecc_chip =
+                BaseFieldEccChip::<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let public_input_chip =
+                PublicInputChip::new(config.public_input_config, public_input_layout());
+
+            let (sum, double) = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = ecc_chip.assign_point(ctx, self.a)?;
+                    let b = ecc_chip.assign_point(ctx, self.b)?;
+                    let sum = ecc_chip.add(ctx, &a, &b)?;
+                    let sum = ecc_chip.normalize(ctx, &sum)?;
+                    let double = ecc_chip.double(ctx, &a)?;
+                    let double = ecc_chip.normalize(ctx, &double)?;
+                    Ok((sum, double))
+                },
+            )?;
+            ecc_chip.expose_public_slot(
+                layouter.namespace(|| "sum"),
+                &public_input_chip,
+                "sum",
+                &sum,
+            )?;
+            ecc_chip.expose_public_slot(
+                layouter.namespace(|| "double"),
+                &public_input_chip,
+                "double",
+                &double,
+            )?;
+
+            config.circuit_config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_base_field_ecc_public_input_slot() {
+        fn run<C: CurveAffine>()
+        where
+            C::Scalar: FromUniformBytes<64>,
+        {
+            let (rns, _) = setup::<C>(20);
+            let rns = Rc::new(rns);
+
+            let a = <C as CurveAffine>::CurveExt::random(OsRng).to_affine();
+            let b = <C as CurveAffine>::CurveExt::random(OsRng).to_affine();
+
+            let sum = Point::new(Rc::clone(&rns), (a + b).to_affine());
+            let double = Point::new(Rc::clone(&rns), (a + a).to_affine());
+
+            let layout = public_input_layout();
+            let circuit = TestEccPublicInputSlot {
+                a: Value::known(a),
+                b: Value::known(b),
+            };
+
+            let instance = layout
+                .public_inputs()
+                .set("sum", &sum.public())
+                .set("double", &double.public())
+                .instances();
+            mock_prover_verify(&circuit, instance);
+
+            let instance = layout
+                .public_inputs()
+                .set("sum", &double.public())
+                .set("double", &sum.public())
+                .instances();
+            mock_prover_verify_fails(&circuit, instance);
+        }
+
+        run::<Bn256>();
+        run::<Pallas>();
+        run::<Vesta>();
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccPublicInputPacked<C: CurveAffine> {
+        a: Value<C>,
+        b: Value<C>,
+    }
+
+    impl<C: CurveAffine> Circuit<C::Scalar> for TestEccPublicInputPacked<C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::Scalar>) -> Self::Config {
+            TestCircuitConfig::new::<C>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<C::Scalar>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                BaseFieldEccChip::<C, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            let (a, sum) = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = ecc_chip.assign_point(ctx, self.a)?;
+                    let b = ecc_chip.assign_point(ctx, self.b)?;
+                    // sum is left unreduced
+                    let c = ecc_chip.add(ctx, &a, &b)?;
+                    Ok((a, c))
+                },
+            )?;
+            let number_of_packed = ecc_chip.rns().packing(2).len();
+            ecc_chip.expose_public_packed(layouter.namespace(|| "a"), &a, 0)?;
+            ecc_chip.expose_public_packed(layouter.namespace(|| "sum"), &sum, number_of_packed)?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_base_field_ecc_public_input_packed() {
+        fn run<C: CurveAffine>()
+        where
+            C::Scalar: FromUniformBytes<64>,
+        {
+            let (rns, _) = setup::<C>(20);
+            let rns = Rc::new(rns);
+
+            let a = <C as CurveAffine>::CurveExt::random(OsRng).to_affine();
+            let b = <C as CurveAffine>::CurveExt::random(OsRng).to_affine();
+
+            let c: C = (a + b).to_affine();
+            let mut public_data = Point::new(Rc::clone(&rns), a).packed();
+            public_data.extend(Point::new(Rc::clone(&rns), c).packed());
+            assert!(public_data.len() < 4 * NUMBER_OF_LIMBS);
+
+            let circuit = TestEccPublicInputPacked {
+                a: Value::known(a),
+                b: Value::known(b),
+            };
+            let instance = vec![public_data];
+            mock_prover_verify(&circuit, instance);
+        }
+
+        run::<Bn256>();
+        run::<Pallas>();
+        run::<Vesta>();
+    }
+
     #[derive(Default, Clone, Debug)]
     struct TestEccMul<C: CurveAffine> {
         window_size: usize,
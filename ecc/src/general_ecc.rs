@@ -8,7 +8,7 @@ use halo2::circuit::{Layouter, Value};
 use halo2::plonk::Error;
 use integer::halo2::ff::PrimeField;
 use integer::maingate::RegionCtx;
-use maingate::{AssignedCondition, MainGate};
+use maingate::{AssignedCondition, AssignedValue, MainGate, PublicInputChip};
 use std::collections::BTreeMap;
 use std::rc::Rc;
 
@@ -186,6 +186,50 @@ impl<
         Ok(())
     }
 
+    /// Expose `AssignedPoint` as Public Input where limbs of both coordinates
+    /// are packed together into as few native field elements as possible. Use
+    /// `Point::packed` to build the matching public inputs off-circuit.
+    pub fn expose_public_packed(
+        &self,
+        mut layouter: impl Layouter<N>,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        offset: usize,
+    ) -> Result<(), Error> {
+        use integer::maingate::MainGateInstructions;
+        let packed = layouter.assign_region(
+            || "pack point",
+            |region| {
+                let ctx = &mut RegionCtx::new(region, 0);
+                self.base_field_chip().pack(ctx, &[point.x(), point.y()])
+            },
+        )?;
+        let main_gate = self.main_gate();
+        for (i, packed) in packed.into_iter().enumerate() {
+            main_gate.expose_public(layouter.namespace(|| "packed coords"), packed, offset + i)?;
+        }
+        Ok(())
+    }
+
+    /// Expose `AssignedPoint` as Public Input to the named slot of the public
+    /// input layout. Slot is expected to be allocated for limbs of `x` and `y`
+    /// coordinates as in `Point::public`
+    pub fn expose_public_slot(
+        &self,
+        layouter: impl Layouter<N>,
+        public_input_chip: &PublicInputChip<N>,
+        name: &str,
+        point: &AssignedPoint<Emulated::Base, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let limbs: Vec<AssignedValue<N>> = point
+            .x()
+            .limbs()
+            .iter()
+            .chain(point.y().limbs().iter())
+            .map(|limb| limb.into())
+            .collect();
+        public_input_chip.expose(layouter, name, &limbs)
+    }
+
     /// Takes `Point` and assign its coordiantes as constant
     /// Returned as `AssignedPoint`
     pub fn assign_constant(
@@ -408,9 +452,10 @@ mod tests {
     use integer::halo2::group::{Curve, Group};
     use integer::rns::Integer;
     use integer::Range;
-    use maingate::mock_prover_verify;
+    use maingate::{mock_prover_verify, mock_prover_verify_fails};
     use maingate::{
-        MainGate, MainGateConfig, RangeChip, RangeConfig, RangeInstructions, RegionCtx,
+        MainGate, MainGateConfig, PublicInputChip, PublicInputConfig, PublicInputLayout, RangeChip,
+        RangeConfig, RangeInstructions, RegionCtx,
     };
     use paste::paste;
     use rand_core::OsRng;
@@ -741,6 +786,250 @@ mod tests {
         run::<Secp256k1, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
     }
 
+    #[derive(Default, Clone, Debug)]
+    struct TestEccPublicInputPacked<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        a: Value<C>,
+        b: Value<C>,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccPublicInputPacked<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+
+            let (a, sum) = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = ecc_chip.assign_point(ctx, self.a)?;
+                    let b = ecc_chip.assign_point(ctx, self.b)?;
+                    // sum is left unreduced
+                    let c = ecc_chip.add(ctx, &a, &b)?;
+                    Ok((a, c))
+                },
+            )?;
+            let number_of_packed = ecc_chip.rns_base().packing(2).len();
+            ecc_chip.expose_public_packed(layouter.namespace(|| "a"), &a, 0)?;
+            ecc_chip.expose_public_packed(layouter.namespace(|| "sum"), &sum, number_of_packed)?;
+
+            config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_public_input_packed() {
+        fn run<
+            C: CurveAffine,
+            N: WithSmallOrderMulGroup<3> + FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let (rns_base, _, _) = setup::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(0);
+            let rns_base = Rc::new(rns_base);
+
+            let a = C::Curve::random(OsRng).to_affine();
+            let b = C::Curve::random(OsRng).to_affine();
+
+            let c: C = (a + b).into();
+            let mut public_data = Point::new(Rc::clone(&rns_base), a).packed();
+            public_data.extend(Point::new(Rc::clone(&rns_base), c).packed());
+
+            let circuit = TestEccPublicInputPacked::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                a: Value::known(a),
+                b: Value::known(b),
+                ..Default::default()
+            };
+            let instance = vec![public_data.clone()];
+            mock_prover_verify(&circuit, instance);
+
+            public_data[0] += N::ONE;
+            let instance = vec![public_data];
+            mock_prover_verify_fails(&circuit, instance);
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestPublicInputConfig {
+        circuit_config: TestCircuitConfig,
+        public_input_config: PublicInputConfig,
+    }
+
+    fn public_input_layout<const NUMBER_OF_LIMBS: usize>() -> PublicInputLayout {
+        let mut layout = PublicInputLayout::new(2);
+        layout
+            .point("sum", 1, NUMBER_OF_LIMBS)
+            .point("double", 0, NUMBER_OF_LIMBS);
+        layout
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestEccPublicInputSlot<
+        C: CurveAffine,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        a: Value<C>,
+        b: Value<C>,
+        _marker: PhantomData<N>,
+    }
+
+    impl<
+            C: CurveAffine,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Circuit<N> for TestEccPublicInputSlot<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestPublicInputConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            let circuit_config =
+                TestCircuitConfig::new::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta);
+            let public_input_config =
+                PublicInputChip::configure(meta, &circuit_config.main_gate_config, 2);
+            TestPublicInputConfig {
+                circuit_config,
+                public_input_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let ecc_chip_config = config.circuit_config.ecc_chip_config();
+            let ecc_chip =
+                GeneralEccChip::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(ecc_chip_config);
+            let public_input_chip = PublicInputChip::new(
+                config.public_input_config,
+                public_input_layout::<NUMBER_OF_LIMBS>(),
+            );
+
+            let (sum, double) = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = ecc_chip.assign_point(ctx, self.a)?;
+                    let b = ecc_chip.assign_point(ctx, self.b)?;
+                    let sum = ecc_chip.add(ctx, &a, &b)?;
+                    let sum = ecc_chip.normalize(ctx, &sum)?;
+                    let double = ecc_chip.double(ctx, &a)?;
+                    let double = ecc_chip.normalize(ctx, &double)?;
+                    Ok((sum, double))
+                },
+            )?;
+            ecc_chip.expose_public_slot(
+                layouter.namespace(|| "sum"),
+                &public_input_chip,
+                "sum",
+                &sum,
+            )?;
+            ecc_chip.expose_public_slot(
+                layouter.namespace(|| "double"),
+                &public_input_chip,
+                "double",
+                &double,
+            )?;
+
+            config.circuit_config.config_range(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_general_ecc_public_input_slot() {
+        fn run<
+            C: CurveAffine,
+            N: WithSmallOrderMulGroup<3> + FromUniformBytes<64> + Ord,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let (rns_base, _, _) = setup::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(0);
+            let rns_base = Rc::new(rns_base);
+
+            let a = C::Curve::random(OsRng).to_affine();
+            let b = C::Curve::random(OsRng).to_affine();
+
+            let sum: C = (a + b).into();
+            let sum = Point::new(Rc::clone(&rns_base), sum);
+            let double: C = (a + a).into();
+            let double = Point::new(Rc::clone(&rns_base), double);
+
+            let layout = public_input_layout::<NUMBER_OF_LIMBS>();
+            let circuit = TestEccPublicInputSlot::<C, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+                a: Value::known(a),
+                b: Value::known(b),
+                ..Default::default()
+            };
+
+            let instance = layout
+                .public_inputs()
+                .set("sum", &sum.public())
+                .set("double", &double.public())
+                .instances();
+            mock_prover_verify(&circuit, instance);
+
+            let instance = layout
+                .public_inputs()
+                .set("sum", &double.public())
+                .set("double", &sum.public())
+                .instances();
+            mock_prover_verify_fails(&circuit, instance);
+        }
+
+        run::<Pallas, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Vesta, PastaFq, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Bn256, PastaFp, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+        run::<Secp256k1, BnScalar, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+    }
+
     #[derive(Default, Clone, Debug)]
     struct TestEccMul<
         C: CurveAffine,
@@ -10,6 +10,11 @@ pub use general_ecc::*;
 pub mod base_field_ecc;
 /// Constaints for any SW curve
 pub mod general_ecc;
+/// Constraints for the optimal ate pairing over BN254
+pub mod pairing;
+/// Serialization of points with `serde`
+#[cfg(feature = "serde")]
+pub mod serialization;
 
 pub use integer;
 pub use integer::halo2;
@@ -68,6 +73,12 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         public_data
     }
 
+    /// Returns limbs of $x$ and $y$ coordinates packed together into native
+    /// field elements. Matches `expose_public_packed` of the ecc chips
+    pub fn packed(&self) -> Vec<N> {
+        Integer::pack(&[&self.x, &self.y])
+    }
+
     /// Returns $x$ coordinate
     pub fn x(&self) -> &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
         &self.x
@@ -0,0 +1,1005 @@
+use super::{AssignedPoint, EccConfig};
+use crate::halo2;
+use crate::integer::extension::{AssignedFp12, AssignedFp2, Fp12Chip, Fp2Chip, Fp6Chip};
+use crate::integer::rns::{Integer, Rns};
+use crate::integer::{IntegerChip, IntegerInstructions, Range};
+use crate::maingate::{modulus, RegionCtx};
+use halo2::arithmetic::CurveAffine;
+use halo2::circuit::Value;
+use halo2::halo2curves::bn256::{Fq, Fq2, G1Affine, G2Affine};
+use halo2::plonk::Error;
+use integer::halo2::ff::{Field, PrimeField};
+use num_bigint::BigUint as big_uint;
+use std::rc::Rc;
+
+/// BN254 curve parameter `u`
+const BN_X: u64 = 4965661367192848881;
+
+/// Returns signed digits of the Miller loop length `6u + 2` in non adjacent
+/// form, least significant first
+fn six_u_plus_2_naf() -> Vec<i8> {
+    let mut e = 6 * BN_X as u128 + 2;
+    let mut naf = vec![];
+    while e > 0 {
+        if e & 1 == 1 {
+            let digit = 2 - (e & 3) as i8;
+            if digit == 1 {
+                e -= 1;
+            } else {
+                e += 1;
+            }
+            naf.push(digit);
+        } else {
+            naf.push(0);
+        }
+        e >>= 1;
+    }
+    naf
+}
+
+fn to_pair(e: Fq2) -> (Fq, Fq) {
+    (e.c0, e.c1)
+}
+
+/// Point of the BN254 twist over the quadratic extension that is expected to
+/// be in G2 and not infinity
+#[derive(Debug, Clone)]
+pub struct AssignedG2Point<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> {
+    x: AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    y: AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns a new `AssignedG2Point` given its coordinates. Does not check
+    /// if the point is on the curve
+    pub fn new(
+        x: AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        y: AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Self {
+        AssignedG2Point { x, y }
+    }
+
+    /// Returns $x$ coordinate
+    pub fn x(&self) -> &AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.x
+    }
+
+    /// Returns $y$ coordinate
+    pub fn y(&self) -> &AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.y
+    }
+}
+
+/// Constraints for the optimal ate pairing over BN254. Extension tower is the
+/// one of `halo2curves`, `Fq2 = Fq[u] / (u^2 + 1)`,
+/// `Fq6 = Fq2[v] / (v^3 - (9 + u))` and `Fq12 = Fq6[w] / (w^2 - v)`.
+///
+/// G1 points are range checked and constrained to be on the curve which is
+/// enough since G1 has cofactor one. G2 points are constrained to be on the
+/// twist and in the subgroup of order `r`.
+#[derive(Debug, Clone)]
+pub struct PairingChip<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> {
+    fp12_chip: Fp12Chip<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    // Coefficients of Frobenius endomorphism on the twist
+    // `xi^((p - 1) / 3)`, `xi^((p - 1) / 2)`, `xi^((p^2 - 1) / 3)`,
+    // `xi^((p^2 - 1) / 2)`
+    twist_frobenius_coeffs: [(Fq, Fq); 4],
+}
+
+impl<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    PairingChip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Residue numeral system
+    /// Used to emulate the base field of BN254 over the native field `N`
+    pub fn rns() -> Rns<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        Rns::construct()
+    }
+
+    /// Return `PairingChip` from `EccConfig`
+    pub fn new(config: EccConfig) -> Self {
+        let integer_chip = IntegerChip::new(config.integer_chip_config(), Rc::new(Self::rns()));
+        let fp2_chip = Fp2Chip::new(integer_chip, -Fq::ONE);
+        let xi = Fq2 {
+            c0: Fq::from(9),
+            c1: Fq::ONE,
+        };
+        let fp6_chip = Fp6Chip::new(fp2_chip, to_pair(xi));
+        let fp12_chip = Fp12Chip::new(fp6_chip);
+
+        let p = modulus::<Fq>();
+        let p2 = &p * &p;
+        let coeff = |exponent: big_uint| to_pair(xi.pow_vartime(exponent.to_u64_digits()));
+        let twist_frobenius_coeffs = [
+            coeff((&p - 1usize) / 3usize),
+            coeff((&p - 1usize) / 2usize),
+            coeff((&p2 - 1usize) / 3usize),
+            coeff((&p2 - 1usize) / 2usize),
+        ];
+
+        Self {
+            fp12_chip,
+            twist_frobenius_coeffs,
+        }
+    }
+
+    /// Return `Fp12Chip` of the extension tower
+    pub fn fp12_chip(&self) -> &Fp12Chip<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.fp12_chip
+    }
+
+    /// Return `Fp2Chip` of the extension tower
+    pub fn fp2_chip(&self) -> &Fp2Chip<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        self.fp12_chip.fp6_chip().fp2_chip()
+    }
+
+    /// Return `IntegerChip` for the base field
+    pub fn integer_chip(&self) -> &IntegerChip<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        self.fp2_chip().integer_chip()
+    }
+
+    /// Takes a G1 point and returns it as `AssignedPoint` constrained to be on
+    /// the curve
+    pub fn assign_g1(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: Value<G1Affine>,
+    ) -> Result<AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.integer_chip();
+        let rns = integer_chip.rns();
+        let (x, y) = point
+            .map(|point| {
+                // disallow point of infinity
+                let coords = point.coordinates().unwrap();
+                (*coords.x(), *coords.y())
+            })
+            .unzip();
+        let x = x.map(|x| Integer::from_fe(x, Rc::clone(&rns)));
+        let y = y.map(|y| Integer::from_fe(y, Rc::clone(&rns)));
+        let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+        let y = integer_chip.assign_integer(ctx, y.into(), Range::Remainder)?;
+
+        // y^2 = x^3 + b
+        let b = Integer::from_fe(G1Affine::b(), rns);
+        let y_square = integer_chip.square(ctx, &y)?;
+        let x_square = integer_chip.square(ctx, &x)?;
+        let x_cube = integer_chip.mul(ctx, &x, &x_square)?;
+        let x_cube_b = integer_chip.add_constant(ctx, &x_cube, &b)?;
+        integer_chip.assert_equal(ctx, &x_cube_b, &y_square)?;
+
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Takes a G2 point and returns it as `AssignedG2Point` constrained to be
+    /// on the twist and in G2
+    pub fn assign_g2(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        point: Value<G2Affine>,
+    ) -> Result<AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp2_chip = self.fp2_chip();
+        let (x, y) = point
+            .map(|point| {
+                // disallow point of infinity
+                let coords = point.coordinates().unwrap();
+                (to_pair(*coords.x()), to_pair(*coords.y()))
+            })
+            .unzip();
+        let x = fp2_chip.assign_fp2(ctx, x, Range::Remainder)?;
+        let y = fp2_chip.assign_fp2(ctx, y, Range::Remainder)?;
+
+        // y^2 = x^3 + b
+        let b = fp2_chip.assign_constant(ctx, to_pair(G2Affine::b()))?;
+        let y_square = fp2_chip.square(ctx, &y)?;
+        let x_square = fp2_chip.square(ctx, &x)?;
+        let x_cube = fp2_chip.mul(ctx, &x, &x_square)?;
+        let x_cube_b = fp2_chip.add(ctx, &x_cube, &b)?;
+        fp2_chip.assert_equal(ctx, &x_cube_b, &y_square)?;
+
+        let point = AssignedG2Point::new(x, y);
+        self.assert_in_subgroup(ctx, &point)?;
+        Ok(point)
+    }
+
+    /// Constrains a point on the twist to be in G2 with the endomorphism
+    /// check `psi(q) = [6u^2] q` of El Housni, Guillevic and Piellard
+    /// "Co-factor clearing and subgroup membership testing on pairing-friendly
+    /// curves"
+    pub fn assert_in_subgroup(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        q: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let fp2_chip = self.fp2_chip();
+        let scalar = 6 * BN_X as u128 * BN_X as u128;
+        let bit_len = 128 - scalar.leading_zeros() as usize;
+        let mut acc = q.clone();
+        for i in (0..bit_len - 1).rev() {
+            acc = self.double(ctx, &acc)?;
+            if (scalar >> i) & 1 == 1 {
+                acc = self.add_unequal(ctx, &acc, q)?;
+            }
+        }
+        let expected = self.psi(ctx, q)?;
+        fp2_chip.assert_equal(ctx, &acc.x, &expected.x)?;
+        fp2_chip.assert_equal(ctx, &acc.y, &expected.y)
+    }
+
+    // Returns `psi(q)`, untwisted Frobenius endomorphism brought back to the
+    // twist
+    fn psi(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        q: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp2_chip = self.fp2_chip();
+        let [x1, y1, _, _] = self.twist_frobenius_coeffs;
+        let x = fp2_chip.conjugate(ctx, &q.x)?;
+        let x = fp2_chip.mul_constant(ctx, &x, x1)?;
+        let y = fp2_chip.conjugate(ctx, &q.y)?;
+        let y = fp2_chip.mul_constant(ctx, &y, y1)?;
+        Ok(AssignedG2Point::new(x, y))
+    }
+
+    // Returns `2 * t`. Denominator `2 * t_y` is not zero since the twist has
+    // no points of order two
+    fn double(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        t: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp2_chip = self.fp2_chip();
+        let numerator = fp2_chip.square(ctx, &t.x)?;
+        let numerator = fp2_chip.mul_by_constant_base(ctx, &numerator, Fq::from(3))?;
+        let denominator = fp2_chip.double(ctx, &t.y)?;
+        let lambda = fp2_chip.div_incomplete(ctx, &numerator, &denominator)?;
+        self.add_with_slope(ctx, &lambda, t, &t.x)
+    }
+
+    // Returns `t + q` where `t_x - q_x` is constrained to be non zero. Points
+    // are not required to be in G2 so the slope must not be left free when
+    // `t = q` or `t = -q`
+    fn add_unequal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        t: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        q: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.integer_chip();
+        let fp2_chip = self.fp2_chip();
+        let numerator = fp2_chip.sub(ctx, &t.y, &q.y)?;
+        let denominator = fp2_chip.sub(ctx, &t.x, &q.x)?;
+        // Norm `c0^2 + c1^2` vanishes only at zero since `-1` is a non residue
+        let c0_square = integer_chip.square(ctx, denominator.c0())?;
+        let c1_square = integer_chip.square(ctx, denominator.c1())?;
+        let norm = integer_chip.add(ctx, &c0_square, &c1_square)?;
+        integer_chip.assert_not_zero(ctx, &norm)?;
+        let lambda = fp2_chip.div_incomplete(ctx, &numerator, &denominator)?;
+        self.add_with_slope(ctx, &lambda, t, &q.x)
+    }
+
+    // Returns `t + q` given the slope of the line through them
+    fn add_with_slope(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        lambda: &AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        t: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        q_x: &AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp2_chip = self.fp2_chip();
+        // x = lambda^2 - t_x - q_x
+        let x = fp2_chip.square(ctx, lambda)?;
+        let x = fp2_chip.sub(ctx, &x, &t.x)?;
+        let x = fp2_chip.sub(ctx, &x, q_x)?;
+        // y = lambda * (t_x - x) - t_y
+        let y = fp2_chip.sub(ctx, &t.x, &x)?;
+        let y = fp2_chip.mul(ctx, lambda, &y)?;
+        let y = fp2_chip.sub(ctx, &y, &t.y)?;
+        Ok(AssignedG2Point::new(x, y))
+    }
+
+    // Evaluates the line with the given slope through `t` at the G1 point
+    // which is prepared as `(-p_x / p_y, 1 / p_y)`. Untwisted line
+    // `p_y - lambda * p_x * w + (lambda * t_x - t_y) * w^3` is scaled by
+    // `1 / p_y` so only `w` and `w^3` coefficients are returned
+    #[allow(clippy::type_complexity)]
+    fn line(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        lambda: &AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        t: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p: &AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedFp2<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    > {
+        let fp2_chip = self.fp2_chip();
+        let b3 = fp2_chip.mul_by_base(ctx, lambda, p.x())?;
+        let b4 = fp2_chip.mul(ctx, lambda, &t.x)?;
+        let b4 = fp2_chip.sub(ctx, &b4, &t.y)?;
+        let b4 = fp2_chip.mul_by_base(ctx, &b4, p.y())?;
+        Ok((b3, b4))
+    }
+
+    // Returns `2 * t` and updates `f` with the tangent line at `t`
+    fn double_step(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        f: &AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        t: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p: &AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    > {
+        let fp2_chip = self.fp2_chip();
+        // lambda = 3 * t_x^2 / 2 * t_y where t_y is not zero since G2 has no
+        // points of order two
+        let numerator = fp2_chip.square(ctx, &t.x)?;
+        let numerator = fp2_chip.mul_by_constant_base(ctx, &numerator, Fq::from(3))?;
+        let denominator = fp2_chip.double(ctx, &t.y)?;
+        let lambda = fp2_chip.div_incomplete(ctx, &numerator, &denominator)?;
+
+        let (b3, b4) = self.line(ctx, &lambda, t, p)?;
+        let f = self.fp12_chip.mul_by_34(ctx, f, &b3, &b4)?;
+        let t = self.add_with_slope(ctx, &lambda, t, &t.x)?;
+        Ok((f, t))
+    }
+
+    // Returns `t + q` and updates `f` with the line through `t` and `q`
+    fn add_step(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        f: &AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        t: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        q: &AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p: &AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    > {
+        let fp2_chip = self.fp2_chip();
+        // lambda = (t_y - q_y) / (t_x - q_x) where `t` is a multiple of `q`
+        // with a scalar smaller than the group order other than `1` and `-1`
+        let numerator = fp2_chip.sub(ctx, &t.y, &q.y)?;
+        let denominator = fp2_chip.sub(ctx, &t.x, &q.x)?;
+        let lambda = fp2_chip.div_incomplete(ctx, &numerator, &denominator)?;
+
+        let (b3, b4) = self.line(ctx, &lambda, t, p)?;
+        let f = self.fp12_chip.mul_by_34(ctx, f, &b3, &b4)?;
+        let t = self.add_with_slope(ctx, &lambda, t, &q.x)?;
+        Ok((f, t))
+    }
+
+    /// Computes the product of Miller loops of the optimal ate pairing for
+    /// given pairs sharing squarings of the accumulator
+    #[allow(clippy::type_complexity)]
+    pub fn multi_miller_loop(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        pairs: &[(
+            AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+    ) -> Result<AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = self.integer_chip();
+        let fp2_chip = self.fp2_chip();
+        let fp12_chip = &self.fp12_chip;
+
+        // Prepare G1 points as `(-p_x / p_y, 1 / p_y)` where `p_y` is not zero
+        // since G1 has no points of order two
+        let mut prepared = vec![];
+        for (p, _) in pairs.iter() {
+            let y_inv = integer_chip.invert_incomplete(ctx, p.y())?;
+            let x = integer_chip.mul(ctx, p.x(), &y_inv)?;
+            let x = integer_chip.neg(ctx, &x)?;
+            prepared.push(AssignedPoint::new(x, y_inv));
+        }
+        let mut negated = vec![];
+        for (_, q) in pairs.iter() {
+            let y = fp2_chip.neg(ctx, &q.y)?;
+            negated.push(AssignedG2Point::new(q.x.clone(), y));
+        }
+
+        let naf = six_u_plus_2_naf();
+        let mut f = fp12_chip.assign_one(ctx)?;
+        let mut ts = pairs.iter().map(|(_, q)| q.clone()).collect::<Vec<_>>();
+        for i in (0..naf.len() - 1).rev() {
+            if i != naf.len() - 2 {
+                f = fp12_chip.square(ctx, &f)?;
+            }
+            for (t, p) in ts.iter_mut().zip(prepared.iter()) {
+                let (f_next, t_next) = self.double_step(ctx, &f, t, p)?;
+                f = f_next;
+                *t = t_next;
+            }
+            if naf[i] != 0 {
+                let qs = if naf[i] == 1 {
+                    pairs.iter().map(|(_, q)| q).collect::<Vec<_>>()
+                } else {
+                    negated.iter().collect::<Vec<_>>()
+                };
+                for ((t, q), p) in ts.iter_mut().zip(qs.into_iter()).zip(prepared.iter()) {
+                    let (f_next, t_next) = self.add_step(ctx, &f, t, q, p)?;
+                    f = f_next;
+                    *t = t_next;
+                }
+            }
+        }
+
+        // Final lines with `q1 = pi(q)` and `q2 = -pi^2(q)`
+        let [_, _, x2, y2] = self.twist_frobenius_coeffs;
+        for ((t, (_, q)), p) in ts.iter().zip(pairs.iter()).zip(prepared.iter()) {
+            let q1 = self.psi(ctx, q)?;
+            let q2 = {
+                let x = fp2_chip.mul_constant(ctx, &q.x, x2)?;
+                let y = fp2_chip.mul_constant(ctx, &q.y, y2)?;
+                let y = fp2_chip.neg(ctx, &y)?;
+                AssignedG2Point::new(x, y)
+            };
+            let (f_next, t) = self.add_step(ctx, &f, t, &q1, p)?;
+            // Resulting point of the last step is not needed
+            let numerator = fp2_chip.sub(ctx, &t.y, &q2.y)?;
+            let denominator = fp2_chip.sub(ctx, &t.x, &q2.x)?;
+            let lambda = fp2_chip.div_incomplete(ctx, &numerator, &denominator)?;
+            let (b3, b4) = self.line(ctx, &lambda, &t, p)?;
+            f = fp12_chip.mul_by_34(ctx, &f_next, &b3, &b4)?;
+        }
+
+        Ok(f)
+    }
+
+    // Returns `a^u`
+    fn exp_by_x(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp12_chip = &self.fp12_chip;
+        let bit_len = 64 - BN_X.leading_zeros() as usize;
+        let mut acc = a.clone();
+        for i in (0..bit_len - 1).rev() {
+            acc = fp12_chip.square(ctx, &acc)?;
+            if (BN_X >> i) & 1 == 1 {
+                acc = fp12_chip.mul(ctx, &acc, a)?;
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Raises Miller loop output to `(p^12 - 1) / r`. Hard part follows
+    /// Scott et al. "On the final exponentiation for calculating pairings on
+    /// ordinary elliptic curves"
+    pub fn final_exponentiation(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        f: &AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp12_chip = &self.fp12_chip;
+
+        // Easy part `f^((p^6 - 1) * (p^2 + 1))`. Miller loop output is never
+        // zero and a zero input would end up as zero anyway
+        let (f_inv, _) = fp12_chip.invert(ctx, f)?;
+        let f = fp12_chip.conjugate(ctx, f)?;
+        let f = fp12_chip.mul(ctx, &f, &f_inv)?;
+        let f_p2 = fp12_chip.frobenius_map(ctx, &f, 2)?;
+        let r = fp12_chip.mul(ctx, &f_p2, &f)?;
+
+        // Hard part `r^((p^4 - p^2 + 1) / r)` where inversion is conjugation
+        // since `r` is in the cyclotomic subgroup
+        let fp = fp12_chip.frobenius_map(ctx, &r, 1)?;
+        let fp2 = fp12_chip.frobenius_map(ctx, &r, 2)?;
+        let fp3 = fp12_chip.frobenius_map(ctx, &fp2, 1)?;
+        let fu = self.exp_by_x(ctx, &r)?;
+        let fu2 = self.exp_by_x(ctx, &fu)?;
+        let fu3 = self.exp_by_x(ctx, &fu2)?;
+        let fu_p = fp12_chip.frobenius_map(ctx, &fu, 1)?;
+        let fu2_p = fp12_chip.frobenius_map(ctx, &fu2, 1)?;
+        let fu3_p = fp12_chip.frobenius_map(ctx, &fu3, 1)?;
+        let fu2_p2 = fp12_chip.frobenius_map(ctx, &fu2, 2)?;
+
+        // y0 = r^p * r^(p^2) * r^(p^3)
+        let y0 = fp12_chip.mul(ctx, &fp, &fp2)?;
+        let y0 = fp12_chip.mul(ctx, &y0, &fp3)?;
+        // y1 = 1 / r
+        let y1 = fp12_chip.conjugate(ctx, &r)?;
+        // y2 = r^(u^2 * p^2)
+        let y2 = fu2_p2;
+        // y3 = 1 / r^(u * p)
+        let y3 = fp12_chip.conjugate(ctx, &fu_p)?;
+        // y4 = 1 / (r^u * r^(u^2 * p))
+        let y4 = fp12_chip.mul(ctx, &fu, &fu2_p)?;
+        let y4 = fp12_chip.conjugate(ctx, &y4)?;
+        // y5 = 1 / r^(u^2)
+        let y5 = fp12_chip.conjugate(ctx, &fu2)?;
+        // y6 = 1 / (r^(u^3) * r^(u^3 * p))
+        let y6 = fp12_chip.mul(ctx, &fu3, &fu3_p)?;
+        let y6 = fp12_chip.conjugate(ctx, &y6)?;
+
+        // y0 * y1^2 * y2^6 * y3^12 * y4^18 * y5^30 * y6^36
+        let t0 = fp12_chip.square(ctx, &y6)?;
+        let t0 = fp12_chip.mul(ctx, &t0, &y4)?;
+        let t0 = fp12_chip.mul(ctx, &t0, &y5)?;
+        let t1 = fp12_chip.mul(ctx, &y3, &y5)?;
+        let t1 = fp12_chip.mul(ctx, &t1, &t0)?;
+        let t0 = fp12_chip.mul(ctx, &t0, &y2)?;
+        let t1 = fp12_chip.square(ctx, &t1)?;
+        let t1 = fp12_chip.mul(ctx, &t1, &t0)?;
+        let t1 = fp12_chip.square(ctx, &t1)?;
+        let t0 = fp12_chip.mul(ctx, &t1, &y1)?;
+        let t1 = fp12_chip.mul(ctx, &t1, &y0)?;
+        let t0 = fp12_chip.square(ctx, &t0)?;
+        fp12_chip.mul(ctx, &t0, &t1)
+    }
+
+    /// Computes the product of pairings of given pairs
+    #[allow(clippy::type_complexity)]
+    pub fn multi_pairing(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        pairs: &[(
+            AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+    ) -> Result<AssignedFp12<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let f = self.multi_miller_loop(ctx, pairs)?;
+        self.final_exponentiation(ctx, &f)
+    }
+
+    /// Constrains the product of pairings of given pairs to be one
+    #[allow(clippy::type_complexity)]
+    pub fn multi_pairing_check(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        pairs: &[(
+            AssignedPoint<Fq, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedG2Point<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+    ) -> Result<(), Error> {
+        let result = self.multi_pairing(ctx, pairs)?;
+        self.fp12_chip.assert_one(ctx, &result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{six_u_plus_2_naf, to_pair, PairingChip, BN_X};
+    use crate::curves::bn256::{
+        Bn256, Fq, Fq12, Fq2, Fq6, Fr, G1Affine, G2Affine, G2Prepared, G1, G2,
+    };
+    use crate::curves::pairing::{MillerLoopResult, MultiMillerLoop};
+    use crate::halo2;
+    use crate::maingate;
+    use crate::EccConfig;
+    use halo2::arithmetic::CurveAffine;
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use integer::halo2::ff::Field;
+    use integer::halo2::group::{Curve, Group};
+    use integer::Range;
+    use maingate::{
+        fe_to_big, mock_prover_verify, mock_prover_verify_fails, modulus, MainGate, MainGateConfig,
+        RangeChip, RangeConfig, RangeInstructions, RegionCtx,
+    };
+    use num_bigint::BigUint as big_uint;
+    use rand_core::OsRng;
+
+    const NUMBER_OF_LIMBS: usize = 4;
+    const BIT_LEN_LIMB: usize = 68;
+
+    fn fq2(e: Fq) -> Fq2 {
+        Fq2 {
+            c0: e,
+            c1: Fq::ZERO,
+        }
+    }
+
+    fn fp12(e: Fq12) -> [[(Fq, Fq); 3]; 2] {
+        let fp6 = |e: Fq6| [to_pair(e.c0), to_pair(e.c1), to_pair(e.c2)];
+        [fp6(e.c0), fp6(e.c1)]
+    }
+
+    fn final_exponent() -> big_uint {
+        (modulus::<Fq>().pow(12) - 1usize) / modulus::<Fr>()
+    }
+
+    fn xi_pow(exponent: big_uint) -> Fq2 {
+        let xi = Fq2 {
+            c0: Fq::from(9),
+            c1: Fq::ONE,
+        };
+        xi.pow_vartime(exponent.to_u64_digits())
+    }
+
+    // Off-circuit `psi` endomorphism of the twist
+    fn psi(q: (Fq2, Fq2)) -> (Fq2, Fq2) {
+        let p = modulus::<Fq>();
+        let conjugate = |e: Fq2| Fq2 {
+            c0: e.c0,
+            c1: -e.c1,
+        };
+        (
+            conjugate(q.0) * xi_pow((&p - 1usize) / 3usize),
+            conjugate(q.1) * xi_pow((&p - 1usize) / 2usize),
+        )
+    }
+
+    // Off-circuit Miller loop that follows the chip step by step
+    fn multi_miller_loop(pairs: &[(G1Affine, G2Affine)]) -> Fq12 {
+        let p = modulus::<Fq>();
+        let p2 = &p * &p;
+        let (x2, y2) = (
+            xi_pow((&p2 - 1usize) / 3usize),
+            xi_pow((&p2 - 1usize) / 2usize),
+        );
+
+        // Multiplies `f` by the line with the given slope through `t` and
+        // returns it along with `t + q`
+        let step = |f: Fq12, lambda: Fq2, t: (Fq2, Fq2), q_x: Fq2, p: (Fq, Fq)| {
+            let b3 = lambda * fq2(p.0);
+            let b4 = (lambda * t.0 - t.1) * fq2(p.1);
+            let line = Fq12 {
+                c0: Fq6::ONE,
+                c1: Fq6 {
+                    c0: b3,
+                    c1: b4,
+                    c2: Fq2::ZERO,
+                },
+            };
+            let x = lambda.square() - t.0 - q_x;
+            let y = lambda * (t.0 - x) - t.1;
+            (f * line, (x, y))
+        };
+        let double = |f: Fq12, t: (Fq2, Fq2), p: (Fq, Fq)| {
+            let lambda = t.0.square() * fq2(Fq::from(3)) * t.1.double().invert().unwrap();
+            step(f, lambda, t, t.0, p)
+        };
+        let add = |f: Fq12, t: (Fq2, Fq2), q: (Fq2, Fq2), p: (Fq, Fq)| {
+            let lambda = (t.1 - q.1) * (t.0 - q.0).invert().unwrap();
+            step(f, lambda, t, q.0, p)
+        };
+
+        let prepared = pairs
+            .iter()
+            .map(|(p, _)| {
+                let coords = p.coordinates().unwrap();
+                let y_inv = coords.y().invert().unwrap();
+                (-*coords.x() * y_inv, y_inv)
+            })
+            .collect::<Vec<_>>();
+        let qs = pairs
+            .iter()
+            .map(|(_, q)| {
+                let coords = q.coordinates().unwrap();
+                (*coords.x(), *coords.y())
+            })
+            .collect::<Vec<_>>();
+
+        let naf = six_u_plus_2_naf();
+        let mut f = Fq12::ONE;
+        let mut ts = qs.clone();
+        for i in (0..naf.len() - 1).rev() {
+            f = f.square();
+            for (t, p) in ts.iter_mut().zip(prepared.iter()) {
+                let (f_next, t_next) = double(f, *t, *p);
+                f = f_next;
+                *t = t_next;
+            }
+            if naf[i] != 0 {
+                for ((t, q), p) in ts.iter_mut().zip(qs.iter()).zip(prepared.iter()) {
+                    let q = if naf[i] == 1 { *q } else { (q.0, -q.1) };
+                    let (f_next, t_next) = add(f, *t, q, *p);
+                    f = f_next;
+                    *t = t_next;
+                }
+            }
+        }
+        for ((t, q), p) in ts.iter().zip(qs.iter()).zip(prepared.iter()) {
+            let q1 = psi(*q);
+            let q2 = (q.0 * x2, -(q.1 * y2));
+            let (f_next, t) = add(f, *t, q1, *p);
+            let (f_next, _) = add(f_next, t, q2, *p);
+            f = f_next;
+        }
+        f
+    }
+
+    fn is_pairing_one(pairs: &[(G1Affine, G2Affine)]) -> bool {
+        let prepared = pairs
+            .iter()
+            .map(|(p, q)| (p, G2Prepared::from(*q)))
+            .collect::<Vec<_>>();
+        let terms = prepared.iter().map(|(p, q)| (*p, q)).collect::<Vec<_>>();
+        bool::from(
+            Bn256::multi_miller_loop(&terms)
+                .final_exponentiation()
+                .is_identity(),
+        )
+    }
+
+    fn rand_pairs(valid: bool) -> Vec<(G1Affine, G2Affine)> {
+        let (p, q) = (G1::random(OsRng), G2::random(OsRng));
+        let (a, b) = (Fr::random(OsRng), Fr::random(OsRng));
+        // e(a * p, b * q) * e(-a * b * p, q) = 1
+        let c = if valid { a * b } else { a * b + Fr::ONE };
+        vec![
+            ((p * a).to_affine(), (q * b).to_affine()),
+            ((-p * c).to_affine(), q.to_affine()),
+        ]
+    }
+
+    // Returns whether `psi(q) = [6u^2] q` which holds exactly for points of
+    // G2
+    fn is_in_subgroup(q: G2Affine) -> bool {
+        let coords = q.coordinates().unwrap();
+        let scalar = Fr::from(BN_X).square() * Fr::from(6);
+        let coords_mul = (q * scalar).to_affine().coordinates().unwrap();
+        psi((*coords.x(), *coords.y())) == (*coords_mul.x(), *coords_mul.y())
+    }
+
+    // Returns a point on the twist that is not in G2
+    fn rand_twist_point() -> G2Affine {
+        loop {
+            let x = Fq2::random(OsRng);
+            let y = (x.square() * x + G2Affine::b()).sqrt();
+            if let Some(y) = Option::<Fq2>::from(y) {
+                let point = G2Affine::from_xy(x, y).unwrap();
+                assert!(!is_in_subgroup(point));
+                return point;
+            }
+        }
+    }
+
+    #[test]
+    fn test_six_u_plus_2_naf() {
+        let naf = six_u_plus_2_naf();
+        let value = naf
+            .iter()
+            .rev()
+            .fold(0i128, |acc, digit| 2 * acc + *digit as i128);
+        assert_eq!(value, 6 * BN_X as i128 + 2);
+        assert!(naf.windows(2).all(|w| w[0] == 0 || w[1] == 0));
+    }
+
+    #[test]
+    fn test_g2_subgroup_reference() {
+        assert!(is_in_subgroup(G2::random(OsRng).to_affine()));
+        assert!(!is_in_subgroup(rand_twist_point()));
+    }
+
+    #[test]
+    fn test_pairing_reference() {
+        let final_exponent = final_exponent().to_u64_digits();
+        let pairing =
+            |pairs: &[(G1Affine, G2Affine)]| multi_miller_loop(pairs).pow_vartime(&final_exponent);
+
+        // Agrees with `halo2curves` pairing check
+        for valid in [true, false] {
+            let pairs = rand_pairs(valid);
+            assert!(is_pairing_one(&pairs) == valid);
+            assert!((pairing(&pairs) == Fq12::ONE) == valid);
+        }
+
+        // Non degenerate and bilinear
+        let (p, q) = (G1::random(OsRng), G2::random(OsRng));
+        let a = Fr::random(OsRng);
+        let e = pairing(&[(p.to_affine(), q.to_affine())]);
+        assert!(e != Fq12::ONE);
+        let e_a = e.pow_vartime(fe_to_big(a).to_u64_digits());
+        assert_eq!(pairing(&[((p * a).to_affine(), q.to_affine())]), e_a);
+        assert_eq!(pairing(&[(p.to_affine(), (q * a).to_affine())]), e_a);
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+        range_config: RangeConfig,
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> TestCircuitConfig {
+        let rns = PairingChip::<Fr, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::rns();
+        let main_gate_config = MainGate::<Fr>::configure(meta);
+        let range_config = RangeChip::<Fr>::configure(
+            meta,
+            &main_gate_config,
+            vec![rns.bit_len_lookup],
+            rns.overflow_lengths(),
+        );
+        TestCircuitConfig {
+            main_gate_config,
+            range_config,
+        }
+    }
+
+    fn pairing_chip(config: &TestCircuitConfig) -> PairingChip<Fr, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        PairingChip::new(EccConfig::new(
+            config.range_config.clone(),
+            config.main_gate_config.clone(),
+        ))
+    }
+
+    // Miller loop and final exponentiation of a single pair are compared with
+    // the reference step by step
+    #[derive(Default)]
+    struct TestPairingSteps;
+
+    impl Circuit<Fr> for TestPairingSteps {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let pairing_chip = pairing_chip(&config);
+            let fp12_chip = pairing_chip.fp12_chip();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let pairs =
+                        vec![(G1::random(OsRng).to_affine(), G2::random(OsRng).to_affine())];
+                    let mut assigned = vec![];
+                    for (p, q) in pairs.iter() {
+                        let p = pairing_chip.assign_g1(ctx, Value::known(*p))?;
+                        let q = pairing_chip.assign_g2(ctx, Value::known(*q))?;
+                        assigned.push((p, q));
+                    }
+
+                    // Miller loop output must match the reference before the
+                    // final exponentiation hides the difference
+                    let f = pairing_chip.multi_miller_loop(ctx, &assigned)?;
+                    let f_expected = multi_miller_loop(&pairs);
+                    let expected = fp12_chip.assign_fp12(
+                        ctx,
+                        Value::known(fp12(f_expected)),
+                        Range::Remainder,
+                    )?;
+                    fp12_chip.assert_equal(ctx, &f, &expected)?;
+
+                    // Pairing of a single random pair is not one
+                    let e_expected = f_expected.pow_vartime(final_exponent().to_u64_digits());
+                    assert!(e_expected != Fq12::ONE);
+                    let e = pairing_chip.final_exponentiation(ctx, &f)?;
+                    let expected = fp12_chip.assign_fp12(
+                        ctx,
+                        Value::known(fp12(e_expected)),
+                        Range::Remainder,
+                    )?;
+                    fp12_chip.assert_equal(ctx, &e, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<Fr>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    struct TestPairingCheck {
+        valid: bool,
+    }
+
+    impl Circuit<Fr> for TestPairingCheck {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let pairing_chip = pairing_chip(&config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let pairs = rand_pairs(self.valid);
+                    let mut assigned = vec![];
+                    for (p, q) in pairs.iter() {
+                        let p = pairing_chip.assign_g1(ctx, Value::known(*p))?;
+                        let q = pairing_chip.assign_g2(ctx, Value::known(*q))?;
+                        assigned.push((p, q));
+                    }
+                    pairing_chip.multi_pairing_check(ctx, &assigned)
+                },
+            )?;
+
+            let range_chip = RangeChip::<Fr>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    struct TestG2Subgroup {
+        point: G2Affine,
+    }
+
+    impl Circuit<Fr> for TestG2Subgroup {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let pairing_chip = pairing_chip(&config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    pairing_chip.assign_g2(ctx, Value::known(self.point))?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<Fr>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_g2_subgroup_circuit() {
+        let point = G2::random(OsRng).to_affine();
+        mock_prover_verify(&TestG2Subgroup { point }, vec![vec![]]);
+        let point = rand_twist_point();
+        mock_prover_verify_fails(&TestG2Subgroup { point }, vec![vec![]]);
+    }
+
+    #[test]
+    fn test_pairing_circuit() {
+        mock_prover_verify(&TestPairingSteps, vec![vec![]]);
+        mock_prover_verify(&TestPairingCheck { valid: true }, vec![vec![]]);
+        mock_prover_verify_fails(&TestPairingCheck { valid: false }, vec![vec![]]);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::halo2::arithmetic::CurveAffine;
+use crate::integer::rns::{Common, Rns};
+use crate::integer::serialization::{fe_from_hex, fe_to_hex};
+use crate::maingate::big_to_fe;
+use crate::Point;
+use integer::halo2::ff::PrimeField;
+use serde::de::{Deserializer, Error};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+/// Points are serialized as their coordinates in the representation of the
+/// base field
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Serialize for Point<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let x: W = big_to_fe(self.x.value());
+        let y: W = big_to_fe(self.y.value());
+        let mut state = serializer.serialize_struct("Point", 2)?;
+        state.serialize_field("x", &fe_to_hex(&x))?;
+        state.serialize_field("y", &fe_to_hex(&y))?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct Coordinates {
+    x: String,
+    y: String,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Point<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Deserializes a point that is checked to be on the curve `C`. Since
+    /// [`Point`] doesn't know its curve it doesn't implement `Deserialize`,
+    /// use this with `#[serde(deserialize_with = "...")]` instead.
+    /// Coordinates must be canonical and the point at infinity is rejected.
+    /// A new [`Rns`] is constructed for each point which dominates the cost
+    /// of decoding.
+    pub fn deserialize_on_curve<'de, C: CurveAffine<Base = W>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let coordinates = Coordinates::deserialize(deserializer)?;
+        let x = fe_from_hex(&coordinates.x)?;
+        let y = fe_from_hex(&coordinates.y)?;
+        let point: C = Option::from(C::from_xy(x, y))
+            .ok_or_else(|| D::Error::custom("point is not on curve"))?;
+        if bool::from(point.coordinates().is_none()) {
+            return Err(D::Error::custom("point at infinity"));
+        }
+        let rns = Rns::try_construct().map_err(D::Error::custom)?;
+        Ok(Point::new(Rc::new(rns), point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::secp256k1::{Fp as Secp256k1Base, Secp256k1, Secp256k1Affine};
+    use crate::halo2::ff::Field;
+    use crate::halo2::group::{Curve, Group};
+    use crate::integer::rns::{Common, Rns};
+    use crate::integer::serialization::fe_to_hex;
+    use crate::Point;
+    use integer::halo2::curves::bn256::Fr as BnScalar;
+    use rand_core::OsRng;
+    use std::rc::Rc;
+
+    type Secp256k1Point = Point<Secp256k1Base, BnScalar, 4, 68>;
+
+    fn deserialize(encoded: &str) -> Result<Secp256k1Point, serde_json::Error> {
+        let mut deserializer = serde_json::Deserializer::from_str(encoded);
+        Secp256k1Point::deserialize_on_curve::<Secp256k1Affine, _>(&mut deserializer)
+    }
+
+    fn encode(x: Secp256k1Base, y: Secp256k1Base) -> String {
+        format!(
+            "{{\"x\":\"{}\",\"y\":\"{}\"}}",
+            fe_to_hex(&x),
+            fe_to_hex(&y)
+        )
+    }
+
+    #[test]
+    fn test_point_serde() {
+        let rns = Rc::new(Rns::construct());
+        let point = Secp256k1::random(OsRng).to_affine();
+        let encoded = serde_json::to_string(&Secp256k1Point::new(rns, point)).unwrap();
+        assert_eq!(encoded, encode(point.x, point.y));
+        let decoded = deserialize(&encoded).unwrap();
+        assert_eq!(decoded.x().value(), crate::maingate::fe_to_big(point.x));
+        assert_eq!(decoded.y().value(), crate::maingate::fe_to_big(point.y));
+
+        // Off curve point
+        let encoded = encode(point.x, point.y + Secp256k1Base::ONE);
+        assert!(deserialize(&encoded).is_err());
+
+        // Point at infinity
+        let encoded = encode(Secp256k1Base::ZERO, Secp256k1Base::ZERO);
+        assert!(deserialize(&encoded).is_err());
+
+        // Non canonical coordinate
+        let encoded = format!("{{\"x\":\"{}\",\"y\":\"00\"}}", "ff".repeat(32));
+        assert!(deserialize(&encoded).is_err());
+    }
+}
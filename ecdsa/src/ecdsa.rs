@@ -137,6 +137,64 @@ impl<E: CurveAffine, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_
     }
 }
 
+#[cfg(feature = "serde")]
+mod serialization {
+    use super::EcdsaSig;
+    use crate::integer::rns::{Common, Integer, Rns};
+    use crate::integer::serialization::{fe_from_hex, fe_to_hex};
+    use crate::maingate::big_to_fe;
+    use ecc::halo2::ff::PrimeField;
+    use serde::de::{Deserializer, Error};
+    use serde::ser::{SerializeStruct, Serializer};
+    use serde::{Deserialize, Serialize};
+    use std::rc::Rc;
+
+    /// Signature is serialized as `r` and `s` in the representation of the
+    /// scalar field
+    impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+        Serialize for EcdsaSig<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let r: W = big_to_fe(self.r.value());
+            let s: W = big_to_fe(self.s.value());
+            let mut state = serializer.serialize_struct("EcdsaSig", 2)?;
+            state.serialize_field("r", &fe_to_hex(&r))?;
+            state.serialize_field("s", &fe_to_hex(&s))?;
+            state.end()
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Signature {
+        r: String,
+        s: String,
+    }
+
+    /// `r` and `s` must be canonical and non zero
+    impl<
+            'de,
+            W: PrimeField,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        > Deserialize<'de> for EcdsaSig<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signature = Signature::deserialize(deserializer)?;
+            let r: W = fe_from_hex(&signature.r)?;
+            let s: W = fe_from_hex(&signature.s)?;
+            if bool::from(r.is_zero()) || bool::from(s.is_zero()) {
+                return Err(D::Error::custom("zero signature component"));
+            }
+            let rns = Rc::new(Rns::try_construct().map_err(D::Error::custom)?);
+            Ok(EcdsaSig {
+                r: Integer::from_fe(r, Rc::clone(&rns)),
+                s: Integer::from_fe(s, rns),
+            })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{AssignedEcdsaSig, AssignedPublicKey, EcdsaChip};
@@ -358,4 +416,32 @@ mod tests {
         run::<Secp256k1, PastaFp>();
         run::<Secp256k1, PastaFq>();
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ecdsa_sig_serde() {
+        use super::EcdsaSig;
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::Fq as Secp256k1Scalar;
+        use crate::halo2::ff::Field;
+        use crate::integer::rns::{Common, Integer, Rns};
+        use rand_core::OsRng;
+        use std::rc::Rc;
+
+        type Sig = EcdsaSig<Secp256k1Scalar, BnScalar, 4, 68>;
+
+        let rns = Rc::new(Rns::construct());
+        let signature = Sig {
+            r: Integer::from_fe(Secp256k1Scalar::random(OsRng), Rc::clone(&rns)),
+            s: Integer::from_fe(Secp256k1Scalar::random(OsRng), rns),
+        };
+        let encoded = serde_json::to_string(&signature).unwrap();
+        let decoded: Sig = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded.r.value(), signature.r.value());
+        assert_eq!(decoded.s.value(), signature.s.value());
+
+        let zero = "00".repeat(32);
+        let encoded = format!("{{\"r\":\"{}\",\"s\":\"{}\"}}", zero, zero);
+        assert!(serde_json::from_str::<Sig>(&encoded).is_err());
+    }
 }
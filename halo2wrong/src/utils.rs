@@ -12,45 +12,132 @@ use halo2::{
 };
 use num_bigint::BigUint as big_uint;
 use num_traits::{Num, One, Zero};
-use std::{
-    cell::RefCell,
-    ops::{RangeInclusive, Shl},
-};
+use std::{cell::RefCell, ops::RangeInclusive};
 
 pub fn modulus<F: PrimeField>() -> big_uint {
     big_uint::from_str_radix(&F::MODULUS[2..], 16).unwrap()
 }
 
 pub fn power_of_two<F: PrimeField>(n: usize) -> F {
-    big_to_fe(big_uint::one() << n)
+    // Powers below `2^(NUM_BITS - 1)` are less than the modulus
+    if n + 1 < F::NUM_BITS as usize {
+        let mut repr = F::Repr::default();
+        repr.as_mut()[n / 8] = 1 << (n % 8);
+        F::from_repr(repr).unwrap()
+    } else {
+        big_to_fe(big_uint::one() << n)
+    }
 }
 
 pub fn big_to_fe<F: PrimeField>(e: big_uint) -> F {
-    let modulus = modulus::<F>();
-    let e = e % modulus;
-    F::from_str_vartime(&e.to_str_radix(10)[..]).unwrap()
+    // Values below `2^(NUM_BITS - 1)` are less than the modulus
+    let e = if e.bits() < F::NUM_BITS as u64 {
+        e
+    } else {
+        e % modulus::<F>()
+    };
+    bytes_to_fe(&e.to_bytes_le())
 }
 
 pub fn fe_to_big<F: PrimeField>(fe: F) -> big_uint {
     big_uint::from_bytes_le(fe.to_repr().as_ref())
 }
 
+/// Converts little endian bytes of a value that is less than the modulus to
+/// a field element. Field representation is expected to be little endian as
+/// in `fe_to_big`.
+fn bytes_to_fe<F: PrimeField>(bytes: &[u8]) -> F {
+    let mut repr = F::Repr::default();
+    let len = bytes.len();
+    repr.as_mut()[..len].copy_from_slice(bytes);
+    F::from_repr(repr).unwrap()
+}
+
+/// Returns `bit_len` bits of little endian `bytes` starting from the bit at
+/// `offset` as little endian bytes. Output is not longer than the input.
+fn extract_bits(bytes: &[u8], offset: usize, bit_len: usize) -> Vec<u8> {
+    let byte = |i: usize| bytes.get(i).copied().unwrap_or(0);
+    let (start, shift) = (offset / 8, offset % 8);
+    let len = std::cmp::min((bit_len + 7) / 8, bytes.len());
+    let mut out: Vec<u8> = (0..len)
+        .map(|i| {
+            let lo = byte(start + i) >> shift;
+            let hi = if shift == 0 {
+                0
+            } else {
+                byte(start + i + 1) << (8 - shift)
+            };
+            lo | hi
+        })
+        .collect();
+    // Clear bits above `bit_len` in the last partial byte
+    if bit_len < len * 8 {
+        out[len - 1] &= (1u8 << (bit_len % 8)) - 1;
+    }
+    out
+}
+
 pub fn decompose<F: PrimeField>(e: F, number_of_limbs: usize, bit_len: usize) -> Vec<F> {
-    decompose_big(fe_to_big(e), number_of_limbs, bit_len)
+    let repr = e.to_repr();
+    // Limbs of a field element are not larger than itself
+    (0..number_of_limbs)
+        .map(|i| bytes_to_fe(&extract_bits(repr.as_ref(), i * bit_len, bit_len)))
+        .collect()
 }
 
 pub fn decompose_big<F: PrimeField>(e: big_uint, number_of_limbs: usize, bit_len: usize) -> Vec<F> {
-    let mut e = e;
-    let mask = big_uint::from(1usize).shl(bit_len) - 1usize;
-    let limbs: Vec<F> = (0..number_of_limbs)
-        .map(|_| {
-            let limb = mask.clone() & e.clone();
-            e = e.clone() >> bit_len;
-            big_to_fe(limb)
+    let bytes = e.to_bytes_le();
+    (0..number_of_limbs)
+        .map(|i| {
+            let limb = extract_bits(&bytes, i * bit_len, bit_len);
+            if bit_len < F::NUM_BITS as usize {
+                bytes_to_fe(&limb)
+            } else {
+                big_to_fe(big_uint::from_bytes_le(&limb))
+            }
         })
-        .collect();
+        .collect()
+}
 
-    limbs
+/// Computes `limb_0 + limb_1 * 2^bit_len + limb_2 * 2^(2 * bit_len) + ...`
+/// of field elements. Limbs are added into fixed size 64 bit words so that
+/// only the result is allocated as `big_uint`. Limbs may be wider than
+/// `bit_len`.
+pub fn compose_fe<F: PrimeField>(limbs: &[F], bit_len: usize) -> big_uint {
+    let repr_len = F::Repr::default().as_ref().len();
+    let number_of_words = (limbs.len() * bit_len) / 64 + repr_len / 8 + 3;
+    let mut words = vec![0u64; number_of_words];
+
+    let add_at = |words: &mut [u64], mut idx: usize, value: u64| {
+        let mut carry = value;
+        while carry != 0 {
+            let (sum, overflow) = words[idx].overflowing_add(carry);
+            words[idx] = sum;
+            carry = overflow as u64;
+            idx += 1;
+        }
+    };
+
+    for (i, limb) in limbs.iter().enumerate() {
+        let (start, shift) = ((i * bit_len) / 64, (i * bit_len) % 64);
+        let repr = limb.to_repr();
+        for (j, chunk) in repr.as_ref().chunks(8).enumerate() {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            let word = u64::from_le_bytes(word);
+            add_at(&mut words, start + j, word << shift);
+            if shift != 0 {
+                add_at(&mut words, start + j + 1, word >> (64 - shift));
+            }
+        }
+    }
+
+    big_uint::new(
+        words
+            .into_iter()
+            .flat_map(|word| [word as u32, (word >> 32) as u32])
+            .collect(),
+    )
 }
 
 /// Compute the represented value by a vector of values and a bit length.
@@ -82,6 +169,22 @@ pub fn mock_prover_verify<
     )
 }
 
+/// Runs the mock prover and expects the circuit to be unsatisfied
+pub fn mock_prover_verify_fails<
+    F: WithSmallOrderMulGroup<3> + FromUniformBytes<64> + Ord,
+    C: Circuit<F>,
+>(
+    circuit: &C,
+    instance: Vec<Vec<F>>,
+) {
+    let dimension = DimensionMeasurement::measure(circuit).unwrap();
+    let prover = MockProver::run(dimension.k(), circuit, instance)
+        .unwrap_or_else(|err| panic!("{:#?}", err));
+    assert!(prover
+        .verify_at_rows_par(dimension.advice_range(), dimension.advice_range())
+        .is_err())
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Dimension {
     blinding_factor: u64,
@@ -106,6 +209,11 @@ impl Dimension {
     fn advice_range(&self) -> RangeInclusive<usize> {
         0..=self.advice as usize
     }
+
+    /// Number of rows used in advice columns
+    pub fn advice(&self) -> u64 {
+        self.advice
+    }
 }
 
 #[derive(Default)]
@@ -267,6 +375,75 @@ fn test_round_trip() {
     }
 }
 
+#[test]
+fn test_conversions_against_big_uint() {
+    use crate::curves::{bn256::Fr, pasta::Fp};
+    use halo2::ff::Field as _;
+    use num_bigint::RandomBits;
+    use rand::Rng;
+    use rand_core::OsRng;
+
+    // Reference implementations through decimal strings and `big_uint`
+    fn big_to_fe_reference<F: PrimeField>(e: big_uint) -> F {
+        let e = e % modulus::<F>();
+        F::from_str_vartime(&e.to_str_radix(10)[..]).unwrap()
+    }
+    fn decompose_big_reference<F: PrimeField>(
+        e: big_uint,
+        number_of_limbs: usize,
+        bit_len: usize,
+    ) -> Vec<F> {
+        let mask = (big_uint::one() << bit_len) - 1usize;
+        (0..number_of_limbs)
+            .map(|i| big_to_fe_reference((&e >> (i * bit_len)) & &mask))
+            .collect()
+    }
+
+    fn run<F: PrimeField>() {
+        for bit_len in [1, 8, 64, 68, 88, 255, 256, 300, 600] {
+            let e: big_uint = OsRng.sample(RandomBits::new(bit_len));
+            assert_eq!(
+                big_to_fe::<F>(e.clone()),
+                big_to_fe_reference::<F>(e.clone())
+            );
+            for limb_bit_len in [1, 7, 8, 17, 64, 68, 88, 104] {
+                let number_of_limbs = bit_len as usize / limb_bit_len + 2;
+                assert_eq!(
+                    decompose_big::<F>(e.clone(), number_of_limbs, limb_bit_len),
+                    decompose_big_reference::<F>(e.clone(), number_of_limbs, limb_bit_len)
+                );
+            }
+        }
+        for n in 0..300 {
+            assert_eq!(
+                power_of_two::<F>(n),
+                big_to_fe_reference(big_uint::one() << n)
+            );
+        }
+        for _ in 0..100 {
+            let e = F::random(OsRng);
+            for limb_bit_len in [1, 17, 64, 68, 88] {
+                let number_of_limbs = F::NUM_BITS as usize / limb_bit_len + 1;
+                let limbs = decompose(e, number_of_limbs, limb_bit_len);
+                assert_eq!(
+                    limbs,
+                    decompose_big_reference::<F>(fe_to_big(e), number_of_limbs, limb_bit_len)
+                );
+                assert_eq!(compose_fe(&limbs, limb_bit_len), fe_to_big(e));
+            }
+            // Limbs wider than the bit length overlap
+            let limbs = [e, e, F::random(OsRng)];
+            assert_eq!(
+                compose_fe(&limbs, 68),
+                compose(limbs.iter().map(|e| fe_to_big(*e)).collect(), 68)
+            );
+        }
+    }
+
+    run::<Fp>();
+    run::<Fr>();
+}
+
 #[test]
 fn test_bit_decomposition() {
     use crate::curves::pasta::Fp;
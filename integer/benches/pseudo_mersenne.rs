@@ -0,0 +1,166 @@
+//! Compares rows and mock proving time of multiplications and reductions over
+//! the secp256k1 base field in the generic and the pseudo-Mersenne path.
+//!
+//! `cargo bench -p integer --bench pseudo_mersenne`
+
+use integer::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+use integer::halo2::ff::Field;
+use integer::halo2::halo2curves::bn256::Fr as BnScalar;
+use integer::halo2::halo2curves::secp256k1::Fp as Secp256k1Base;
+use integer::halo2::plonk::{Circuit, ConstraintSystem, Error};
+use integer::maingate::{
+    mock_prover_verify, DimensionMeasurement, MainGate, MainGateConfig, RangeChip, RangeConfig,
+    RangeInstructions, RegionCtx,
+};
+use integer::rns::{Integer, Rns};
+use integer::{IntegerChip, IntegerConfig, IntegerInstructions, Range};
+use rand_core::OsRng;
+use std::rc::Rc;
+use std::time::Instant;
+
+const NUMBER_OF_LIMBS: usize = 4;
+const BIT_LEN_LIMB: usize = 68;
+const NUMBER_OF_OPERATIONS: usize = 100;
+
+type W = Secp256k1Base;
+type N = BnScalar;
+
+fn rns() -> Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+    Rns::construct()
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    Mul,
+    Reduce,
+}
+
+#[derive(Clone, Debug)]
+struct BenchConfig {
+    main_gate_config: MainGateConfig,
+    range_config: RangeConfig,
+}
+
+// Assigns random operands and applies the operation to them if `apply` is set
+// so that rows of the operation alone are found as the difference
+struct BenchCircuit {
+    rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    pseudo_mersenne: bool,
+    operation: Operation,
+    apply: bool,
+}
+
+impl Circuit<N> for BenchCircuit {
+    type Config = BenchConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        unimplemented!();
+    }
+
+    fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+        let rns = rns();
+        let main_gate_config = MainGate::<N>::configure(meta);
+        let range_config = RangeChip::<N>::configure(
+            meta,
+            &main_gate_config,
+            vec![rns.bit_len_lookup],
+            rns.overflow_lengths(),
+        );
+        BenchConfig {
+            main_gate_config,
+            range_config,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<N>,
+    ) -> Result<(), Error> {
+        let integer_chip = IntegerChip::new(
+            IntegerConfig::new(config.range_config.clone(), config.main_gate_config),
+            Rc::clone(&self.rns),
+        )
+        .with_pseudo_mersenne(self.pseudo_mersenne);
+
+        layouter.assign_region(
+            || "region 0",
+            |region| {
+                let offset = 0;
+                let ctx = &mut RegionCtx::new(region, offset);
+                for _ in 0..NUMBER_OF_OPERATIONS {
+                    let mut assign = || {
+                        let e = Integer::from_fe(W::random(OsRng), Rc::clone(&self.rns));
+                        integer_chip.assign_integer(ctx, Value::known(e).into(), Range::Remainder)
+                    };
+                    let a = assign()?;
+                    let b = assign()?;
+                    match self.operation {
+                        Operation::Mul => {
+                            if self.apply {
+                                integer_chip.mul(ctx, &a, &b)?;
+                            }
+                        }
+                        Operation::Reduce => {
+                            let sum = integer_chip.add(ctx, &a, &b)?;
+                            if self.apply {
+                                integer_chip.reduce(ctx, &sum)?;
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            },
+        )?;
+
+        let range_chip = RangeChip::<N>::new(config.range_config);
+        range_chip.load_table(&mut layouter)
+    }
+}
+
+fn main() {
+    let rns = Rc::new(rns());
+    println!(
+        "{:<8} {:<16} {:>12} {:>12} {:>12}",
+        "op", "path", "rows/op", "estimate", "mock (ms)"
+    );
+    for operation in [Operation::Mul, Operation::Reduce] {
+        for pseudo_mersenne in [false, true] {
+            let circuit = |apply| BenchCircuit {
+                rns: Rc::clone(&rns),
+                pseudo_mersenne,
+                operation,
+                apply,
+            };
+            let rows = |apply| {
+                DimensionMeasurement::measure(&circuit(apply))
+                    .unwrap()
+                    .advice()
+            };
+            let rows_per_operation = (rows(true) - rows(false)) as usize / NUMBER_OF_OPERATIONS;
+            let estimate = match operation {
+                Operation::Mul => rns.mul_row_cost(pseudo_mersenne),
+                Operation::Reduce => rns.reduce_row_cost(pseudo_mersenne),
+            };
+
+            let start = Instant::now();
+            mock_prover_verify(&circuit(true), vec![vec![]]);
+            let elapsed = start.elapsed().as_millis();
+
+            let path = if pseudo_mersenne {
+                "pseudo-mersenne"
+            } else {
+                "generic"
+            };
+            println!(
+                "{:<8} {:<16} {:>12} {:>12} {:>12}",
+                format!("{:?}", operation).to_lowercase(),
+                path,
+                rows_per_operation,
+                estimate,
+                elapsed
+            );
+        }
+    }
+}
@@ -3,30 +3,40 @@ use std::rc::Rc;
 use super::{AssignedInteger, AssignedLimb, UnassignedInteger};
 use crate::instructions::{IntegerInstructions, Range};
 use crate::rns::{Common, Integer, Rns};
+use halo2::circuit::Layouter;
 use halo2::plonk::Error;
 use maingate::halo2::ff::PrimeField;
-use maingate::{halo2, AssignedCondition, AssignedValue, MainGateInstructions, RegionCtx};
-use maingate::{MainGate, MainGateConfig};
+use maingate::{
+    halo2, power_of_two, AssignedCondition, AssignedValue, MainGateInstructions, RegionCtx, Term,
+};
+use maingate::{MainGate, MainGateConfig, PublicInputChip};
 use maingate::{RangeChip, RangeConfig};
+use num_bigint::BigUint as big_uint;
 
 mod add;
 mod assert_in_field;
 mod assert_not_zero;
 mod assert_zero;
 mod assign;
+mod bytes;
+mod compare;
 mod div;
 mod invert;
 mod mul;
+mod pow;
+mod pseudo_mersenne;
 mod reduce;
+mod relimb;
+mod sqrt;
 mod square;
 
 /// Configuration for [`IntegerChip`]
 #[derive(Clone, Debug)]
 pub struct IntegerConfig {
     /// Configuration for [`RangeChip`]
-    range_config: RangeConfig,
+    pub(crate) range_config: RangeConfig,
     /// Configuration for [`MainGate`]
-    main_gate_config: MainGateConfig,
+    pub(crate) main_gate_config: MainGateConfig,
 }
 
 impl IntegerConfig {
@@ -54,15 +64,16 @@ pub struct IntegerChip<
     main_gate: MainGate<N>,
     /// Residue number system used to represent the integers
     rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    /// Use multiplication and reduction specialized for pseudo-Mersenne
+    /// wrong moduli
+    pseudo_mersenne: bool,
 }
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
-    fn sublimb_bit_len() -> usize {
-        let number_of_lookup_limbs = 4;
-        assert!(BIT_LEN_LIMB % number_of_lookup_limbs == 0);
-        BIT_LEN_LIMB / number_of_lookup_limbs
+    fn sublimb_bit_len(&self) -> usize {
+        self.rns.bit_len_lookup
     }
 
     /// Creates a new [`AssignedInteger`] from its limb representation and its
@@ -113,23 +124,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<Vec<AssignedCondition<N>>, Error> {
         self.assert_in_field(ctx, integer)?;
-
-        let main_gate = self.main_gate();
-
-        let mut decomposed = Vec::new();
-        for idx in 0..NUMBER_OF_LIMBS {
-            let number_of_bits = if idx == NUMBER_OF_LIMBS - 1 {
-                self.rns.wrong_modulus.bits() as usize % BIT_LEN_LIMB
-            } else {
-                BIT_LEN_LIMB
-            };
-            let decomposed_limb = main_gate.to_bits(ctx, integer.limb(idx), number_of_bits)?;
-            decomposed.extend(decomposed_limb);
-        }
-
-        assert_eq!(decomposed.len(), self.rns.wrong_modulus.bits() as usize);
-
-        Ok(decomposed)
+        self.decompose_in_field(ctx, integer)
     }
 
     fn add(
@@ -237,7 +232,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
             &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
         );
-        self.mul_generic(ctx, a, b)
+        if self.pseudo_mersenne {
+            self.mul_pseudo_mersenne(ctx, a, b)
+        } else {
+            self.mul_generic(ctx, a, b)
+        }
     }
 
     fn mul_constant(
@@ -251,6 +250,36 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.mul_constant_generic(ctx, a, b)
     }
 
+    fn mul_add_many(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        products: &[(
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+        additions: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        subtractions: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let products = products
+            .iter()
+            .map(|(a, b)| {
+                Ok((
+                    self.reduce_if_limb_values_exceeds_remainder(ctx, a)?,
+                    self.reduce_if_limb_values_exceeds_remainder(ctx, b)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let additions = additions
+            .iter()
+            .map(|c| self.reduce_if_limb_values_exceeds_remainder(ctx, c))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let subtractions = subtractions
+            .iter()
+            .map(|d| self.reduce_if_limb_values_exceeds_remainder(ctx, d))
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.mul_add_many_generic(ctx, &products, &additions, &subtractions)
+    }
+
     fn mul_into_one(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -278,6 +307,28 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.square_generic(ctx, a)
     }
 
+    fn pow_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        exponent: &big_uint,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+        self.pow_constant_generic(ctx, a, exponent)
+    }
+
+    fn pow(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        exponent: &[AssignedCondition<N>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+        self.pow_generic(ctx, a, exponent)
+    }
+
     fn div(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -334,6 +385,30 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.invert_generic(ctx, a)
     }
 
+    fn batch_invert(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<
+        (
+            Vec<(
+                AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+                AssignedCondition<N>,
+            )>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        let a = a
+            .iter()
+            .map(|a| {
+                let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+                self.reduce_if_max_operand_value_exceeds(ctx, a)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.batch_invert_generic(ctx, &a)
+    }
+
     fn invert_incomplete(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -344,12 +419,41 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.invert_incomplete_generic(ctx, a)
     }
 
+    fn sqrt(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedCondition<N>,
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    > {
+        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+        self.sqrt_generic(ctx, a)
+    }
+
+    fn is_square(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let (is_square, _) = self.sqrt(ctx, a)?;
+        Ok(is_square)
+    }
+
     fn reduce(
         &self,
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        self.reduce_generic(ctx, a)
+        if self.pseudo_mersenne {
+            self.reduce_pseudo_mersenne(ctx, a)
+        } else {
+            self.reduce_generic(ctx, a)
+        }
     }
 
     fn assert_equal(
@@ -509,6 +613,48 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.assert_in_field_generic(ctx, a)
     }
 
+    fn is_zero(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        self.is_zero_generic(ctx, a)
+    }
+
+    fn is_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let c = &self.sub(ctx, a, b)?;
+        self.is_zero_generic(ctx, c)
+    }
+
+    fn is_strict_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let main_gate = self.main_gate();
+        let mut is_equal = main_gate.is_equal(ctx, a.limb(0), b.limb(0))?;
+        for idx in 1..NUMBER_OF_LIMBS {
+            let is_limb_equal = main_gate.is_equal(ctx, a.limb(idx), b.limb(idx))?;
+            is_equal = main_gate.and(ctx, &is_equal, &is_limb_equal)?;
+        }
+        Ok(is_equal)
+    }
+
+    fn is_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        self.is_less_than_generic(ctx, a, b)
+    }
+
     fn sign(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -527,10 +673,33 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         IntegerChip {
             range_chip: RangeChip::new(config.range_config),
             main_gate: MainGate::new(config.main_gate_config),
+            pseudo_mersenne: rns.pseudo_mersenne_offset.is_some(),
             rns,
         }
     }
 
+    /// Enables or disables multiplication and reduction specialized for
+    /// pseudo-Mersenne wrong moduli. It is enabled by default if [`Rns`] finds
+    /// the wrong modulus in this form, see [`Rns::pseudo_mersenne_offset`].
+    /// The specialized path is sound for any wrong modulus and saves the most
+    /// rows when the negative wrong modulus has zero limbs.
+    pub fn with_pseudo_mersenne(mut self, enable: bool) -> Self {
+        self.pseudo_mersenne = enable;
+        self
+    }
+
+    /// Estimated number of main gate rows of a multiplication in the path
+    /// this chip takes, see [`Rns::mul_row_cost`]
+    pub fn mul_row_cost(&self) -> usize {
+        self.rns.mul_row_cost(self.pseudo_mersenne)
+    }
+
+    /// Estimated number of main gate rows of a reduction in the path this
+    /// chip takes, see [`Rns::reduce_row_cost`]
+    pub fn reduce_row_cost(&self) -> usize {
+        self.rns.reduce_row_cost(self.pseudo_mersenne)
+    }
+
     /// Getter for [`RangeChip`]
     pub fn range_chip(&self) -> &RangeChip<N> {
         &self.range_chip
@@ -545,6 +714,79 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     pub fn rns(&self) -> Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> {
         Rc::clone(&self.rns)
     }
+
+    /// Reduces given integers to their in-field representatives and packs their
+    /// limbs into as few native field elements as possible following
+    /// [`Rns::packing`]. Reduced limbs are range checked so that composition
+    /// constraint of each packed element cannot overflow the native field.
+    pub fn pack(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integers: &[&AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        let main_gate = self.main_gate();
+
+        let reduced = integers
+            .iter()
+            .map(|integer| {
+                let reduced = self.reduce(ctx, integer)?;
+                self.assert_in_field(ctx, &reduced)?;
+                Ok(reduced)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let limbs: Vec<&AssignedValue<N>> = reduced
+            .iter()
+            .flat_map(|integer| (0..NUMBER_OF_LIMBS).map(move |i| integer.limb(i)))
+            .collect();
+
+        self.rns
+            .packing(integers.len())
+            .iter()
+            .map(|group| {
+                let terms: Vec<Term<N>> = group
+                    .iter()
+                    .map(|(i, shift)| Term::Assigned(limbs[*i], power_of_two(*shift)))
+                    .collect();
+                main_gate.compose(ctx, &terms, N::ZERO)
+            })
+            .collect()
+    }
+
+    /// Expose `AssignedInteger` as public inputs packing its limbs as in
+    /// [`IntegerChip::pack`]. Use `Integer::packed` to build the matching
+    /// public inputs off-circuit.
+    pub fn expose_public_packed(
+        &self,
+        mut layouter: impl Layouter<N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        offset: usize,
+    ) -> Result<(), Error> {
+        let packed = layouter.assign_region(
+            || "pack integer",
+            |region| {
+                let ctx = &mut RegionCtx::new(region, 0);
+                self.pack(ctx, &[integer])
+            },
+        )?;
+        let main_gate = self.main_gate();
+        for (i, packed) in packed.into_iter().enumerate() {
+            main_gate.expose_public(layouter.namespace(|| "packed limbs"), packed, offset + i)?;
+        }
+        Ok(())
+    }
+
+    /// Expose limbs of `AssignedInteger` as public inputs to the named slot of
+    /// the public input layout
+    pub fn expose_public_slot(
+        &self,
+        layouter: impl Layouter<N>,
+        public_input_chip: &PublicInputChip<N>,
+        name: &str,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let limbs: Vec<AssignedValue<N>> = integer.limbs().iter().map(|limb| limb.into()).collect();
+        public_input_chip.expose(layouter, name, &limbs)
+    }
 }
 
 #[cfg(test)]
@@ -553,32 +795,50 @@ mod tests {
     use crate::rns::{Common, Integer, Rns};
     use crate::{PrimeField, UnassignedInteger};
     use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::ff::{FromUniformBytes, WithSmallOrderMulGroup};
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
-    use maingate::mock_prover_verify;
     use maingate::{
-        big_to_fe, decompose_big, fe_to_big, halo2, AssignedCondition, MainGate, MainGateConfig,
-        MainGateInstructions, RangeChip, RangeConfig, RangeInstructions, RegionCtx,
+        big_to_fe, decompose_big, fe_to_big, halo2, modulus, AssignedCondition, MainGate,
+        MainGateConfig, MainGateInstructions, PublicInputChip, PublicInputConfig,
+        PublicInputLayout, RangeChip, RangeConfig, RangeInstructions, RegionCtx,
     };
+    use maingate::{mock_prover_verify, mock_prover_verify_fails};
     use num_bigint::{BigUint as big_uint, RandBigInt};
     use num_traits::Zero;
     use rand_core::OsRng;
     use std::rc::Rc;
 
-    const NUMBER_OF_LIMBS: usize = 4;
+    mod bls12_381 {
+        use ff::PrimeField;
+
+        #[derive(PrimeField)]
+        #[PrimeFieldModulus = "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787"]
+        #[PrimeFieldGenerator = "2"]
+        #[PrimeFieldReprEndianness = "little"]
+        pub struct Fq([u64; 6]);
+    }
 
-    fn rns<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>(
-    ) -> Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+    fn rns<
+        W: PrimeField,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    >() -> Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
         Rns::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct()
     }
 
-    fn setup<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>(
-    ) -> (Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, u32) {
+    fn setup<
+        W: PrimeField,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    >() -> (Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, u32) {
         let rns = rns();
         let k: u32 = (rns.bit_len_lookup + 1) as u32;
         (rns, k)
     }
 
-    impl<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>
+    impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
         From<Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>
         for UnassignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
     {
@@ -587,11 +847,18 @@ mod tests {
         }
     }
 
-    pub(crate) struct TestRNS<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> {
+    pub(crate) struct TestRNS<
+        W: PrimeField,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
         rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
     }
 
-    impl<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> TestRNS<W, N, BIT_LEN_LIMB> {
+    impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+        TestRNS<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
         pub(crate) fn rand_in_field(&self) -> Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
             Integer::from_fe(W::random(OsRng), Rc::clone(&self.rns))
         }
@@ -618,10 +885,18 @@ mod tests {
             &self,
             bit_len: usize,
         ) -> Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
-            let limbs = (0..NUMBER_OF_LIMBS)
-                .map(|_| {
-                    let el = OsRng.gen_biguint(bit_len as u64);
-                    big_to_fe(el)
+            // Limbs above the most significant limb of the wrong modulus are kept
+            // zero
+            let limbs = self
+                .rns
+                .max_remainder_limbs
+                .iter()
+                .map(|max| {
+                    if max.is_zero() {
+                        N::ZERO
+                    } else {
+                        big_to_fe(OsRng.gen_biguint(bit_len as u64))
+                    }
                 })
                 .collect::<Vec<N>>()
                 .try_into()
@@ -644,6 +919,28 @@ mod tests {
             Integer::from_limbs(e, Rc::clone(&self.rns))
         }
 
+        // `a_0 * b_0 + ... + c_0 + ... - d_0 - ...`
+        pub(crate) fn mul_add_many(
+            &self,
+            products: &[(
+                Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+                Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            )],
+            additions: &[Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+            subtractions: &[Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        ) -> Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+            let wrong_modulus = &self.rns.wrong_modulus;
+            let sum = products
+                .iter()
+                .map(|(a, b)| a.value() * b.value())
+                .chain(additions.iter().map(|c| c.value()))
+                .fold(big_uint::zero(), |acc, e| acc + e);
+            let sum = subtractions.iter().fold(sum, |acc, d| {
+                acc + wrong_modulus - (d.value() % wrong_modulus)
+            });
+            self.new_from_big(sum % wrong_modulus)
+        }
+
         pub(crate) fn max_in_remainder_range(
             &self,
         ) -> Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
@@ -673,14 +970,19 @@ mod tests {
     }
 
     impl TestCircuitConfig {
-        fn new<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>(
+        fn new<
+            W: PrimeField,
+            N: PrimeField,
+            const NUMBER_OF_LIMBS: usize,
+            const BIT_LEN_LIMB: usize,
+        >(
             meta: &mut ConstraintSystem<N>,
         ) -> Self {
             let main_gate_config = MainGate::<N>::configure(meta);
 
-            let overflow_bit_lens = rns::<W, N, BIT_LEN_LIMB>().overflow_lengths();
-            let composition_bit_len =
-                IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::sublimb_bit_len();
+            let rns = rns::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>();
+            let overflow_bit_lens = rns.overflow_lengths();
+            let composition_bit_len = rns.bit_len_lookup;
             let range_config = RangeChip::<N>::configure(
                 meta,
                 &main_gate_config,
@@ -717,22 +1019,22 @@ mod tests {
 
 
             #[derive(Clone, Debug)]
-            struct $circuit_name<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> {
+            struct $circuit_name<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> {
                 rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
             }
 
-            impl<W: PrimeField, N: PrimeField,  const BIT_LEN_LIMB: usize> $circuit_name<W, N, BIT_LEN_LIMB> {
+            impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> $circuit_name<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
                 fn integer_chip(&self, config:TestCircuitConfig) -> IntegerChip<W, N, NUMBER_OF_LIMBS,BIT_LEN_LIMB>{
                     IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(config.integer_chip_config(), Rc::clone(&self.rns))
                 }
 
-                fn tester(&self) -> TestRNS<W, N, BIT_LEN_LIMB> {
+                fn tester(&self) -> TestRNS<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
                     TestRNS {rns:Rc::clone(&self.rns)}
                 }
 
             }
 
-            impl<W: PrimeField, N: PrimeField,  const BIT_LEN_LIMB: usize> Circuit<N> for $circuit_name<W, N, BIT_LEN_LIMB> {
+            impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> Circuit<N> for $circuit_name<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
                 type Config = TestCircuitConfig;
                 type FloorPlanner = SimpleFloorPlanner;
 
@@ -741,7 +1043,7 @@ mod tests {
                 }
 
                 fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
-                    TestCircuitConfig::new::<W, N, BIT_LEN_LIMB>(meta)
+                    TestCircuitConfig::new::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
                 }
 
                 $( $synth )*
@@ -917,6 +1219,98 @@ mod tests {
                     let inv = &integer_chip.assign_integer(ctx, inv.into(), Range::Remainder)?;
                     integer_chip.mul_into_one(ctx, a, inv)?;
 
+                    let assign_all =
+                        |ctx: &mut RegionCtx<'_, N>,
+                         integers: &[Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+                         range: Range| {
+                            integers
+                                .iter()
+                                .map(|e| integer_chip.assign_integer(ctx, e.clone().into(), range))
+                                .collect::<Result<Vec<_>, Error>>()
+                        };
+
+                    // Maximum number of terms where all terms take their maximum values
+                    let number_of_terms = self.rns.max_mul_add_terms;
+                    let number_of_additions = number_of_terms / 4;
+                    let number_of_subtractions = number_of_terms / 4;
+                    let number_of_products =
+                        number_of_terms - number_of_additions - number_of_subtractions;
+                    let products = (0..number_of_products)
+                        .map(|_| (t.max_in_remainder_range(), t.max_in_remainder_range()))
+                        .collect::<Vec<_>>();
+                    let additions = (0..number_of_additions)
+                        .map(|_| t.max_in_remainder_range())
+                        .collect::<Vec<_>>();
+                    let subtractions = (0..number_of_subtractions)
+                        .map(|_| t.rand_in_remainder_range())
+                        .collect::<Vec<_>>();
+                    let c = t.mul_add_many(&products, &additions, &subtractions);
+
+                    let assigned_products = products
+                        .iter()
+                        .map(|(a, b)| {
+                            Ok((
+                                integer_chip.assign_integer(
+                                    ctx,
+                                    a.clone().into(),
+                                    Range::Remainder,
+                                )?,
+                                integer_chip.assign_integer(
+                                    ctx,
+                                    b.clone().into(),
+                                    Range::Remainder,
+                                )?,
+                            ))
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let additions = assign_all(ctx, &additions, Range::Remainder)?;
+                    let subtractions = assign_all(ctx, &subtractions, Range::Remainder)?;
+                    let c_0 = &integer_chip.assign_integer(ctx, c.into(), Range::Remainder)?;
+                    let c_1 = &integer_chip.mul_add_many(
+                        ctx,
+                        &assigned_products,
+                        &additions,
+                        &subtractions,
+                    )?;
+                    assert_eq!(c_1.max_val(), self.rns.max_remainder);
+                    integer_chip.assert_equal(ctx, c_0, c_1)?;
+                    integer_chip.assert_strict_equal(ctx, c_0, c_1)?;
+
+                    // Unreduced inputs are reduced first
+                    let products = vec![(t.rand_in_unreduced_range(), t.rand_in_unreduced_range())];
+                    let additions = vec![t.rand_in_unreduced_range()];
+                    let subtractions = vec![t.rand_in_unreduced_range()];
+                    let c = t.mul_add_many(&products, &additions, &subtractions);
+
+                    let assigned_products = products
+                        .iter()
+                        .map(|(a, b)| {
+                            Ok((
+                                integer_chip.assign_integer(
+                                    ctx,
+                                    a.clone().into(),
+                                    Range::Unreduced,
+                                )?,
+                                integer_chip.assign_integer(
+                                    ctx,
+                                    b.clone().into(),
+                                    Range::Unreduced,
+                                )?,
+                            ))
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let additions = assign_all(ctx, &additions, Range::Unreduced)?;
+                    let subtractions = assign_all(ctx, &subtractions, Range::Unreduced)?;
+                    let c_0 = &integer_chip.assign_integer(ctx, c.into(), Range::Remainder)?;
+                    let c_1 = &integer_chip.mul_add_many(
+                        ctx,
+                        &assigned_products,
+                        &additions,
+                        &subtractions,
+                    )?;
+                    integer_chip.assert_equal(ctx, c_0, c_1)?;
+                    integer_chip.assert_strict_equal(ctx, c_0, c_1)?;
+
                     Ok(())
                 },
             )?;
@@ -971,6 +1365,127 @@ mod tests {
         }
     );
 
+    impl_circuit!(
+        TestCircuitPow,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let wrong_modulus = &self.rns.wrong_modulus;
+
+                    let a = t.rand_in_unreduced_range();
+                    let exponents = [0u64, 1, 2, 3, 65537]
+                        .into_iter()
+                        .map(big_uint::from)
+                        .chain(std::iter::once(OsRng.gen_biguint(64)));
+                    for exponent in exponents {
+                        let c = a.value().modpow(&exponent, wrong_modulus);
+                        let c = t.new_from_big(c);
+
+                        let a = &integer_chip.assign_integer(
+                            ctx,
+                            a.clone().into(),
+                            Range::Unreduced,
+                        )?;
+                        let c_0 = &integer_chip.assign_integer(ctx, c.into(), Range::Remainder)?;
+                        let c_1 = &integer_chip.pow_constant(ctx, a, &exponent)?;
+                        integer_chip.assert_equal(ctx, c_0, c_1)?;
+
+                        let bits = (0..exponent.bits())
+                            .map(|i| {
+                                let bit = if exponent.bit(i) { N::ONE } else { N::ZERO };
+                                main_gate.assign_bit(ctx, Value::known(bit))
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+                        let c_1 = &integer_chip.pow(ctx, a, &bits)?;
+                        integer_chip.assert_equal(ctx, c_0, c_1)?;
+                    }
+
+                    // a^(p-2) * a = 1
+                    let a = t.rand_in_field();
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let exponent = wrong_modulus - 2usize;
+                    let a_inv = &integer_chip.pow_constant(ctx, a, &exponent)?;
+                    integer_chip.mul_into_one(ctx, a, a_inv)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitSqrt,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // square
+                    let root = W::random(OsRng);
+                    let a = t.new_from_big(fe_to_big(root.square()));
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let (is_square, root) = integer_chip.sqrt(ctx, a)?;
+                    main_gate.assert_one(ctx, &is_square)?;
+                    let must_be_a = integer_chip.square(ctx, &root)?;
+                    integer_chip.assert_equal(ctx, &must_be_a, a)?;
+
+                    // non square
+                    let non_residue = super::sqrt::non_residue::<W>();
+                    let a = W::random(OsRng).square() * non_residue;
+                    let a = t.new_from_big(fe_to_big(a));
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let is_square = integer_chip.is_square(ctx, a)?;
+                    main_gate.assert_zero(ctx, &is_square)?;
+
+                    // zero
+                    let zero =
+                        integer_chip.assign_integer(ctx, t.zero().into(), Range::Remainder)?;
+                    let (is_square, root) = integer_chip.sqrt(ctx, &zero)?;
+                    main_gate.assert_one(ctx, &is_square)?;
+                    integer_chip.assert_zero(ctx, &root)?;
+
+                    // unreduced
+                    let a = t.rand_in_unreduced_range();
+                    let expected = big_to_fe::<W>(a.value()).sqrt().is_some();
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Unreduced)?;
+                    let is_square = integer_chip.is_square(ctx, a)?;
+                    let expected = if bool::from(expected) {
+                        N::ONE
+                    } else {
+                        N::ZERO
+                    };
+                    let expected = main_gate.assign_constant(ctx, expected)?;
+                    main_gate.assert_equal(ctx, &is_square, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
     impl_circuit!(
         TestCircuitInField,
         fn synthesize(
@@ -1058,6 +1573,37 @@ mod tests {
                     // must fail
                     // integer_chip.invert_incomplete(ctx, &zero)?;
 
+                    // 1 / [a_0, a_1, ...]
+                    let integers = (0..4)
+                        .map(|_| t.rand_in_remainder_range())
+                        .collect::<Vec<_>>();
+                    let assigned = integers
+                        .iter()
+                        .map(|e| {
+                            integer_chip.assign_integer(ctx, e.clone().into(), Range::Remainder)
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let (inverses, cond) = integer_chip.batch_invert(ctx, &assigned)?;
+                    main_gate.assert_zero(ctx, &cond)?;
+                    for (e, (inv_1, cond)) in integers.iter().zip(inverses.iter()) {
+                        let inv_0 = e.invert().unwrap();
+                        let inv_0 =
+                            &integer_chip.assign_integer(ctx, inv_0.into(), Range::Remainder)?;
+                        integer_chip.assert_equal(ctx, inv_0, inv_1)?;
+                        main_gate.assert_zero(ctx, cond)?;
+                    }
+
+                    // 1 / [a, 0, p]
+                    let assigned = vec![a.clone(), zero.clone(), modulus.clone()];
+                    let (inverses, cond) = integer_chip.batch_invert(ctx, &assigned)?;
+                    main_gate.assert_one(ctx, &cond)?;
+                    integer_chip.assert_equal(ctx, inv_0, &inverses[0].0)?;
+                    main_gate.assert_zero(ctx, &inverses[0].1)?;
+                    for (must_be_one, cond) in inverses[1..].iter() {
+                        integer_chip.assert_strict_one(ctx, must_be_one)?;
+                        main_gate.assert_one(ctx, cond)?;
+                    }
+
                     // a / b
                     let a = t.rand_in_remainder_range();
                     let b = t.rand_in_remainder_range();
@@ -1477,6 +2023,98 @@ mod tests {
         }
     );
 
+    impl_circuit!(
+        TestCircuitComparison,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let wrong_modulus = &self.rns.wrong_modulus;
+                    let assert_cond = |ctx: &mut RegionCtx<'_, N>,
+                                       cond: &AssignedCondition<N>,
+                                       expected: bool| {
+                        if expected {
+                            main_gate.assert_one(ctx, cond)
+                        } else {
+                            main_gate.assert_zero(ctx, cond)
+                        }
+                    };
+
+                    // `x` and `x + p` are equal in the field but not in limbs
+                    let x = OsRng.gen_biguint_below(&(&self.rns.max_remainder - wrong_modulus));
+                    let x_0 = t.new_from_big(x.clone());
+                    let x_1 = t.new_from_big(x.clone() + wrong_modulus);
+                    let x_0 = &integer_chip.assign_integer(ctx, x_0.into(), Range::Remainder)?;
+                    let x_1 = &integer_chip.assign_integer(ctx, x_1.into(), Range::Remainder)?;
+                    let cond = integer_chip.is_equal(ctx, x_0, x_1)?;
+                    assert_cond(ctx, &cond, true)?;
+                    let cond = integer_chip.is_strict_equal(ctx, x_0, x_1)?;
+                    assert_cond(ctx, &cond, false)?;
+                    let cond = integer_chip.is_strict_equal(ctx, x_0, x_0)?;
+                    assert_cond(ctx, &cond, true)?;
+                    let cond = integer_chip.is_less_than(ctx, x_0, x_1)?;
+                    assert_cond(ctx, &cond, false)?;
+                    let cond = integer_chip.is_less_than(ctx, x_1, x_0)?;
+                    assert_cond(ctx, &cond, false)?;
+
+                    // `x + p < x + 1` in the field
+                    let y = t.new_from_big(x.clone() + 1usize);
+                    let y = &integer_chip.assign_integer(ctx, y.into(), Range::Remainder)?;
+                    let cond = integer_chip.is_less_than(ctx, x_1, y)?;
+                    assert_cond(ctx, &cond, true)?;
+                    let cond = integer_chip.is_less_than(ctx, y, x_1)?;
+                    assert_cond(ctx, &cond, false)?;
+
+                    // Zero and its non canonical representation
+                    let zero = t.new_from_big(big_uint::zero());
+                    let p = t.new_from_big(wrong_modulus.clone());
+                    let zero = &integer_chip.assign_integer(ctx, zero.into(), Range::Remainder)?;
+                    let p = &integer_chip.assign_integer(ctx, p.into(), Range::Remainder)?;
+                    let cond = integer_chip.is_zero(ctx, zero)?;
+                    assert_cond(ctx, &cond, true)?;
+                    let cond = integer_chip.is_zero(ctx, p)?;
+                    assert_cond(ctx, &cond, true)?;
+                    let cond = integer_chip.is_zero(ctx, x_1)?;
+                    assert_cond(ctx, &cond, x.is_zero())?;
+                    let cond = integer_chip.is_less_than(ctx, p, x_0)?;
+                    assert_cond(ctx, &cond, !x.is_zero())?;
+
+                    // Random values with unreduced limbs
+                    for _ in 0..4 {
+                        let a = t.rand_in_unreduced_range();
+                        let b = t.rand_in_unreduced_range();
+                        let (a_value, b_value) =
+                            (a.value() % wrong_modulus, b.value() % wrong_modulus);
+                        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Unreduced)?;
+                        let b = &integer_chip.assign_integer(ctx, b.into(), Range::Unreduced)?;
+                        let cond = integer_chip.is_equal(ctx, a, b)?;
+                        assert_cond(ctx, &cond, a_value == b_value)?;
+                        let cond = integer_chip.is_zero(ctx, a)?;
+                        assert_cond(ctx, &cond, a_value.is_zero())?;
+                        let cond = integer_chip.is_less_than(ctx, a, b)?;
+                        assert_cond(ctx, &cond, a_value < b_value)?;
+                        let cond = integer_chip.is_less_than(ctx, b, a)?;
+                        assert_cond(ctx, &cond, b_value < a_value)?;
+                        let cond = integer_chip.is_less_than(ctx, a, a)?;
+                        assert_cond(ctx, &cond, false)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
     impl_circuit!(
         TestCircuitSign,
         fn synthesize(
@@ -1512,14 +2150,251 @@ mod tests {
         }
     );
 
+    impl_circuit!(
+        TestCircuitPacking,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_field();
+                    let b = t.rand_in_unreduced_range();
+                    let packed = Integer::pack(&[&a, &b]);
+                    assert_eq!(packed.len(), self.rns.packing(2).len());
+
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Unreduced)?;
+                    let packed_0 = integer_chip.pack(ctx, &[a, b])?;
+                    for (packed_0, packed_1) in packed_0.iter().zip(packed.into_iter()) {
+                        let packed_1 = main_gate.assign_value(ctx, Value::known(packed_1))?;
+                        main_gate.assert_equal(ctx, packed_0, &packed_1)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitBytes,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let wrong_modulus = &self.rns.wrong_modulus;
+                    let number_of_bytes = ((wrong_modulus.bits() + 7) / 8) as usize;
+                    let to_bytes_le = |value: &big_uint| {
+                        let mut bytes = value.to_bytes_le();
+                        bytes.resize(number_of_bytes, 0);
+                        bytes
+                    };
+                    let assign_bytes = |ctx: &mut RegionCtx<'_, N>, bytes: &[u8]| {
+                        bytes
+                            .iter()
+                            .map(|byte| {
+                                main_gate.assign_value(ctx, Value::known(N::from(*byte as u64)))
+                            })
+                            .collect::<Result<Vec<_>, Error>>()
+                    };
+
+                    for _ in 0..2 {
+                        // Output bytes are of the in-field representative
+                        let a = t.rand_in_unreduced_range();
+                        let expected = to_bytes_le(&(a.value() % wrong_modulus));
+                        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Unreduced)?;
+                        let bytes = integer_chip.to_bytes_le(ctx, a)?;
+                        assert_eq!(bytes.len(), number_of_bytes);
+                        for (byte, expected) in bytes.iter().zip(expected.iter()) {
+                            main_gate.assert_equal_to_constant(
+                                ctx,
+                                byte,
+                                N::from(*expected as u64),
+                            )?;
+                        }
+                        let bytes_be = integer_chip.to_bytes_be(ctx, a)?;
+                        for (byte_be, byte) in bytes_be.iter().zip(bytes.iter().rev()) {
+                            main_gate.assert_equal(ctx, byte_be, byte)?;
+                        }
+
+                        // and composing them back gives the same integer
+                        let b = integer_chip.from_bytes_le(ctx, &bytes)?;
+                        integer_chip.assert_equal(ctx, a, &b)?;
+                        let b = integer_chip.from_bytes_be(ctx, &bytes_be)?;
+                        integer_chip.assert_equal(ctx, a, &b)?;
+                    }
+
+                    // Bytes may encode a value larger than the wrong modulus
+                    let bytes = vec![0xffu8; number_of_bytes];
+                    let value = big_uint::from_bytes_le(&bytes);
+                    let expected = t.new_from_big(value.clone() % wrong_modulus);
+                    let expected =
+                        &integer_chip.assign_integer(ctx, expected.into(), Range::Remainder)?;
+                    let bytes = assign_bytes(ctx, &bytes)?;
+                    let a = &integer_chip.from_bytes_be(ctx, &bytes)?;
+                    integer_chip.assert_equal(ctx, a, expected)?;
+                    let reduced = integer_chip.to_bytes_le(ctx, a)?;
+                    for (byte, expected) in reduced
+                        .iter()
+                        .zip(to_bytes_le(&(value % wrong_modulus)).iter())
+                    {
+                        main_gate.assert_equal_to_constant(ctx, byte, N::from(*expected as u64))?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitRelimb,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            let rns_out = Rc::new(rns::<W, N, 3, 88>());
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let wrong_modulus = &self.rns.wrong_modulus;
+
+                    for _ in 0..2 {
+                        let a = t.rand_in_unreduced_range();
+                        let reduced = a.value() % wrong_modulus;
+                        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Unreduced)?;
+
+                        let b = integer_chip.relimb(ctx, a, Rc::clone(&rns_out))?;
+                        let expected = Integer::from_big(reduced.clone(), Rc::clone(&rns_out));
+                        for (limb, expected) in b.limbs().iter().zip(expected.limbs().into_iter()) {
+                            main_gate.assert_equal_to_constant(ctx, limb.as_ref(), expected)?;
+                        }
+                        main_gate.assert_equal_to_constant(
+                            ctx,
+                            b.native(),
+                            big_to_fe(reduced.clone() % modulus::<N>()),
+                        )?;
+
+                        let chunks = integer_chip.split(ctx, a, 64)?;
+                        let expected = decompose_big::<N>(reduced, chunks.len(), 64);
+                        for (chunk, expected) in chunks.iter().zip(expected.into_iter()) {
+                            main_gate.assert_equal_to_constant(ctx, chunk, expected)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitPseudoMersenne<
+        W: PrimeField,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > {
+        rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+        pseudo_mersenne: bool,
+    }
+
+    impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+        Circuit<N> for TestCircuitPseudoMersenne<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.integer_chip_config(),
+                Rc::clone(&self.rns),
+            )
+            .with_pseudo_mersenne(self.pseudo_mersenne);
+            let t = TestRNS {
+                rns: Rc::clone(&self.rns),
+            };
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for _ in 0..10 {
+                        let a = t.rand_in_operand_range();
+                        let b = t.rand_in_operand_range();
+                        let c = (a.value() * b.value()) % &self.rns.wrong_modulus;
+                        let c = t.new_from_big(c);
+
+                        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Operand)?;
+                        let b = &integer_chip.assign_integer(ctx, b.into(), Range::Operand)?;
+                        let c_0 = &integer_chip.assign_integer(ctx, c.into(), Range::Remainder)?;
+                        let c_1 = &integer_chip.mul(ctx, a, b)?;
+                        integer_chip.assert_strict_equal(ctx, c_0, c_1)?;
+
+                        let a = t.rand_in_unreduced_range();
+                        let c = t.new_from_big(a.value() % &self.rns.wrong_modulus);
+
+                        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Unreduced)?;
+                        let c_0 = &integer_chip.assign_integer(ctx, c.into(), Range::Remainder)?;
+                        let c_1 = &integer_chip.reduce(ctx, a)?;
+                        integer_chip.assert_strict_equal(ctx, c_0, c_1)?;
+                    }
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    }
+
     macro_rules! test_circuit_runner {
         (
-            $circuit:ident, $([$wrong_field:ident, $native_field:ident, $bit_len_limb:expr]),*
+            $circuit:ident, $([$wrong_field:ident, $native_field:ident, $number_of_limbs:expr, $bit_len_limb:expr]),*
         ) => {
             $(
-                let (rns, _):(Rns<$wrong_field, $native_field, NUMBER_OF_LIMBS, $bit_len_limb>, u32) = setup();
+                let (rns, _):(Rns<$wrong_field, $native_field, $number_of_limbs, $bit_len_limb>, u32) = setup();
 
-                let circuit = $circuit::<$wrong_field, $native_field, $bit_len_limb> { rns: Rc::new(rns) };
+                let circuit = $circuit::<$wrong_field, $native_field, $number_of_limbs, $bit_len_limb> { rns: Rc::new(rns) };
             let instance = vec![vec![]];
             mock_prover_verify(&circuit, instance);
             )*
@@ -1533,18 +2408,24 @@ mod tests {
             use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
             use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
             use crate::curves::secp256k1::{Fp as Secp256k1Base, Fq as Secp256k1Scalar};
+            use bls12_381::Fq as Bls12381Base;
             test_circuit_runner!(
                 $circuit,
-                [PastaFp, PastaFq, 68],
-                [PastaFq, PastaFp, 68],
-                [BnBase, BnScalar, 68],
-                [BnScalar, BnScalar, 68],
-                [Secp256k1Base, BnScalar, 68],
-                [Secp256k1Base, PastaFp, 68],
-                [Secp256k1Base, PastaFq, 68],
-                [Secp256k1Scalar, BnScalar, 68],
-                [Secp256k1Scalar, PastaFp, 68],
-                [Secp256k1Scalar, PastaFq, 68]
+                [PastaFp, PastaFq, 4, 68],
+                [PastaFq, PastaFp, 4, 68],
+                [BnBase, BnScalar, 4, 68],
+                [BnScalar, BnScalar, 4, 68],
+                [Secp256k1Base, BnScalar, 4, 68],
+                [Secp256k1Base, PastaFp, 4, 68],
+                [Secp256k1Base, PastaFq, 4, 68],
+                [Secp256k1Scalar, BnScalar, 4, 68],
+                [Secp256k1Scalar, PastaFp, 4, 68],
+                [Secp256k1Scalar, PastaFq, 4, 68],
+                [BnBase, BnScalar, 3, 88],
+                [BnBase, BnScalar, 5, 68],
+                [Bls12381Base, BnScalar, 5, 104],
+                [Bls12381Base, BnScalar, 6, 88],
+                [Bls12381Base, PastaFp, 6, 88]
             );
         };
     }
@@ -1566,6 +2447,14 @@ mod tests {
         test_circuit!(TestCircuitSquaring);
     }
     #[test]
+    fn test_integer_circuit_pow() {
+        test_circuit!(TestCircuitPow);
+    }
+    #[test]
+    fn test_integer_circuit_sqrt() {
+        test_circuit!(TestCircuitSqrt);
+    }
+    #[test]
     fn test_integer_circuit_infield() {
         test_circuit!(TestCircuitInField);
     }
@@ -1590,7 +2479,193 @@ mod tests {
         test_circuit!(TestCircuitDecomposition);
     }
     #[test]
+    fn test_integer_circuit_comparison() {
+        test_circuit!(TestCircuitComparison);
+    }
+    #[test]
     fn test_integer_circuit_sign() {
         test_circuit!(TestCircuitSign);
     }
+    #[test]
+    fn test_integer_circuit_packing() {
+        test_circuit!(TestCircuitPacking);
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestPublicInputConfig {
+        circuit_config: TestCircuitConfig,
+        public_input_config: PublicInputConfig,
+    }
+
+    fn public_input_layout() -> PublicInputLayout {
+        let mut layout = PublicInputLayout::new(2);
+        layout
+            .integer("product", 1, NUMBER_OF_LIMBS)
+            .integer("a", 0, NUMBER_OF_LIMBS);
+        layout
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitPublicInput<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> {
+        rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+        a: Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    }
+
+    impl<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> Circuit<N>
+        for TestCircuitPublicInput<W, N, BIT_LEN_LIMB>
+    {
+        type Config = TestPublicInputConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            let circuit_config = TestCircuitConfig::new::<W, N, BIT_LEN_LIMB>(meta);
+            let public_input_config =
+                PublicInputChip::configure(meta, &circuit_config.main_gate_config, 2);
+            TestPublicInputConfig {
+                circuit_config,
+                public_input_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.circuit_config.integer_chip_config(),
+                Rc::clone(&self.rns),
+            );
+            let public_input_chip =
+                PublicInputChip::new(config.public_input_config, public_input_layout());
+
+            let (a, product) = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = integer_chip.assign_integer(
+                        ctx,
+                        self.a.clone().into(),
+                        Range::Remainder,
+                    )?;
+                    let b = integer_chip.assign_integer(
+                        ctx,
+                        self.b.clone().into(),
+                        Range::Remainder,
+                    )?;
+                    let product = integer_chip.mul(ctx, &a, &b)?;
+                    Ok((a, product))
+                },
+            )?;
+            integer_chip.expose_public_slot(
+                layouter.namespace(|| "product"),
+                &public_input_chip,
+                "product",
+                &product,
+            )?;
+            integer_chip.expose_public_slot(
+                layouter.namespace(|| "a"),
+                &public_input_chip,
+                "a",
+                &a,
+            )?;
+
+            config.circuit_config.config_range(&mut layouter)
+        }
+    }
+
+    #[test]
+    fn test_integer_public_input_slot() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
+
+        fn run<
+            W: PrimeField,
+            N: WithSmallOrderMulGroup<3> + FromUniformBytes<64> + Ord,
+            const BIT_LEN_LIMB: usize,
+        >() {
+            let (rns, _) = setup::<W, N, BIT_LEN_LIMB>();
+            let rns = Rc::new(rns);
+
+            let a = W::random(OsRng);
+            let b = W::random(OsRng);
+            let product = Integer::from_fe(a * b, Rc::clone(&rns));
+            let a = Integer::from_fe(a, Rc::clone(&rns));
+            let b = Integer::from_fe(b, Rc::clone(&rns));
+
+            let layout = public_input_layout();
+            let circuit = TestCircuitPublicInput::<W, N, BIT_LEN_LIMB> {
+                rns: Rc::clone(&rns),
+                a: a.clone(),
+                b: b.clone(),
+            };
+
+            let instance = layout
+                .public_inputs()
+                .set("product", &product.limbs())
+                .set("a", &a.limbs())
+                .instances();
+            mock_prover_verify(&circuit, instance);
+
+            let instance = layout
+                .public_inputs()
+                .set("product", &product.limbs())
+                .set("a", &b.limbs())
+                .instances();
+            mock_prover_verify_fails(&circuit, instance);
+        }
+
+        run::<PastaFp, PastaFq, 68>();
+        run::<BnBase, BnScalar, 68>();
+    }
+
+    #[test]
+    fn test_integer_circuit_bytes() {
+        test_circuit!(TestCircuitBytes);
+    }
+    #[test]
+    fn test_integer_circuit_pseudo_mersenne() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        use crate::curves::secp256k1::Fp as Secp256k1Base;
+        use maingate::DimensionMeasurement;
+
+        fn advice_rows<W: PrimeField>(pseudo_mersenne: bool) -> u64 {
+            let circuit = TestCircuitPseudoMersenne::<W, BnScalar, 4, 68> {
+                rns: Rc::new(rns()),
+                pseudo_mersenne,
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+            DimensionMeasurement::measure(&circuit).unwrap().advice()
+        }
+
+        // Secp256k1 base field is `2^256 - 2^32 - 977`
+        let generic = advice_rows::<Secp256k1Base>(false);
+        let pseudo_mersenne = advice_rows::<Secp256k1Base>(true);
+        assert!(pseudo_mersenne < generic);
+
+        // Specialized path is sound for a dense modulus but doesn't save rows
+        let generic = advice_rows::<BnBase>(false);
+        let pseudo_mersenne = advice_rows::<BnBase>(true);
+        assert!(pseudo_mersenne <= generic);
+    }
+
+    #[test]
+    fn test_integer_circuit_relimb() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        use crate::curves::secp256k1::{Fp as Secp256k1Base, Fq as Secp256k1Scalar};
+        test_circuit_runner!(
+            TestCircuitRelimb,
+            [BnBase, BnScalar, 4, 68],
+            [BnBase, BnScalar, 5, 68],
+            [Secp256k1Base, BnScalar, 4, 68],
+            [Secp256k1Scalar, BnScalar, 4, 68]
+        );
+    }
 }
@@ -23,18 +23,25 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
 
         // Sanity check.
         // This algorithm requires that wrong modulus * 2 <= native modulus * 2 ^
-        // bit_len_limb.
-        let two_pow_limb_bits_minus_1 =
-            big_uint::from(2u64).pow((BIT_LEN_LIMB - 1).try_into().unwrap());
-        assert!(
-            self.rns.wrong_modulus.clone()
-                <= self.rns.native_modulus.clone() * two_pow_limb_bits_minus_1
-        );
+        // (bit_len_limb * number_of_limbs). Wide wrong fields are compared with
+        // more than one limb.
+        let number_of_limbs = (1..=NUMBER_OF_LIMBS)
+            .find(|number_of_limbs| {
+                let two_pow_limb_bits_minus_1 = big_uint::from(2u64)
+                    .pow((BIT_LEN_LIMB * number_of_limbs - 1).try_into().unwrap());
+                self.rns.wrong_modulus.clone()
+                    <= self.rns.native_modulus.clone() * two_pow_limb_bits_minus_1
+            })
+            .expect("wrong modulus is too large to compare with native modulus");
 
         // r = 0 <-> r % 2 ^ 64 = 0 /\ r % native_modulus = 0
         // r <> 0 <-> r % 2 ^ 64 <> 0 \/ r % native_modulus <> 0
         // r <> 0 <-> invert(r.limb(0)) \/ invert(r.native())
-        let cond_zero_0 = main_gate.is_zero(ctx, r.limb(0))?;
+        let mut cond_zero_0 = main_gate.is_zero(ctx, r.limb(0))?;
+        for i in 1..number_of_limbs {
+            let cond = main_gate.is_zero(ctx, r.limb(i))?;
+            cond_zero_0 = main_gate.and(ctx, &cond_zero_0, &cond)?;
+        }
         let cond_zero_1 = main_gate.is_zero(ctx, r.native())?;
 
         // one of them might be succeeded, i.e. cond_zero_0 * cond_zero_1 = 0
@@ -46,21 +53,30 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         // invert(r.limb(0) - wrong_modulus[0]) \/ invert(r.native() -
         // wrong_modulus.native())
         let wrong_modulus = self.rns.wrong_modulus_decomposed;
-        let limb_diff = r.limbs[0].value().map(|value| value - wrong_modulus[0]);
-        let limb_diff = main_gate
-            .apply(
-                ctx,
-                [
-                    Term::Assigned(r.limb(0), one),
-                    Term::Unassigned(limb_diff, -one),
-                    Term::Zero,
-                    Term::Zero,
-                    Term::Zero,
-                ],
-                -wrong_modulus[0],
-                CombinationOptionCommon::OneLinerAdd.into(),
-            )?
-            .swap_remove(1);
+        let mut cond_wrong_0 = None;
+        for (limb, wrong_modulus) in r.limbs().iter().zip(wrong_modulus).take(number_of_limbs) {
+            let limb_diff = limb.value().map(|value| value - wrong_modulus);
+            let limb_diff = main_gate
+                .apply(
+                    ctx,
+                    [
+                        Term::Assigned(limb.as_ref(), one),
+                        Term::Unassigned(limb_diff, -one),
+                        Term::Zero,
+                        Term::Zero,
+                        Term::Zero,
+                    ],
+                    -wrong_modulus,
+                    CombinationOptionCommon::OneLinerAdd.into(),
+                )?
+                .swap_remove(1);
+            let cond = main_gate.is_zero(ctx, &limb_diff)?;
+            cond_wrong_0 = Some(match cond_wrong_0 {
+                Some(acc) => main_gate.and(ctx, &acc, &cond)?,
+                None => cond,
+            });
+        }
+        let cond_wrong_0 = cond_wrong_0.unwrap();
 
         let native_diff = r
             .native()
@@ -81,7 +97,6 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             )?
             .swap_remove(1);
 
-        let cond_wrong_0 = main_gate.is_zero(ctx, &limb_diff)?;
         let cond_wrong_1 = main_gate.is_zero(ctx, &native_diff)?;
 
         // one of them might be succeeded, i.e. cond_zero_0 * cond_zero_1 = 0
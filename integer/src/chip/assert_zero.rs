@@ -22,13 +22,13 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
 
         // Apply ranges
         let range_chip = self.range_chip();
-        let quotient = range_chip.assign(ctx, quotient, Self::sublimb_bit_len(), BIT_LEN_LIMB)?;
+        let quotient = range_chip.assign(ctx, quotient, self.sublimb_bit_len(), BIT_LEN_LIMB)?;
         let residues = witness
-            .residues()
+            .residues(self.rns.residue_limbs)
             .iter()
             .map(|v| {
                 let residue =
-                    range_chip.assign(ctx, *v, Self::sublimb_bit_len(), self.rns.red_v_bit_len)?;
+                    range_chip.assign(ctx, *v, self.sublimb_bit_len(), self.rns.red_v_bit_len)?;
                 Ok(residue)
             })
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
@@ -52,37 +52,19 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
 
         // Constrain residues
-        let lsh_one = self.rns.left_shifter(1);
-        let lsh_two = self.rns.left_shifter(2);
+        // v * R^k = t_0 + R * t_1 + ... + carry
+        let residue_limbs = self.rns.residue_limbs;
         let mut carry = Term::Zero;
-        for (t_chunk, v) in t.chunks(2).zip(residues.iter()) {
-            if t_chunk.len() == 2 {
-                let (t_lo, t_hi) = (&t_chunk[0], &t_chunk[1]);
-                main_gate.assert_zero_sum(
-                    ctx,
-                    &[
-                        // R^2 * v = t_lo + R * t_hi + carry
-                        Term::Assigned(t_lo, one),
-                        Term::Assigned(t_hi, lsh_one),
-                        Term::Assigned(v, -lsh_two),
-                        carry.clone(),
-                    ],
-                    zero,
-                )?;
-                carry = Term::Assigned(v, one);
-            } else {
-                let t = &t[0];
-                main_gate.assert_zero_sum(
-                    ctx,
-                    &[
-                        // R * v = t + carry
-                        Term::Assigned(t, one),
-                        Term::Assigned(v, -lsh_one),
-                        carry.clone(),
-                    ],
-                    zero,
-                )?;
-            }
+        for (t_chunk, v) in t.chunks(residue_limbs).zip(residues.iter()) {
+            let mut terms = t_chunk
+                .iter()
+                .enumerate()
+                .map(|(i, t)| Term::Assigned(t, self.rns.left_shifter(i)))
+                .collect::<Vec<Term<N>>>();
+            terms.push(Term::Assigned(v, -self.rns.left_shifter(t_chunk.len())));
+            terms.push(carry);
+            main_gate.assert_zero_sum(ctx, &terms, zero)?;
+            carry = Term::Assigned(v, one);
         }
 
         Ok(())
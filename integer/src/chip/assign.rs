@@ -5,7 +5,7 @@ use halo2::plonk::Error;
 use maingate::halo2::ff::PrimeField;
 use maingate::{fe_to_big, halo2, MainGateInstructions, RangeInstructions, RegionCtx, Term};
 use num_bigint::BigUint as big_uint;
-use num_traits::One;
+use num_traits::{One, Zero};
 use std::rc::Rc;
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
@@ -20,65 +20,45 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         let range_chip = self.range_chip();
         let main_gate = self.main_gate();
 
-        let bit_len_limb_msb = match range {
-            Range::Operand => self.rns.max_most_significant_operand_limb.bits(),
-            Range::Remainder => self.rns.max_most_significant_reduced_limb.bits(),
-            Range::MulQuotient => self.rns.max_most_significant_mul_quotient_limb.bits(),
-            Range::Unreduced => 0,
-        } as usize;
-
-        let max_val_msb = (big_uint::one() << bit_len_limb_msb) - 1usize;
-        let max_val = (big_uint::one() << BIT_LEN_LIMB) - 1usize;
+        let max_vals = match range {
+            Range::Operand => &self.rns.max_operand_limbs,
+            Range::Remainder => &self.rns.max_remainder_limbs,
+            Range::MulQuotient => &self.rns.max_mul_quotient_limbs,
+            // Unreduced limbs are allowed where remainder limbs are non zero
+            Range::Unreduced => &self.rns.max_remainder_limbs,
+        };
 
         let limbs = integer
             .0
             .map(|integer| integer.limbs())
             .transpose_vec(NUMBER_OF_LIMBS);
-        let limbs = match range {
-            Range::Unreduced => limbs
-                .into_iter()
-                .map(|limb| {
-                    Ok(AssignedLimb::from(
-                        main_gate.assign_value(ctx, limb)?,
-                        self.rns.max_unreduced_limb.clone(),
-                    ))
-                })
-                .collect::<Result<Vec<AssignedLimb<N>>, Error>>(),
-            _ => {
-                limbs
-                    .into_iter()
-                    .enumerate()
-                    .map(|(i, limb)| {
-                        Ok(
-                            // Most significant limb
-                            if i == NUMBER_OF_LIMBS - 1 {
-                                AssignedLimb::from(
-                                    range_chip.assign(
-                                        ctx,
-                                        limb,
-                                        Self::sublimb_bit_len(),
-                                        bit_len_limb_msb,
-                                    )?,
-                                    max_val_msb.clone(),
-                                )
-
-                            // Rest
-                            } else {
-                                AssignedLimb::from(
-                                    range_chip.assign(
-                                        ctx,
-                                        limb,
-                                        Self::sublimb_bit_len(),
-                                        BIT_LEN_LIMB,
-                                    )?,
-                                    max_val.clone(),
-                                )
-                            },
+        let limbs = limbs
+            .into_iter()
+            .zip(max_vals.iter())
+            .map(|(limb, max_val)| {
+                let bit_len = max_val.bits() as usize;
+                Ok(
+                    // Limbs above the most significant limb of the range are zero
+                    if bit_len == 0 {
+                        AssignedLimb::from(
+                            main_gate.assign_constant(ctx, N::ZERO)?,
+                            big_uint::zero(),
                         )
-                    })
-                    .collect::<Result<Vec<AssignedLimb<N>>, Error>>()
-            }
-        }?;
+                    } else {
+                        match range {
+                            Range::Unreduced => AssignedLimb::from(
+                                main_gate.assign_value(ctx, limb)?,
+                                self.rns.max_unreduced_limb.clone(),
+                            ),
+                            _ => AssignedLimb::from(
+                                range_chip.assign(ctx, limb, self.sublimb_bit_len(), bit_len)?,
+                                (big_uint::one() << bit_len) - 1usize,
+                            ),
+                        }
+                    },
+                )
+            })
+            .collect::<Result<Vec<AssignedLimb<N>>, Error>>()?;
 
         let limbs_to_compose: Vec<Term<N>> = limbs
             .iter()
@@ -0,0 +1,134 @@
+use super::IntegerChip;
+use crate::{AssignedByte, AssignedInteger, AssignedLimb, PrimeField};
+use halo2::plonk::Error;
+use maingate::{halo2, power_of_two, AssignedCondition, MainGateInstructions, RegionCtx, Term};
+use num_bigint::BigUint as big_uint;
+use num_traits::{One, Zero};
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Decomposes limbs of an integer that is already constrained to be in
+    /// field into `wrong_modulus.bits()` little endian bits
+    pub(super) fn decompose_in_field(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedCondition<N>>, Error> {
+        let main_gate = self.main_gate();
+
+        let mut decomposed = Vec::new();
+        let wrong_modulus_bit_len = self.rns.wrong_modulus.bits() as usize;
+        for idx in 0..NUMBER_OF_LIMBS {
+            // Limbs above the most significant limb of the wrong modulus are
+            // constant zero for an in field integer
+            let number_of_bits = std::cmp::min(
+                BIT_LEN_LIMB,
+                wrong_modulus_bit_len.saturating_sub(idx * BIT_LEN_LIMB),
+            );
+            if number_of_bits == 0 {
+                continue;
+            }
+            let decomposed_limb = main_gate.to_bits(ctx, integer.limb(idx), number_of_bits)?;
+            decomposed.extend(decomposed_limb);
+        }
+
+        assert_eq!(decomposed.len(), wrong_modulus_bit_len);
+
+        Ok(decomposed)
+    }
+
+    /// Composes an integer from little endian bytes. Each byte is decomposed
+    /// into bits and limbs are composed from these bits so that input bytes
+    /// are also constrained to be less than `2^8`. Result is not reduced, it
+    /// is the integer that bytes represent and it can be as large as
+    /// `2^(8 * bytes.len())`.
+    pub fn from_bytes_le(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        bytes: &[AssignedByte<N>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let main_gate = self.main_gate();
+        let bit_len = bytes.len() * 8;
+        assert!(!bytes.is_empty());
+        assert!(
+            bit_len <= NUMBER_OF_LIMBS * BIT_LEN_LIMB,
+            "bytes do not fit in the limbs"
+        );
+
+        let bits = bytes
+            .iter()
+            .map(|byte| main_gate.to_bits(ctx, byte, 8))
+            .collect::<Result<Vec<_>, Error>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let limbs = (0..NUMBER_OF_LIMBS)
+            .map(|idx| {
+                let offset = std::cmp::min(idx * BIT_LEN_LIMB, bit_len);
+                let bits = &bits[offset..std::cmp::min(offset + BIT_LEN_LIMB, bit_len)];
+                // Limbs above the most significant byte are zero
+                if bits.is_empty() {
+                    return Ok(AssignedLimb::from(
+                        main_gate.assign_constant(ctx, N::ZERO)?,
+                        big_uint::zero(),
+                    ));
+                }
+                let terms = bits
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| Term::Assigned(bit, power_of_two(i)))
+                    .collect::<Vec<Term<N>>>();
+                let limb = main_gate.compose(ctx, &terms, N::ZERO)?;
+                Ok(AssignedLimb::from(
+                    limb,
+                    (big_uint::one() << bits.len()) - 1usize,
+                ))
+            })
+            .collect::<Result<Vec<AssignedLimb<N>>, Error>>()?;
+
+        let limbs_to_compose: Vec<Term<N>> = limbs
+            .iter()
+            .zip(self.rns.left_shifters.iter())
+            .map(|(limb, sh)| Term::Assigned(limb.as_ref(), *sh))
+            .collect();
+        let native = main_gate.compose(ctx, &limbs_to_compose, N::ZERO)?;
+
+        Ok(self.new_assigned_integer(&limbs.try_into().unwrap(), native))
+    }
+
+    /// Composes an integer from big endian bytes. See
+    /// [`IntegerChip::from_bytes_le`].
+    pub fn from_bytes_be(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        bytes: &[AssignedByte<N>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let bytes: Vec<AssignedByte<N>> = bytes.iter().rev().cloned().collect();
+        self.from_bytes_le(ctx, &bytes)
+    }
+
+    /// Returns little endian bytes of the in-field representative of the
+    /// integer. Output is `(wrong_modulus.bits() + 7) / 8` bytes. See
+    /// [`IntegerChip::split`].
+    pub fn to_bytes_le(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedByte<N>>, Error> {
+        self.split(ctx, integer, 8)
+    }
+
+    /// Returns big endian bytes of the in-field representative of the integer.
+    /// See [`IntegerChip::to_bytes_le`].
+    pub fn to_bytes_be(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedByte<N>>, Error> {
+        let mut bytes = self.to_bytes_le(ctx, integer)?;
+        bytes.reverse();
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,117 @@
+use super::{IntegerChip, IntegerInstructions, Range};
+use crate::rns::{Common, Integer};
+use crate::{AssignedInteger, PrimeField};
+use halo2::{circuit::Value, plonk::Error};
+use maingate::{
+    big_to_fe, fe_to_big, halo2, AssignedCondition, CombinationOptionCommon, MainGateInstructions,
+    RangeInstructions, RegionCtx, Term,
+};
+use std::rc::Rc;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns the in-field representative of an [`AssignedInteger`]. Result
+    /// is constrained to be less than the wrong modulus and congruent to the
+    /// input.
+    pub(super) fn reduce_strict(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let result = a
+            .integer()
+            .map(|a| Integer::from_big(a.value() % &self.rns.wrong_modulus, Rc::clone(&self.rns)));
+        let result = self.assign_integer(ctx, result.into(), Range::Remainder)?;
+        self.assert_in_field_generic(ctx, &result)?;
+        self.assert_equal(ctx, a, &result)?;
+        Ok(result)
+    }
+
+    pub(super) fn is_zero_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let main_gate = self.main_gate();
+        let a = self.reduce_strict(ctx, a)?;
+
+        // Limbs are range checked so that their sum is zero only if all limbs
+        // are zero
+        let terms = a
+            .limbs()
+            .iter()
+            .map(|limb| Term::assigned_to_add(limb.as_ref()))
+            .collect::<Vec<Term<N>>>();
+        let sum = main_gate.compose(ctx, &terms, N::ZERO)?;
+        main_gate.is_zero(ctx, &sum)
+    }
+
+    pub(super) fn is_less_than_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let main_gate = self.main_gate();
+        let range_chip = self.range_chip();
+        let one = N::ONE;
+        let (a, b) = (&self.reduce_strict(ctx, a)?, &self.reduce_strict(ctx, b)?);
+
+        // Both integers are below `2^(n*B)` so the carry out of
+        // `a + (2^(n*B) - 1 - b) + 1` is set if and only if `a >= b`
+        //
+        // Constraints for `NUMBER_OF_LIMBS = 4`
+        // 0 = a_0 - b_0 + R           - s_0 - c_0 * R
+        // 0 = a_1 - b_1 + R - 1 + c_0 - s_1 - c_1 * R
+        // 0 = a_2 - b_2 + R - 1 + c_1 - s_2 - c_2 * R
+        // 0 = a_3 - b_3 + R - 1 + c_2 - s_3 - c_3 * R
+        let left_shifter = self.rns.left_shifter(1);
+        let mut carry: Option<AssignedCondition<N>> = None;
+        for i in 0..NUMBER_OF_LIMBS {
+            let constant = if i == 0 {
+                left_shifter
+            } else {
+                left_shifter - one
+            };
+            let carry_value = carry
+                .as_ref()
+                .map(|carry| carry.value().copied())
+                .unwrap_or_else(|| Value::known(N::ZERO));
+            let (sum, carry_out) = a
+                .limb(i)
+                .value()
+                .zip(b.limb(i).value())
+                .zip(carry_value)
+                .map(|((a, b), carry)| {
+                    let sum = fe_to_big(*a - *b + constant + carry);
+                    (
+                        big_to_fe::<N>(&sum % fe_to_big(left_shifter)),
+                        big_to_fe::<N>(sum >> BIT_LEN_LIMB),
+                    )
+                })
+                .unzip();
+            let sum = range_chip.assign(ctx, sum, self.sublimb_bit_len(), BIT_LEN_LIMB)?;
+            let carry_out = main_gate.assign_bit(ctx, carry_out)?;
+            main_gate.apply(
+                ctx,
+                [
+                    Term::Assigned(a.limb(i), one),
+                    Term::Assigned(b.limb(i), -one),
+                    carry
+                        .as_ref()
+                        .map(|carry| Term::Assigned(carry, one))
+                        .unwrap_or(Term::Zero),
+                    Term::Assigned(&sum, -one),
+                    Term::Assigned(&carry_out, -left_shifter),
+                ],
+                constant,
+                CombinationOptionCommon::OneLinerAdd.into(),
+            )?;
+            carry = Some(carry_out);
+        }
+
+        let is_greater_or_equal = carry.unwrap();
+        main_gate.not(ctx, &is_greater_or_equal)
+    }
+}
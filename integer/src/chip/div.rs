@@ -58,9 +58,9 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
 
         let quotient = &self.assign_integer(ctx, quotient.into(), Range::MulQuotient)?;
         let residues = witness
-            .residues()
+            .residues(self.rns.residue_limbs)
             .iter()
-            .map(|v| range_chip.assign(ctx, *v, Self::sublimb_bit_len(), self.rns.mul_v_bit_len))
+            .map(|v| range_chip.assign(ctx, *v, self.sublimb_bit_len(), self.rns.mul_v_bit_len))
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
 
         let mut t: Vec<AssignedValue<N>> = vec![];
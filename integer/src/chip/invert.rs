@@ -1,9 +1,11 @@
 use super::{IntegerChip, IntegerInstructions, Range};
-use crate::{rns::Integer, AssignedInteger, PrimeField};
+use crate::rns::{Common, Integer};
+use crate::{AssignedInteger, PrimeField};
 use halo2::plonk::Error;
 use maingate::{
     halo2, AssignedCondition, CombinationOptionCommon, MainGateInstructions, RegionCtx, Term,
 };
+use num_traits::Zero;
 use std::rc::Rc;
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
@@ -88,4 +90,62 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.mul_into_one(ctx, a, &inv)?;
         Ok(inv)
     }
+
+    pub(super) fn batch_invert_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<
+        (
+            Vec<(
+                AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+                AssignedCondition<N>,
+            )>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        assert!(!a.is_empty());
+        let main_gate = self.main_gate();
+        let zero = &self.assign_constant(ctx, W::ZERO)?;
+        let one = &self.assign_constant(ctx, W::ONE)?;
+
+        // Inversion in the wrong field is a witness and a single multiplication
+        // check so that prefix products wouldn't save any multiplication here.
+        // Each inverse is witnessed and checked with `mul_into_one` instead.
+        let mut inverses = Vec::with_capacity(a.len());
+        let mut has_zero: Option<AssignedCondition<N>> = None;
+        for a in a.iter() {
+            let is_zero = a.integer().map(|a| {
+                if (a.value() % &self.rns.wrong_modulus).is_zero() {
+                    N::ONE
+                } else {
+                    N::ZERO
+                }
+            });
+            let is_zero = main_gate.assign_bit(ctx, is_zero)?;
+
+            // Flagged entries must be zero
+            let must_be_zero = self.select(ctx, a, zero, &is_zero)?;
+            self.assert_zero_generic(ctx, &must_be_zero)?;
+
+            // and are replaced with one so that every entry has an inverse
+            let a_or_one = &self.select(ctx, one, a, &is_zero)?;
+            let inv_or_one = a_or_one.integer().map(|a_or_one| {
+                a_or_one
+                    .invert()
+                    .unwrap_or_else(|| Integer::from_big(1u32.into(), Rc::clone(&self.rns)))
+            });
+            let inv_or_one = self.assign_integer(ctx, inv_or_one.into(), Range::Remainder)?;
+            self.mul_into_one(ctx, a_or_one, &inv_or_one)?;
+
+            has_zero = Some(match has_zero {
+                None => is_zero.clone(),
+                Some(has_zero) => main_gate.or(ctx, &has_zero, &is_zero)?,
+            });
+            inverses.push((inv_or_one, is_zero));
+        }
+
+        Ok((inverses, has_zero.unwrap()))
+    }
 }
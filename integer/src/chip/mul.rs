@@ -1,11 +1,12 @@
 use super::{IntegerChip, IntegerInstructions, Range};
 use crate::rns::{Common, Integer, MaybeReduced};
 use crate::{AssignedInteger, PrimeField};
-use halo2::{arithmetic::Field, plonk::Error};
+use halo2::{arithmetic::Field, circuit::Value, plonk::Error};
 use maingate::{
     halo2, AssignedValue, CombinationOptionCommon, MainGateInstructions, RangeInstructions,
     RegionCtx, Term,
 };
+use std::rc::Rc;
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
@@ -21,45 +22,27 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         let (zero, one) = (N::ZERO, N::ONE);
 
         // Constrain residues
-        let lsh_one = self.rns.left_shifter(1);
-        let lsh_two = self.rns.left_shifter(2);
+        // v * R^k = t_0 + R * t_1 + ... - r_0 - R * r_1 - ... + carry
+        let residue_limbs = self.rns.residue_limbs;
         let mut carry = Term::Zero;
-
         for ((t_chunk, r_chunk), v) in t
-            .chunks(2)
-            .zip(result.limbs().chunks(2))
+            .chunks(residue_limbs)
+            .zip(result.limbs().chunks(residue_limbs))
             .zip(residues.iter())
         {
-            if t_chunk.len() == 2 {
-                let (t_lo, t_hi) = (&t_chunk[0], &t_chunk[1]);
-                let (r_lo, r_hi) = (r_chunk[0].as_ref(), r_chunk[1].as_ref());
-                main_gate.assert_zero_sum(
-                    ctx,
-                    &[
-                        // v * R^2 = t_lo + R * t_hi  + r_lo + R * r_hi + carry
-                        Term::Assigned(t_lo, one),
-                        Term::Assigned(t_hi, lsh_one),
-                        Term::Assigned(r_lo, -one),
-                        Term::Assigned(r_hi, -lsh_one),
-                        Term::Assigned(v, -lsh_two),
-                        carry.clone(),
-                    ],
-                    zero,
-                )?;
-                carry = Term::Assigned(v, one);
-            } else {
-                main_gate.assert_zero_sum(
-                    ctx,
-                    &[
-                        // R * v = t + carry
-                        Term::Assigned(&t_chunk[0], one),
-                        Term::Assigned(r_chunk[0].as_ref(), one),
-                        Term::Assigned(v, -lsh_one),
-                        carry.clone(),
-                    ],
-                    zero,
-                )?;
-            }
+            let mut terms = t_chunk
+                .iter()
+                .zip(r_chunk.iter())
+                .enumerate()
+                .flat_map(|(i, (t, r))| {
+                    let lsh = self.rns.left_shifter(i);
+                    [Term::Assigned(t, lsh), Term::Assigned(r.as_ref(), -lsh)]
+                })
+                .collect::<Vec<Term<N>>>();
+            terms.push(Term::Assigned(v, -self.rns.left_shifter(t_chunk.len())));
+            terms.push(carry);
+            main_gate.assert_zero_sum(ctx, &terms, zero)?;
+            carry = Term::Assigned(v, one);
         }
         Ok(())
     }
@@ -90,9 +73,9 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
 
         let quotient = &self.assign_integer(ctx, quotient.into(), Range::MulQuotient)?;
         let residues = witness
-            .residues()
+            .residues(self.rns.residue_limbs)
             .iter()
-            .map(|v| range_chip.assign(ctx, *v, Self::sublimb_bit_len(), self.rns.mul_v_bit_len))
+            .map(|v| range_chip.assign(ctx, *v, self.sublimb_bit_len(), self.rns.mul_v_bit_len))
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
 
         // Witness layout for `NUMBER_OF_LIMBS = 4`:
@@ -215,9 +198,9 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         let quotient = &self.assign_integer(ctx, quotient.into(), Range::MulQuotient)?;
         let result = self.assign_integer(ctx, result.into(), Range::Remainder)?;
         let residues = witness
-            .residues()
+            .residues(self.rns.residue_limbs)
             .iter()
-            .map(|v| range_chip.assign(ctx, *v, Self::sublimb_bit_len(), self.rns.mul_v_bit_len))
+            .map(|v| range_chip.assign(ctx, *v, self.sublimb_bit_len(), self.rns.mul_v_bit_len))
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
 
         // Assign intermediate values
@@ -290,9 +273,9 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         let range_chip = self.range_chip();
         let quotient = &self.assign_integer(ctx, quotient.into(), Range::MulQuotient)?;
         let residues = witness
-            .residues()
+            .residues(self.rns.residue_limbs)
             .iter()
-            .map(|v| range_chip.assign(ctx, *v, Self::sublimb_bit_len(), self.rns.mul_v_bit_len))
+            .map(|v| range_chip.assign(ctx, *v, self.sublimb_bit_len(), self.rns.mul_v_bit_len))
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
 
         let mut t: Vec<AssignedValue<N>> = vec![];
@@ -351,38 +334,19 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         }
 
         // Constrain binary part of crt
-        let lsh_one = self.rns.left_shifter(1);
-        let lsh_two = self.rns.left_shifter(2);
+        // v * R^k = t_0 + R * t_1 + ... - 1 + carry
+        let residue_limbs = self.rns.residue_limbs;
         let mut carry = Term::Zero;
-
-        for (i, (t_chunk, v)) in t.chunks(2).zip(residues.iter()).enumerate() {
-            if t_chunk.len() == 2 {
-                let (t_lo, t_hi) = (&t_chunk[0], &t_chunk[1]);
-                main_gate.assert_zero_sum(
-                    ctx,
-                    &[
-                        // R^2 * v = t_lo - 1 + R * t_hi
-                        Term::Assigned(t_lo, one),
-                        Term::Assigned(t_hi, lsh_one),
-                        Term::Assigned(v, -lsh_two),
-                        carry.clone(),
-                    ],
-                    if i == 0 { -one } else { zero },
-                )?;
-                carry = Term::Assigned(v, one);
-            } else {
-                let t = &t[0];
-                main_gate.assert_zero_sum(
-                    ctx,
-                    &[
-                        // R * v = t + carry
-                        Term::Assigned(t, one),
-                        Term::Assigned(v, -lsh_one),
-                        carry.clone(),
-                    ],
-                    zero,
-                )?;
-            }
+        for (i, (t_chunk, v)) in t.chunks(residue_limbs).zip(residues.iter()).enumerate() {
+            let mut terms = t_chunk
+                .iter()
+                .enumerate()
+                .map(|(j, t)| Term::Assigned(t, self.rns.left_shifter(j)))
+                .collect::<Vec<Term<N>>>();
+            terms.push(Term::Assigned(v, -self.rns.left_shifter(t_chunk.len())));
+            terms.push(carry);
+            main_gate.assert_zero_sum(ctx, &terms, if i == 0 { -one } else { zero })?;
+            carry = Term::Assigned(v, one);
         }
 
         // Constrain native part of crt
@@ -401,4 +365,201 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
 
         Ok(())
     }
+
+    // Accumulates `sum_i (x_i * y_i) + sum_j terms_j + constant` in a running
+    // sum where each row takes a single product and at most two linear terms
+    //
+    // | A   | B   | C       | D       | E     |
+    // | --- | --- | ------- | ------- | ----- |
+    // | x_0 | y_0 | terms_0 | terms_1 | sum   |
+    // | x_1 | y_1 | terms_2 | terms_3 | tmp_a |
+    // | x_2 | y_2 | -       | -       | tmp_b |
+    fn sum_of_products(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        products: &[(&AssignedValue<N>, &AssignedValue<N>)],
+        terms: &[Term<'_, N>],
+        constant: N,
+    ) -> Result<AssignedValue<N>, Error> {
+        let main_gate = self.main_gate();
+        let (zero, one) = (N::ZERO, N::ONE);
+
+        let number_of_rows = products.len().max((terms.len() + 1) / 2).max(1);
+        let rows = (0..number_of_rows)
+            .map(|i| {
+                let (x, y) = products
+                    .get(i)
+                    .map(|(x, y)| (Term::Assigned(x, zero), Term::Assigned(y, zero)))
+                    .unwrap_or((Term::Zero, Term::Zero));
+                let mut terms = terms.iter().skip(2 * i).take(2).cloned();
+                let c = terms.next().unwrap_or(Term::Zero);
+                let d = terms.next().unwrap_or(Term::Zero);
+                [x, y, c, d]
+            })
+            .collect::<Vec<_>>();
+
+        // Running sum values from the last row to the first one
+        let mut sums = rows
+            .iter()
+            .enumerate()
+            .rev()
+            .scan(Value::known(zero), |sum, (i, [x, y, c, d])| {
+                let constant = if i == 0 { constant } else { zero };
+                *sum = *sum
+                    + x.coeff() * y.coeff()
+                    + c.coeff() * Value::known(c.base())
+                    + d.coeff() * Value::known(d.base())
+                    + Value::known(constant);
+                Some(*sum)
+            })
+            .collect::<Vec<_>>();
+        sums.reverse();
+
+        let mut sum = None;
+        for (i, ([x, y, c, d], e)) in rows.into_iter().zip(sums.into_iter()).enumerate() {
+            let combination_option = if i == number_of_rows - 1 {
+                CombinationOptionCommon::OneLinerMul
+            } else {
+                CombinationOptionCommon::CombineToNextMul(one)
+            }
+            .into();
+            let constant = if i == 0 { constant } else { zero };
+            let e = main_gate
+                .apply(
+                    ctx,
+                    [x, y, c, d, Term::Unassigned(e, -one)],
+                    constant,
+                    combination_option,
+                )?
+                .swap_remove(4);
+            if i == 0 {
+                sum = Some(e);
+            }
+        }
+        Ok(sum.unwrap())
+    }
+
+    pub(super) fn mul_add_many_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        products: &[(
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+        additions: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        subtractions: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let number_of_terms = products.len() + additions.len() + subtractions.len();
+        assert!(number_of_terms > 0);
+        assert!(
+            number_of_terms <= self.rns.max_mul_add_terms,
+            "too many terms in sum of products"
+        );
+        let one = N::ONE;
+
+        let negative_wrong_modulus = self.rns.negative_wrong_modulus_decomposed;
+
+        // Sum of auxillary values that keeps subtracted limbs positive
+        let aux = subtractions
+            .iter()
+            .fold(vec![N::ZERO; NUMBER_OF_LIMBS], |acc, d| {
+                let aux = Integer::subtracion_aux(&d.max_vals(), Rc::clone(&self.rns));
+                acc.iter()
+                    .zip(aux.limbs())
+                    .map(|(acc, aux)| *acc + aux)
+                    .collect()
+            });
+        let aux: [N; NUMBER_OF_LIMBS] = aux.try_into().unwrap();
+        let aux = Integer::from_limbs(&aux, Rc::clone(&self.rns));
+
+        let witness: MaybeReduced<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> = products
+            .iter()
+            .map(|(a, b)| a.integer().zip(b.integer()))
+            .collect::<Value<Vec<_>>>()
+            .zip(
+                additions
+                    .iter()
+                    .map(|c| c.integer())
+                    .collect::<Value<Vec<_>>>(),
+            )
+            .zip(
+                subtractions
+                    .iter()
+                    .map(|d| d.integer())
+                    .collect::<Value<Vec<_>>>(),
+            )
+            .map(|((products, additions), subtractions)| {
+                Integer::mul_add_many(&products, &additions, &subtractions, &aux)
+            })
+            .into();
+        let result = witness.result();
+        let quotient = witness.long();
+
+        // Apply ranges
+        let range_chip = self.range_chip();
+        let result = self.assign_integer(ctx, result.into(), Range::Remainder)?;
+        let quotient = &self.assign_integer(ctx, quotient.into(), Range::MulQuotient)?;
+        let residues = witness
+            .residues(self.rns.residue_limbs)
+            .iter()
+            .map(|v| range_chip.assign(ctx, *v, self.sublimb_bit_len(), self.rns.mul_add_v_bit_len))
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        // Assign intermediate values
+        // `t_k = sum_i sum_j a_i_j * b_i_(k-j) + sum_j p'_(k-j) * q_j + c_k - d_k +
+        // aux_k`
+        let t = witness
+            .intermediates()
+            .into_iter()
+            .enumerate()
+            .map(|(k, intermediate_value)| {
+                let limb_products = products
+                    .iter()
+                    .flat_map(|(a, b)| (0..=k).map(move |j| (a.limb(j), b.limb(k - j))))
+                    .collect::<Vec<_>>();
+                let terms = (0..=k)
+                    .map(|j| Term::Assigned(quotient.limb(j), negative_wrong_modulus[k - j]))
+                    .chain(additions.iter().map(|c| Term::Assigned(c.limb(k), one)))
+                    .chain(subtractions.iter().map(|d| Term::Assigned(d.limb(k), -one)))
+                    .collect::<Vec<_>>();
+                let t_k = self.sum_of_products(ctx, &limb_products, &terms, aux.limb(k).fe())?;
+
+                // Sanity check for the accumulated value
+                t_k.value()
+                    .zip(intermediate_value)
+                    .assert_if_known(|(t_k, intermediate_value)| **t_k == *intermediate_value);
+                Ok(t_k)
+            })
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        // Constrain binary part of crt
+        self.constrain_binary_crt(
+            ctx,
+            &t.try_into()
+                .expect("Unexpected failure in AssignedCell -> AssignedValue conversion"),
+            &result,
+            residues,
+        )?;
+
+        // Constrain native part of crt
+        let native_products = products
+            .iter()
+            .map(|(a, b)| (a.native(), b.native()))
+            .collect::<Vec<_>>();
+        let terms = std::iter::once(Term::Assigned(
+            quotient.native(),
+            -self.rns.wrong_modulus_in_native_modulus,
+        ))
+        .chain(additions.iter().map(|c| Term::Assigned(c.native(), one)))
+        .chain(
+            subtractions
+                .iter()
+                .map(|d| Term::Assigned(d.native(), -one)),
+        )
+        .collect::<Vec<_>>();
+        let native = self.sum_of_products(ctx, &native_products, &terms, aux.native())?;
+        ctx.constrain_equal(native.cell(), result.native().cell())?;
+
+        Ok(result)
+    }
 }
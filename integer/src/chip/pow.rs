@@ -0,0 +1,81 @@
+use super::{IntegerChip, IntegerInstructions};
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{addition_chain, halo2, AssignedCondition, RegionCtx};
+use num_bigint::BigUint as big_uint;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn pow_constant_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        exponent: &big_uint,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let chain = addition_chain(&exponent.to_u64_digits());
+        if chain.is_empty() {
+            return if exponent.bits() == 0 {
+                self.assign_constant(ctx, W::ONE)
+            } else {
+                Ok(a.clone())
+            };
+        }
+
+        // Intermediate values are not reduced on their own. Each product is
+        // left in remainder range by its crt check which is already a valid
+        // operand, so reduction checks of the operands are deferred to the
+        // input of the chain and are not repeated at every step
+        let mut elements = vec![a.clone()];
+        for (i, j) in chain {
+            let element = if i == j {
+                self.square_generic(ctx, &elements[i])?
+            } else {
+                self.mul_generic(ctx, &elements[i], &elements[j])?
+            };
+            elements.push(element);
+        }
+        Ok(elements.pop().unwrap())
+    }
+
+    pub(super) fn pow_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        exponent: &[AssignedCondition<N>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let one = self.assign_constant(ctx, W::ONE)?;
+        if exponent.is_empty() {
+            return Ok(one);
+        }
+
+        // Multiplication by the base is deferred to 2 bit windows so that a
+        // product is paid once per window rather than once per bit. Table
+        // holds `a^0, a^1, a^2, a^3`
+        let a_2 = self.square_generic(ctx, a)?;
+        let a_3 = self.mul_generic(ctx, &a_2, a)?;
+        let table = [one.clone(), a.clone(), a_2, a_3];
+
+        // Leading bit is consumed alone if the exponent has odd length
+        let (windows, head) = exponent.split_at(exponent.len() - exponent.len() % 2);
+        let mut acc = head
+            .first()
+            .map(|bit| self.select(ctx, a, &one, bit))
+            .transpose()?;
+        for window in windows.chunks(2).rev() {
+            let (lo, hi) = (&window[0], &window[1]);
+            let t_0 = self.select(ctx, &table[1], &table[0], lo)?;
+            let t_1 = self.select(ctx, &table[3], &table[2], lo)?;
+            let selected = self.select(ctx, &t_1, &t_0, hi)?;
+            acc = Some(match acc {
+                None => selected,
+                Some(acc) => {
+                    let acc = self.square_generic(ctx, &acc)?;
+                    let acc = self.square_generic(ctx, &acc)?;
+                    self.mul_generic(ctx, &acc, &selected)?
+                }
+            });
+        }
+        Ok(acc.unwrap())
+    }
+}
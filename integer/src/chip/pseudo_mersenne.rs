@@ -0,0 +1,284 @@
+use super::{IntegerChip, IntegerInstructions, Range};
+use crate::rns::{Common, MaybeReduced};
+use crate::{AssignedInteger, PrimeField};
+use halo2::{arithmetic::Field, plonk::Error};
+use maingate::{
+    halo2, AssignedValue, CombinationOption, CombinationOptionCommon, MainGateInstructions,
+    RangeInstructions, RegionCtx, Term,
+};
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Multiplication for pseudo-Mersenne wrong moduli `p = 2^k - c`.
+    ///
+    /// Same as [`IntegerChip::mul_generic`] but negative wrong modulus limbs
+    /// between the first limb and the limb of bit `k` are zero. Products
+    /// `a_j * b_k` that come with no quotient term are paired in rows with
+    /// both multiplication gates open.
+    pub(super) fn mul_pseudo_mersenne(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let main_gate = self.main_gate();
+        let (zero, one) = (N::ZERO, N::ONE);
+
+        let negative_wrong_modulus = self.rns.negative_wrong_modulus_decomposed;
+
+        let witness: MaybeReduced<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> = a
+            .integer()
+            .zip(b.integer())
+            .map(|(a_int, b_int)| a_int.mul(&b_int))
+            .into();
+        let result = witness.result();
+        let quotient = witness.long();
+
+        // Apply ranges
+        let range_chip = self.range_chip();
+        let result = self.assign_integer(ctx, result.into(), Range::Remainder)?;
+
+        let quotient = &self.assign_integer(ctx, quotient.into(), Range::MulQuotient)?;
+        let residues = witness
+            .residues(self.rns.residue_limbs)
+            .iter()
+            .map(|v| range_chip.assign(ctx, *v, self.sublimb_bit_len(), self.rns.mul_v_bit_len))
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        // Witness layout for `NUMBER_OF_LIMBS = 4` and `p' = [p'_0, 0, 0, p'_3]`:
+        // | A   | B   | C   | D     | E     |
+        // | --- | --- | --- | ----- | ----- |
+        // | a_0 | b_0 | q_0 | -     | t_0   |
+
+        // | a_0 | b_1 | q_1 | -     | t_1   |
+        // | a_1 | b_0 | -   | -     | tmp   |
+
+        // | a_0 | b_2 | q_2 | -     | t_2   |
+        // | a_1 | b_1 | a_2 | b_0   | tmp   |
+
+        // | a_0 | b_3 | q_3 | q_0   | t_3   |
+        // | a_1 | b_2 | a_2 | b_1   | tmp_a |
+        // | a_3 | b_0 | -   | -     | tmp_b |
+
+        let mut t: Vec<AssignedValue<N>> = vec![];
+
+        // Assign intermediate values
+        for (i, intermediate_value) in witness.intermediates().into_iter().enumerate() {
+            let mut intermediate_value = intermediate_value;
+
+            // Reversed so that popping starts from `j = 0`
+            let mut products: Vec<(&AssignedValue<N>, &AssignedValue<N>)> =
+                (0..=i).rev().map(|j| (a.limb(j), b.limb(i - j))).collect();
+            let mut quotient_terms: Vec<Term<N>> = (0..=i)
+                .rev()
+                .filter(|j| !negative_wrong_modulus[*j].is_zero_vartime())
+                .map(|j| Term::Assigned(quotient.limb(i - j), negative_wrong_modulus[j]))
+                .collect();
+
+            // Quotient terms take the free columns of single multiplication
+            // rows and the rest of the products are paired
+            let mut rows: Vec<([Term<N>; 4], usize)> = vec![];
+            while !products.is_empty() || !quotient_terms.is_empty() {
+                let row = if !quotient_terms.is_empty() {
+                    match products.pop() {
+                        Some((a_j, b_k)) => (
+                            [
+                                Term::Assigned(a_j, zero),
+                                Term::Assigned(b_k, zero),
+                                quotient_terms.pop().unwrap_or(Term::Zero),
+                                quotient_terms.pop().unwrap_or(Term::Zero),
+                            ],
+                            1,
+                        ),
+                        None => (
+                            [(); 4].map(|_| quotient_terms.pop().unwrap_or(Term::Zero)),
+                            0,
+                        ),
+                    }
+                } else {
+                    let (a_0, b_0) = products.pop().unwrap();
+                    match products.pop() {
+                        Some((a_1, b_1)) => (
+                            [
+                                Term::Assigned(a_0, zero),
+                                Term::Assigned(b_0, zero),
+                                Term::Assigned(a_1, zero),
+                                Term::Assigned(b_1, zero),
+                            ],
+                            2,
+                        ),
+                        None => (
+                            [
+                                Term::Assigned(a_0, zero),
+                                Term::Assigned(b_0, zero),
+                                Term::Zero,
+                                Term::Zero,
+                            ],
+                            1,
+                        ),
+                    }
+                };
+                rows.push(row);
+            }
+
+            let number_of_rows = rows.len();
+            for (row_index, (terms, number_of_products)) in rows.into_iter().enumerate() {
+                let is_last = row_index + 1 == number_of_rows;
+                let combination_option = match (number_of_products, is_last) {
+                    (2, false) => CombinationOption::CombineToNextDoubleMul(one),
+                    (2, true) => CombinationOption::OneLinerDoubleMul(one),
+                    (1, false) => CombinationOptionCommon::CombineToNextMul(one).into(),
+                    (1, true) => CombinationOptionCommon::OneLinerMul.into(),
+                    (_, false) => CombinationOptionCommon::CombineToNextAdd(one).into(),
+                    (_, true) => CombinationOptionCommon::OneLinerAdd.into(),
+                };
+
+                let row_value = match number_of_products {
+                    2 => terms[0]
+                        .coeff()
+                        .zip(terms[1].coeff())
+                        .zip(terms[2].coeff())
+                        .zip(terms[3].coeff())
+                        .map(|(((a_0, b_0), a_1), b_1)| a_0 * b_0 + a_1 * b_1),
+                    1 => terms[0]
+                        .coeff()
+                        .zip(terms[1].coeff())
+                        .zip(Term::compose(&terms[2..], zero))
+                        .map(|((a, b), q)| a * b + q),
+                    _ => Term::compose(&terms, zero),
+                };
+
+                let [t_a, t_b, t_c, t_d] = terms;
+                let t_i = main_gate
+                    .apply(
+                        ctx,
+                        [
+                            t_a,
+                            t_b,
+                            t_c,
+                            t_d,
+                            Term::Unassigned(intermediate_value, -one),
+                        ],
+                        zero,
+                        combination_option,
+                    )?
+                    .swap_remove(4);
+
+                if row_index == 0 {
+                    // first time we see t_i assignment
+                    t.push(t_i);
+                }
+
+                // update running temp value
+                intermediate_value = intermediate_value
+                    .zip(row_value)
+                    .map(|(t, row_value)| t - row_value);
+
+                // Sanity check for the last running subtraction value
+                {
+                    if is_last {
+                        intermediate_value.assert_if_known(Field::is_zero_vartime);
+                    }
+                }
+            }
+        }
+
+        // Constrain binary part of crt
+        self.constrain_binary_crt(
+            ctx,
+            &t.try_into()
+                .expect("Unexpected failure in AssignedCell -> AssignedValue conversion"),
+            &result,
+            residues,
+        )?;
+
+        // Constrain native part of crt
+        main_gate.apply(
+            ctx,
+            [
+                Term::Assigned(a.native(), zero),
+                Term::Assigned(b.native(), zero),
+                Term::Assigned(quotient.native(), -self.rns.wrong_modulus_in_native_modulus),
+                Term::Zero,
+                Term::Assigned(result.native(), -one),
+            ],
+            zero,
+            CombinationOptionCommon::OneLinerMul.into(),
+        )?;
+
+        Ok(result)
+    }
+
+    /// Reduction for pseudo-Mersenne wrong moduli `p = 2^k - c`.
+    ///
+    /// Same as [`IntegerChip::reduce_generic`] but intermediate values are
+    /// not assigned where the negative wrong modulus limb is zero since they
+    /// are equal to the limbs of the input.
+    pub(super) fn reduce_pseudo_mersenne(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let main_gate = self.main_gate();
+        let (zero, one) = (N::ZERO, N::ONE);
+
+        let witness: MaybeReduced<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> =
+            a.integer().as_ref().map(|a_int| a_int.reduce()).into();
+        let quotient = witness.short();
+        let result = witness.result();
+
+        // Apply ranges
+        let range_chip = self.range_chip();
+        let result = self.assign_integer(ctx, result.into(), Range::Remainder)?;
+        let quotient = range_chip.assign(ctx, quotient, self.sublimb_bit_len(), BIT_LEN_LIMB)?;
+        let residues = witness
+            .residues(self.rns.residue_limbs)
+            .iter()
+            .map(|v| range_chip.assign(ctx, *v, self.sublimb_bit_len(), self.rns.red_v_bit_len))
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        // Assign intermediate values
+        let t: Vec<AssignedValue<N>> = a
+            .limbs()
+            .iter()
+            .zip(self.rns.negative_wrong_modulus_decomposed.into_iter())
+            .map(|(a_i, w_i)| {
+                if w_i.is_zero_vartime() {
+                    Ok(a_i.as_ref().clone())
+                } else {
+                    main_gate.compose(
+                        ctx,
+                        &[
+                            Term::Assigned(a_i.as_ref(), one),
+                            Term::Assigned(&quotient, w_i),
+                        ],
+                        zero,
+                    )
+                }
+            })
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        // Constrain binary part of crt
+        self.constrain_binary_crt(
+            ctx,
+            &t.try_into()
+                .expect("Unexpected failure in AssignedCell -> AssignedValue conversion"),
+            &result,
+            residues,
+        )?;
+
+        // Constrain native part of crt
+        main_gate.assert_zero_sum(
+            ctx,
+            &[
+                Term::Assigned(a.native(), -one),
+                Term::Assigned(&quotient, self.rns.wrong_modulus_in_native_modulus),
+                Term::Assigned(result.native(), one),
+            ],
+            zero,
+        )?;
+
+        Ok(result)
+    }
+}
@@ -1,5 +1,5 @@
 use super::{IntegerChip, IntegerInstructions, Range};
-use crate::rns::MaybeReduced;
+use crate::rns::{Common, MaybeReduced};
 use crate::{AssignedInteger, PrimeField};
 use halo2::plonk::Error;
 use maingate::{halo2, AssignedValue, MainGateInstructions, RangeInstructions, RegionCtx, Term};
@@ -58,6 +58,25 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         }
     }
 
+    /// Reduces an [`AssignedInteger`] if any of its limbs values is greater
+    /// than the corresponding limb of the [`Rns`] `max_remainder`
+    pub(super) fn reduce_if_limb_values_exceeds_remainder(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let exceeds_max_limb_value = a
+            .max_vals()
+            .iter()
+            .zip(self.rns.max_remainder_limbs.iter())
+            .any(|(limb, max)| limb > max);
+        if exceeds_max_limb_value {
+            self.reduce(ctx, a)
+        } else {
+            Ok(self.new_assigned_integer(a.limbs(), a.native().clone()))
+        }
+    }
+
     /// Reduces an [`AssignedInteger`] if any of its max value is greater
     /// than the [`Rns`] `max_operand`.
     pub(super) fn reduce_if_max_operand_value_exceeds(
@@ -89,11 +108,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         // Apply ranges
         let range_chip = self.range_chip();
         let result = self.assign_integer(ctx, result.into(), Range::Remainder)?;
-        let quotient = range_chip.assign(ctx, quotient, Self::sublimb_bit_len(), BIT_LEN_LIMB)?;
+        let quotient = range_chip.assign(ctx, quotient, self.sublimb_bit_len(), BIT_LEN_LIMB)?;
         let residues = witness
-            .residues()
+            .residues(self.rns.residue_limbs)
             .iter()
-            .map(|v| range_chip.assign(ctx, *v, Self::sublimb_bit_len(), self.rns.red_v_bit_len))
+            .map(|v| range_chip.assign(ctx, *v, self.sublimb_bit_len(), self.rns.red_v_bit_len))
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
 
         // Assign intermediate values
@@ -0,0 +1,86 @@
+use super::IntegerChip;
+use crate::rns::Rns;
+use crate::{AssignedInteger, AssignedLimb, PrimeField};
+use halo2::plonk::Error;
+use maingate::{halo2, power_of_two, AssignedValue, MainGateInstructions, RegionCtx, Term};
+use num_bigint::BigUint as big_uint;
+use num_traits::One;
+use std::rc::Rc;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Splits the in-field representative of the integer into little endian
+    /// chunks of `bit_len` bits. Output is `wrong_modulus.bits()` bits long
+    /// where the last chunk might be narrower. Bits of the representative are
+    /// composed into chunks so that each chunk is also range checked. This is
+    /// the layout other chips such as hash chips may use to consume integers.
+    pub fn split(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        bit_len: usize,
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        assert!(bit_len > 0);
+        assert!(bit_len < N::NUM_BITS as usize);
+        let main_gate = self.main_gate();
+        let integer = self.reduce_strict(ctx, integer)?;
+        let bits = self.decompose_in_field(ctx, &integer)?;
+
+        bits.chunks(bit_len)
+            .map(|bits| {
+                let terms = bits
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| Term::Assigned(bit, power_of_two(i)))
+                    .collect::<Vec<Term<N>>>();
+                main_gate.compose(ctx, &terms, N::ZERO)
+            })
+            .collect()
+    }
+
+    /// Converts the integer into another limb layout of the same wrong field.
+    /// Result is the in-field representative so that it is in remainder range
+    /// under the target [`Rns`] too.
+    pub fn relimb<const NUMBER_OF_LIMBS_OUT: usize, const BIT_LEN_LIMB_OUT: usize>(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        rns: Rc<Rns<W, N, NUMBER_OF_LIMBS_OUT, BIT_LEN_LIMB_OUT>>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS_OUT, BIT_LEN_LIMB_OUT>, Error> {
+        let main_gate = self.main_gate();
+        let wrong_modulus_bit_len = self.rns.wrong_modulus.bits() as usize;
+        assert!(wrong_modulus_bit_len <= NUMBER_OF_LIMBS_OUT * BIT_LEN_LIMB_OUT);
+
+        let mut limbs = self
+            .split(ctx, integer, BIT_LEN_LIMB_OUT)?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, limb)| {
+                let bit_len = std::cmp::min(
+                    BIT_LEN_LIMB_OUT,
+                    wrong_modulus_bit_len - idx * BIT_LEN_LIMB_OUT,
+                );
+                AssignedLimb::from(limb, (big_uint::one() << bit_len) - 1usize)
+            })
+            .collect::<Vec<AssignedLimb<N>>>();
+        // Limbs above the most significant limb of the wrong modulus are zero
+        for _ in limbs.len()..NUMBER_OF_LIMBS_OUT {
+            let zero = main_gate.assign_constant(ctx, N::ZERO)?;
+            limbs.push(AssignedLimb::from(zero, big_uint::default()));
+        }
+
+        let limbs_to_compose: Vec<Term<N>> = limbs
+            .iter()
+            .zip(rns.left_shifters.iter())
+            .map(|(limb, sh)| Term::Assigned(limb.as_ref(), *sh))
+            .collect();
+        let native = main_gate.compose(ctx, &limbs_to_compose, N::ZERO)?;
+
+        Ok(AssignedInteger::new(
+            rns,
+            &limbs.try_into().unwrap(),
+            native,
+        ))
+    }
+}
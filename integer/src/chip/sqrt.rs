@@ -0,0 +1,90 @@
+use super::{IntegerChip, IntegerInstructions, Range};
+use crate::rns::Integer;
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{big_to_fe, halo2, modulus, AssignedCondition, MainGateInstructions, RegionCtx};
+use num_bigint::BigUint as big_uint;
+use std::rc::Rc;
+
+/// Returns a quadratic non residue of the field. For `p = 3 mod 4` it is `-1`
+/// otherwise the smallest non residue is searched with Euler's criterion.
+pub(crate) fn non_residue<W: PrimeField>() -> W {
+    let modulus = modulus::<W>();
+    if &modulus % 4usize == big_uint::from(3usize) {
+        return -W::ONE;
+    }
+    let legendre_exponent = ((modulus - 1usize) >> 1usize).to_u64_digits();
+    (2u64..)
+        .map(W::from)
+        .find(|e| e.pow_vartime(&legendre_exponent) == -W::ONE)
+        .unwrap()
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn sqrt_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedCondition<N>,
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    > {
+        let main_gate = self.main_gate();
+        let non_residue = non_residue::<W>();
+
+        // Root is found off-circuit so the same constraints cover both
+        // `p = 3 mod 4` and Tonelli-Shanks primes
+        let witness = a.integer().map(|a| {
+            let a: W = big_to_fe(a.value());
+            match Option::<W>::from(a.sqrt()) {
+                Some(root) => (N::ONE, root),
+                None => (N::ZERO, (a * non_residue).sqrt().unwrap()),
+            }
+        });
+        let is_square = main_gate.assign_bit(ctx, witness.map(|(is_square, _)| is_square))?;
+        let root = witness.map(|(_, root)| Integer::from_fe(root, Rc::clone(&self.rns)));
+        let root = self.assign_integer(ctx, root.into(), Range::Remainder)?;
+
+        // r^2 = a if a is a square or r^2 = a * non_residue otherwise
+        let non_residue = Integer::from_fe(non_residue, Rc::clone(&self.rns));
+        let a_non_residue = self.mul_constant_generic(ctx, a, &non_residue)?;
+        let expected = self.select(ctx, a, &a_non_residue, &is_square)?;
+        let root_square = self.square_generic(ctx, &root)?;
+        self.assert_equal(ctx, &root_square, &expected)?;
+
+        // Zero is a square and also satisfies the non square equation so the
+        // non square claim additionally requires a non zero input
+        let one = self.assign_constant(ctx, W::ONE)?;
+        let must_be_non_zero = self.select(ctx, &one, a, &is_square)?;
+        self.assert_not_zero(ctx, &must_be_non_zero)?;
+
+        Ok((is_square, root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::non_residue;
+    use crate::curves::bn256::Fq as BnBase;
+    use crate::curves::pasta::Fp as PastaFp;
+    use crate::curves::secp256k1::Fq as Secp256k1Scalar;
+    use crate::PrimeField;
+    use maingate::modulus;
+
+    #[test]
+    fn test_non_residue() {
+        fn run<W: PrimeField>() {
+            let legendre_exponent = ((modulus::<W>() - 1usize) >> 1usize).to_u64_digits();
+            let non_residue = non_residue::<W>();
+            assert_eq!(non_residue.pow_vartime(&legendre_exponent), -W::ONE);
+        }
+        run::<BnBase>();
+        run::<PastaFp>();
+        run::<Secp256k1Scalar>();
+    }
+}
@@ -0,0 +1,7 @@
+mod fp12;
+mod fp2;
+mod fp6;
+
+pub use fp12::{AssignedFp12, Fp12Chip};
+pub use fp2::{AssignedFp2, Fp2Chip};
+pub use fp6::{AssignedFp6, Fp6Chip};
@@ -0,0 +1,492 @@
+use super::fp2::{fp2_pow, AssignedFp2};
+use super::fp6::{AssignedFp6, Fp6Chip};
+use crate::instructions::Range;
+use crate::PrimeField;
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use maingate::{halo2, modulus, AssignedCondition, RegionCtx};
+
+/// Element of the quadratic extension `c0 + c1 * w` over the cubic extension
+/// where `w^2 = v`
+#[derive(Debug, Clone)]
+pub struct AssignedFp12<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    c0: AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    c1: AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Creates a new [`AssignedFp12`] from its coefficients
+    pub fn new(
+        c0: AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c1: AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Self {
+        AssignedFp12 { c0, c1 }
+    }
+
+    /// Returns the constant coefficient
+    pub fn c0(&self) -> &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.c0
+    }
+
+    /// Returns the coefficient of `w`
+    pub fn c1(&self) -> &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.c1
+    }
+}
+
+/// Chip for arithmetic over the quadratic extension `Fp6[w] / (w^2 - v)` on
+/// top of [`Fp6Chip`]
+#[derive(Debug, Clone)]
+pub struct Fp12Chip<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    fp6_chip: Fp6Chip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    // `non_residue^((p^k - 1) / 6)` where `non_residue = w^6`
+    frobenius_coeffs: Vec<(W, W)>,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Fp12Chip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Creates a new [`Fp12Chip`]
+    pub fn new(fp6_chip: Fp6Chip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>) -> Self {
+        let modulus = modulus::<W>();
+        let non_residue = fp6_chip.non_residue();
+        let beta = fp6_chip.fp2_chip().non_residue();
+        // Modulus is `1 mod 6` since it is odd and `1 mod 3`
+        let frobenius_coeffs = (0..12u32)
+            .map(|k| fp2_pow(non_residue, &((modulus.pow(k) - 1usize) / 6usize), beta))
+            .collect();
+        Fp12Chip {
+            fp6_chip,
+            frobenius_coeffs,
+        }
+    }
+
+    /// Getter for [`Fp6Chip`]
+    pub fn fp6_chip(&self) -> &Fp6Chip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.fp6_chip
+    }
+
+    /// Assigns a new witness element where all coefficients are range checked
+    /// with the given [`Range`]
+    pub fn assign_fp12(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: Value<[[(W, W); 3]; 2]>,
+        range: Range,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp6_chip.assign_fp6(ctx, value.map(|e| e[0]), range)?;
+        let c1 = self.fp6_chip.assign_fp6(ctx, value.map(|e| e[1]), range)?;
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Assigns a constant element
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: [[(W, W); 3]; 2],
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp6_chip.assign_constant(ctx, value[0])?;
+        let c1 = self.fp6_chip.assign_constant(ctx, value[1])?;
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Assigns the multiplicative identity
+    pub fn assign_one(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let zero = (W::ZERO, W::ZERO);
+        let one = (W::ONE, W::ZERO);
+        self.assign_constant(ctx, [[one, zero, zero], [zero, zero, zero]])
+    }
+
+    /// Adds two elements
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp6_chip.add(ctx, &a.c0, &b.c0)?;
+        let c1 = self.fp6_chip.add(ctx, &a.c1, &b.c1)?;
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Subtracts `b` from `a`
+    pub fn sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp6_chip.sub(ctx, &a.c0, &b.c0)?;
+        let c1 = self.fp6_chip.sub(ctx, &a.c1, &b.c1)?;
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Multiplies two elements with Karatsuba method
+    /// `c0 = a0 * b0 + v * a1 * b1`
+    /// `c1 = (a0 + a1) * (b0 + b1) - a0 * b0 - a1 * b1`
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp6_chip = &self.fp6_chip;
+        let v0 = fp6_chip.mul(ctx, &a.c0, &b.c0)?;
+        let v1 = fp6_chip.mul(ctx, &a.c1, &b.c1)?;
+
+        let a_sum = fp6_chip.add(ctx, &a.c0, &a.c1)?;
+        let b_sum = fp6_chip.add(ctx, &b.c0, &b.c1)?;
+        let c1 = fp6_chip.mul(ctx, &a_sum, &b_sum)?;
+        let c1 = fp6_chip.sub(ctx, &c1, &v0)?;
+        let c1 = fp6_chip.sub(ctx, &c1, &v1)?;
+
+        let v1 = fp6_chip.mul_by_non_residue(ctx, &v1)?;
+        let c0 = fp6_chip.add(ctx, &v0, &v1)?;
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Squares an element
+    /// `c0 = (a0 + a1) * (a0 + v * a1) - a0 * a1 - v * a0 * a1`
+    /// `c1 = 2 * a0 * a1`
+    pub fn square(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp6_chip = &self.fp6_chip;
+        let ab = fp6_chip.mul(ctx, &a.c0, &a.c1)?;
+        let c1 = fp6_chip.double(ctx, &ab)?;
+
+        let t0 = fp6_chip.add(ctx, &a.c0, &a.c1)?;
+        let t1 = fp6_chip.mul_by_non_residue(ctx, &a.c1)?;
+        let t1 = fp6_chip.add(ctx, &a.c0, &t1)?;
+        let c0 = fp6_chip.mul(ctx, &t0, &t1)?;
+        let c0 = fp6_chip.sub(ctx, &c0, &ab)?;
+        let ab = fp6_chip.mul_by_non_residue(ctx, &ab)?;
+        let c0 = fp6_chip.sub(ctx, &c0, &ab)?;
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Multiplies an element by the sparse element `1 + (b3 + b4 * v) * w`.
+    /// Line evaluations of pairings are in this form
+    pub fn mul_by_34(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b3: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b4: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp6_chip = &self.fp6_chip;
+        // c0 = a0 + v * a1 * b
+        let t = fp6_chip.mul_by_01(ctx, &a.c1, b3, b4)?;
+        let t = fp6_chip.mul_by_non_residue(ctx, &t)?;
+        let c0 = fp6_chip.add(ctx, &a.c0, &t)?;
+        // c1 = a1 + a0 * b
+        let t = fp6_chip.mul_by_01(ctx, &a.c0, b3, b4)?;
+        let c1 = fp6_chip.add(ctx, &a.c1, &t)?;
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Returns the conjugate `a0 - a1 * w` which is `a^(p^6)`
+    pub fn conjugate(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c1 = self.fp6_chip.neg(ctx, &a.c1)?;
+        Ok(AssignedFp12::new(a.c0.clone(), c1))
+    }
+
+    /// Inverts an element through the norm `(a0 - a1 * w) / (a0^2 - v * a1^2)`.
+    /// An [`AssignedCondition`] is returned along with the result indicating
+    /// if the input was zero in which case the result is zero.
+    pub fn invert(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        let fp6_chip = &self.fp6_chip;
+        let t0 = fp6_chip.square(ctx, &a.c0)?;
+        let t1 = fp6_chip.square(ctx, &a.c1)?;
+        let t1 = fp6_chip.mul_by_non_residue(ctx, &t1)?;
+        let norm = fp6_chip.sub(ctx, &t0, &t1)?;
+        let (norm_inv, is_zero) = fp6_chip.invert(ctx, &norm)?;
+
+        let c0 = fp6_chip.mul(ctx, &a.c0, &norm_inv)?;
+        let c1 = fp6_chip.mul(ctx, &a.c1, &norm_inv)?;
+        let c1 = fp6_chip.neg(ctx, &c1)?;
+        Ok((AssignedFp12::new(c0, c1), is_zero))
+    }
+
+    /// Applies Frobenius map `a^(p^power)`
+    pub fn frobenius_map(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        power: usize,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp6_chip = &self.fp6_chip;
+        let fp2_chip = fp6_chip.fp2_chip();
+        let c0 = fp6_chip.frobenius_map(ctx, &a.c0, power)?;
+        let c1 = fp6_chip.frobenius_map(ctx, &a.c1, power)?;
+
+        let coeff = self.frobenius_coeffs[power % 12];
+        let c1 = AssignedFp6::new(
+            fp2_chip.mul_constant(ctx, c1.c0(), coeff)?,
+            fp2_chip.mul_constant(ctx, c1.c1(), coeff)?,
+            fp2_chip.mul_constant(ctx, c1.c2(), coeff)?,
+        );
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Selects `a` if the condition is set and `b` otherwise
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp6_chip.select(ctx, &a.c0, &b.c0, cond)?;
+        let c1 = self.fp6_chip.select(ctx, &a.c1, &b.c1, cond)?;
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Reduces all coefficients
+    pub fn reduce(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp6_chip.reduce(ctx, &a.c0)?;
+        let c1 = self.fp6_chip.reduce(ctx, &a.c1)?;
+        Ok(AssignedFp12::new(c0, c1))
+    }
+
+    /// Constrains two elements to be equal
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        self.fp6_chip.assert_equal(ctx, &a.c0, &b.c0)?;
+        self.fp6_chip.assert_equal(ctx, &a.c1, &b.c1)
+    }
+
+    /// Constrains an element to be the multiplicative identity
+    pub fn assert_one(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp12<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let one = self.assign_one(ctx)?;
+        self.assert_equal(ctx, a, &one)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fp12Chip;
+    use crate::curves::bn256::{Fq, Fq12, Fq2, Fq6, Fr};
+    use crate::extension::{Fp2Chip, Fp6Chip};
+    use crate::rns::Rns;
+    use crate::{IntegerChip, IntegerConfig, Range};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::ff::Field;
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use maingate::{
+        halo2, mock_prover_verify, MainGate, MainGateInstructions, RangeChip, RangeInstructions,
+        RegionCtx,
+    };
+    use rand_core::OsRng;
+    use std::rc::Rc;
+
+    const NUMBER_OF_LIMBS: usize = 4;
+    const BIT_LEN_LIMB: usize = 68;
+
+    fn fp2(e: Fq2) -> (Fq, Fq) {
+        (e.c0, e.c1)
+    }
+
+    fn fp6(e: Fq6) -> [(Fq, Fq); 3] {
+        [fp2(e.c0), fp2(e.c1), fp2(e.c2)]
+    }
+
+    fn fp12(e: Fq12) -> [[(Fq, Fq); 3]; 2] {
+        [fp6(e.c0), fp6(e.c1)]
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        range_config: maingate::RangeConfig,
+        main_gate_config: maingate::MainGateConfig,
+    }
+
+    struct TestCircuit;
+
+    impl Circuit<Fr> for TestCircuit {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let main_gate_config = MainGate::<Fr>::configure(meta);
+            let rns = Rns::<Fq, Fr, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct();
+            let range_config = RangeChip::<Fr>::configure(
+                meta,
+                &main_gate_config,
+                vec![rns.bit_len_lookup],
+                rns.overflow_lengths(),
+            );
+            TestCircuitConfig {
+                range_config,
+                main_gate_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let rns = Rns::<Fq, Fr, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct();
+            let integer_chip = IntegerChip::new(
+                IntegerConfig::new(config.range_config.clone(), config.main_gate_config.clone()),
+                Rc::new(rns),
+            );
+            // Tower of `halo2curves` BN254 extensions
+            let fp2_chip = Fp2Chip::new(integer_chip, -Fq::ONE);
+            let fp6_chip = Fp6Chip::new(fp2_chip, (Fq::from(9), Fq::ONE));
+            let fp12_chip = Fp12Chip::new(fp6_chip.clone());
+            let main_gate = MainGate::<Fr>::new(config.main_gate_config.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // Cubic extension
+                    let (a, b) = (Fq6::random(OsRng), Fq6::random(OsRng));
+                    let assign = |ctx: &mut RegionCtx<'_, Fr>, e: Fq6| {
+                        fp6_chip.assign_fp6(ctx, Value::known(fp6(e)), Range::Remainder)
+                    };
+                    let a_assigned = &assign(ctx, a)?;
+                    let b_assigned = &assign(ctx, b)?;
+
+                    let c = fp6_chip.add(ctx, a_assigned, b_assigned)?;
+                    fp6_chip.assert_equal(ctx, &c, &assign(ctx, a + b)?)?;
+                    let c = fp6_chip.sub(ctx, a_assigned, b_assigned)?;
+                    fp6_chip.assert_equal(ctx, &c, &assign(ctx, a - b)?)?;
+                    let c = fp6_chip.mul(ctx, a_assigned, b_assigned)?;
+                    fp6_chip.assert_equal(ctx, &c, &assign(ctx, a * b)?)?;
+                    let c = fp6_chip.square(ctx, a_assigned)?;
+                    fp6_chip.assert_equal(ctx, &c, &assign(ctx, a.square())?)?;
+                    let (c, is_zero) = fp6_chip.invert(ctx, a_assigned)?;
+                    fp6_chip.assert_equal(ctx, &c, &assign(ctx, a.invert().unwrap())?)?;
+                    main_gate.assert_zero(ctx, &is_zero)?;
+                    let c =
+                        fp6_chip.mul_by_01(ctx, a_assigned, b_assigned.c0(), b_assigned.c1())?;
+                    let b_sparse = Fq6 {
+                        c0: b.c0,
+                        c1: b.c1,
+                        c2: Fq2::ZERO,
+                    };
+                    fp6_chip.assert_equal(ctx, &c, &assign(ctx, a * b_sparse)?)?;
+                    for power in 0..6 {
+                        let mut expected = a;
+                        expected.frobenius_map(power);
+                        let c = fp6_chip.frobenius_map(ctx, a_assigned, power)?;
+                        fp6_chip.assert_equal(ctx, &c, &assign(ctx, expected)?)?;
+                    }
+
+                    // Twelfth degree extension
+                    let (a, b) = (Fq12::random(OsRng), Fq12::random(OsRng));
+                    let assign = |ctx: &mut RegionCtx<'_, Fr>, e: Fq12| {
+                        fp12_chip.assign_fp12(ctx, Value::known(fp12(e)), Range::Remainder)
+                    };
+                    let a_assigned = &assign(ctx, a)?;
+                    let b_assigned = &assign(ctx, b)?;
+
+                    let c = fp12_chip.mul(ctx, a_assigned, b_assigned)?;
+                    fp12_chip.assert_equal(ctx, &c, &assign(ctx, a * b)?)?;
+                    let c = fp12_chip.square(ctx, a_assigned)?;
+                    fp12_chip.assert_equal(ctx, &c, &assign(ctx, a.square())?)?;
+                    let (c, is_zero) = fp12_chip.invert(ctx, a_assigned)?;
+                    fp12_chip.assert_equal(ctx, &c, &assign(ctx, a.invert().unwrap())?)?;
+                    main_gate.assert_zero(ctx, &is_zero)?;
+                    let c = fp12_chip.conjugate(ctx, a_assigned)?;
+                    let mut expected = a;
+                    expected.conjugate();
+                    fp12_chip.assert_equal(ctx, &c, &assign(ctx, expected)?)?;
+                    for power in [1, 2, 3, 6] {
+                        let mut expected = a;
+                        expected.frobenius_map(power);
+                        let c = fp12_chip.frobenius_map(ctx, a_assigned, power)?;
+                        fp12_chip.assert_equal(ctx, &c, &assign(ctx, expected)?)?;
+                    }
+
+                    // Sparse multiplication by `1 + (b3 + b4 * v) * w`
+                    let (b3, b4) = (Fq2::random(OsRng), Fq2::random(OsRng));
+                    let b_sparse = Fq12 {
+                        c0: Fq6::ONE,
+                        c1: Fq6 {
+                            c0: b3,
+                            c1: b4,
+                            c2: Fq2::ZERO,
+                        },
+                    };
+                    let fp2_chip = fp6_chip.fp2_chip();
+                    let b3 = fp2_chip.assign_fp2(ctx, Value::known(fp2(b3)), Range::Remainder)?;
+                    let b4 = fp2_chip.assign_fp2(ctx, Value::known(fp2(b4)), Range::Remainder)?;
+                    let c = fp12_chip.mul_by_34(ctx, a_assigned, &b3, &b4)?;
+                    fp12_chip.assert_equal(ctx, &c, &assign(ctx, a * b_sparse)?)?;
+
+                    let one = fp12_chip.assign_one(ctx)?;
+                    let (a_inv, _) = fp12_chip.invert(ctx, a_assigned)?;
+                    let c = fp12_chip.mul(ctx, a_assigned, &a_inv)?;
+                    fp12_chip.assert_one(ctx, &c)?;
+                    let c = fp12_chip.mul(ctx, &c, &one)?;
+                    fp12_chip.assert_one(ctx, &c)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<Fr>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fp12_circuit() {
+        mock_prover_verify(&TestCircuit, vec![vec![]]);
+    }
+}
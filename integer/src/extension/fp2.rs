@@ -0,0 +1,645 @@
+use crate::instructions::{IntegerInstructions, Range};
+use crate::rns::{Common, Integer};
+use crate::{AssignedInteger, IntegerChip, PrimeField};
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use maingate::{big_to_fe, halo2, AssignedCondition, RegionCtx};
+use num_bigint::BigUint as big_uint;
+
+// Off-circuit arithmetic over `(c0, c1)` pairs that is used to find witnesses
+// and constants of the extension
+
+pub(crate) fn fp2_mul<W: PrimeField>(a: (W, W), b: (W, W), non_residue: W) -> (W, W) {
+    (a.0 * b.0 + non_residue * a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+pub(crate) fn fp2_invert<W: PrimeField>(a: (W, W), non_residue: W) -> Option<(W, W)> {
+    let norm = a.0.square() - non_residue * a.1.square();
+    Option::from(norm.invert()).map(|norm_inv: W| (a.0 * norm_inv, -a.1 * norm_inv))
+}
+
+pub(crate) fn fp2_pow<W: PrimeField>(a: (W, W), exponent: &big_uint, non_residue: W) -> (W, W) {
+    (0..exponent.bits())
+        .rev()
+        .fold((W::ONE, W::ZERO), |acc, i| {
+            let acc = fp2_mul(acc, acc, non_residue);
+            if exponent.bit(i) {
+                fp2_mul(acc, a, non_residue)
+            } else {
+                acc
+            }
+        })
+}
+
+/// Element of the quadratic extension `c0 + c1 * u` where `u^2` is the non
+/// residue of the [`Fp2Chip`]
+#[derive(Debug, Clone)]
+pub struct AssignedFp2<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    c0: AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    c1: AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Creates a new [`AssignedFp2`] from its coefficients
+    pub fn new(
+        c0: AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c1: AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Self {
+        AssignedFp2 { c0, c1 }
+    }
+
+    /// Returns the constant coefficient
+    pub fn c0(&self) -> &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.c0
+    }
+
+    /// Returns the coefficient of `u`
+    pub fn c1(&self) -> &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.c1
+    }
+
+    pub(crate) fn value(&self) -> Value<(W, W)> {
+        self.c0
+            .integer()
+            .zip(self.c1.integer())
+            .map(|(c0, c1)| (big_to_fe(c0.value()), big_to_fe(c1.value())))
+    }
+}
+
+/// Chip for arithmetic over the quadratic extension
+/// `W[u] / (u^2 - non_residue)`. Coefficient arithmetic is delegated to
+/// [`IntegerChip`] and sums are left unreduced until a multiplication
+/// requires a reduction.
+#[derive(Debug, Clone)]
+pub struct Fp2Chip<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    integer_chip: IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    non_residue: W,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Fp2Chip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Creates a new [`Fp2Chip`]. `non_residue` must be a quadratic non
+    /// residue of the wrong field
+    pub fn new(
+        integer_chip: IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        non_residue: W,
+    ) -> Self {
+        assert!(
+            bool::from(non_residue.sqrt().is_none()),
+            "extension requires a non residue"
+        );
+        Fp2Chip {
+            integer_chip,
+            non_residue,
+        }
+    }
+
+    /// Getter for [`IntegerChip`]
+    pub fn integer_chip(&self) -> &IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.integer_chip
+    }
+
+    /// Returns the non residue that defines the extension
+    pub fn non_residue(&self) -> W {
+        self.non_residue
+    }
+
+    fn integer(&self, e: W) -> Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        Integer::from_fe(e, self.integer_chip.rns())
+    }
+
+    // Multiplies a coefficient by the non residue
+    fn mul_by_non_residue_base(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        if self.non_residue == -W::ONE {
+            self.integer_chip.neg(ctx, a)
+        } else {
+            self.integer_chip
+                .mul_constant(ctx, a, &self.integer(self.non_residue))
+        }
+    }
+
+    /// Assigns a new witness element where both coefficients are range
+    /// checked with the given [`Range`]
+    pub fn assign_fp2(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: Value<(W, W)>,
+        range: Range,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let (c0, c1) = value.unzip();
+        let c0 =
+            self.integer_chip
+                .assign_integer(ctx, c0.map(|c0| self.integer(c0)).into(), range)?;
+        let c1 =
+            self.integer_chip
+                .assign_integer(ctx, c1.map(|c1| self.integer(c1)).into(), range)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Assigns a constant element
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: (W, W),
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.assign_constant(ctx, value.0)?;
+        let c1 = self.integer_chip.assign_constant(ctx, value.1)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Adds two elements
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.add(ctx, &a.c0, &b.c0)?;
+        let c1 = self.integer_chip.add(ctx, &a.c1, &b.c1)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Subtracts `b` from `a`
+    pub fn sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.sub(ctx, &a.c0, &b.c0)?;
+        let c1 = self.integer_chip.sub(ctx, &a.c1, &b.c1)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Negates an element
+    pub fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.neg(ctx, &a.c0)?;
+        let c1 = self.integer_chip.neg(ctx, &a.c1)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Doubles an element
+    pub fn double(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.mul2(ctx, &a.c0)?;
+        let c1 = self.integer_chip.mul2(ctx, &a.c1)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Multiplies two elements where each coefficient is a sum of products
+    /// that is reduced once
+    /// `c0 = a0 * b0 + non_residue * a1 * b1`
+    /// `c1 = a0 * b1 + a1 * b0`
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = &self.integer_chip;
+        let v1 = integer_chip.mul(ctx, &a.c1, &b.c1)?;
+        let c0 = if self.non_residue == -W::ONE {
+            integer_chip.mul_add_many(ctx, &[(a.c0.clone(), b.c0.clone())], &[], &[v1])?
+        } else {
+            let v1 = self.mul_by_non_residue_base(ctx, &v1)?;
+            integer_chip.mul_add_many(ctx, &[(a.c0.clone(), b.c0.clone())], &[v1], &[])?
+        };
+        let c1 = integer_chip.mul_add_many(
+            ctx,
+            &[(a.c0.clone(), b.c1.clone()), (a.c1.clone(), b.c0.clone())],
+            &[],
+            &[],
+        )?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Multiplies an element by a constant element
+    pub fn mul_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: (W, W),
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = &self.integer_chip;
+        let (b0, b1) = b;
+        if b1 == W::ZERO {
+            return self.mul_by_constant_base(ctx, a, b0);
+        }
+
+        // c0 = a0 * b0 + a1 * (non_residue * b1)
+        // c1 = a0 * b1 + a1 * b0
+        let a0_b0 = integer_chip.mul_constant(ctx, &a.c0, &self.integer(b0))?;
+        let a1_b1 = integer_chip.mul_constant(ctx, &a.c1, &self.integer(self.non_residue * b1))?;
+        let c0 = integer_chip.add(ctx, &a0_b0, &a1_b1)?;
+        let a0_b1 = integer_chip.mul_constant(ctx, &a.c0, &self.integer(b1))?;
+        let a1_b0 = integer_chip.mul_constant(ctx, &a.c1, &self.integer(b0))?;
+        let c1 = integer_chip.add(ctx, &a0_b1, &a1_b0)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Multiplies an element by a base field element
+    pub fn mul_by_base(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.mul(ctx, &a.c0, b)?;
+        let c1 = self.integer_chip.mul(ctx, &a.c1, b)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Multiplies an element by a constant base field element
+    pub fn mul_by_constant_base(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: W,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let b = self.integer(b);
+        let c0 = self.integer_chip.mul_constant(ctx, &a.c0, &b)?;
+        let c1 = self.integer_chip.mul_constant(ctx, &a.c1, &b)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Squares an element with two multiplications
+    /// `c0 = (a0 + a1) * (a0 + non_residue * a1) - (1 + non_residue) * a0 * a1`
+    /// `c1 = 2 * a0 * a1`
+    pub fn square(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let integer_chip = &self.integer_chip;
+        let v = integer_chip.mul(ctx, &a.c0, &a.c1)?;
+        let c1 = integer_chip.mul2(ctx, &v)?;
+
+        let c0 = if self.non_residue == -W::ONE {
+            // c0 = (a0 + a1) * (a0 - a1)
+            let t0 = integer_chip.add(ctx, &a.c0, &a.c1)?;
+            let t1 = integer_chip.sub(ctx, &a.c0, &a.c1)?;
+            integer_chip.mul(ctx, &t0, &t1)?
+        } else {
+            let a1_non_residue = self.mul_by_non_residue_base(ctx, &a.c1)?;
+            let t0 = integer_chip.add(ctx, &a.c0, &a.c1)?;
+            let t1 = integer_chip.add(ctx, &a.c0, &a1_non_residue)?;
+            let t = integer_chip.mul(ctx, &t0, &t1)?;
+            let v_non_residue = self.mul_by_non_residue_base(ctx, &v)?;
+            integer_chip.sub_sub(ctx, &t, &v, &v_non_residue)?
+        };
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Inverts an element through the norm
+    /// `(a0 - a1 * u) / (a0^2 - non_residue * a1^2)`. An [`AssignedCondition`]
+    /// is returned along with the result indicating if the input was zero in
+    /// which case the result is zero.
+    pub fn invert(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        let integer_chip = &self.integer_chip;
+        let a0_square = integer_chip.square(ctx, &a.c0)?;
+        let a1_square = integer_chip.square(ctx, &a.c1)?;
+        // Norm is zero only if the element is zero since `u^2` is a non residue
+        let norm = if self.non_residue == -W::ONE {
+            integer_chip.add(ctx, &a0_square, &a1_square)?
+        } else {
+            let a1_square = self.mul_by_non_residue_base(ctx, &a1_square)?;
+            integer_chip.sub(ctx, &a0_square, &a1_square)?
+        };
+        let (norm_inv, is_zero) = integer_chip.invert(ctx, &norm)?;
+
+        let c0 = integer_chip.mul(ctx, &a.c0, &norm_inv)?;
+        let c1 = integer_chip.mul(ctx, &a.c1, &norm_inv)?;
+        let c1 = integer_chip.neg(ctx, &c1)?;
+        Ok((AssignedFp2::new(c0, c1), is_zero))
+    }
+
+    /// Divides `a` by `b` where `b` is assumed to be non zero
+    pub fn div_incomplete(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let non_residue = self.non_residue;
+        let c = a.value().zip(b.value()).map(|(a, b)| {
+            let b_inv = fp2_invert(b, non_residue).unwrap_or((W::ZERO, W::ZERO));
+            fp2_mul(a, b_inv, non_residue)
+        });
+        let c = self.assign_fp2(ctx, c, Range::Remainder)?;
+        let b_mul_c = self.mul(ctx, b, &c)?;
+        self.assert_equal(ctx, &b_mul_c, a)?;
+        Ok(c)
+    }
+
+    /// Returns the conjugate `a0 - a1 * u`
+    pub fn conjugate(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c1 = self.integer_chip.neg(ctx, &a.c1)?;
+        Ok(AssignedFp2::new(a.c0.clone(), c1))
+    }
+
+    /// Applies Frobenius map `a^(p^power)`. Since `u^p = -u` for a non residue
+    /// `u^2` it is the conjugation for odd powers and identity otherwise
+    pub fn frobenius_map(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        power: usize,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        if power % 2 == 1 {
+            self.conjugate(ctx, a)
+        } else {
+            Ok(a.clone())
+        }
+    }
+
+    /// Selects `a` if the condition is set and `b` otherwise
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.select(ctx, &a.c0, &b.c0, cond)?;
+        let c1 = self.integer_chip.select(ctx, &a.c1, &b.c1, cond)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Reduces both coefficients
+    pub fn reduce(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.integer_chip.reduce(ctx, &a.c0)?;
+        let c1 = self.integer_chip.reduce(ctx, &a.c1)?;
+        Ok(AssignedFp2::new(c0, c1))
+    }
+
+    /// Constrains two elements to be equal
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        self.integer_chip.assert_equal(ctx, &a.c0, &b.c0)?;
+        self.integer_chip.assert_equal(ctx, &a.c1, &b.c1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fp2_invert, fp2_mul, fp2_pow, Fp2Chip};
+    use crate::rns::Rns;
+    use crate::{IntegerChip, IntegerConfig, PrimeField, Range};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use maingate::{
+        halo2, mock_prover_verify, modulus, MainGate, MainGateInstructions, RangeChip,
+        RangeInstructions, RegionCtx,
+    };
+    use rand_core::OsRng;
+    use std::marker::PhantomData;
+    use std::rc::Rc;
+
+    const NUMBER_OF_LIMBS: usize = 4;
+    const BIT_LEN_LIMB: usize = 68;
+
+    fn mul<W: PrimeField>(a: (W, W), b: (W, W), non_residue: W) -> (W, W) {
+        fp2_mul(a, b, non_residue)
+    }
+
+    fn invert<W: PrimeField>(a: (W, W), non_residue: W) -> (W, W) {
+        fp2_invert(a, non_residue).unwrap()
+    }
+
+    fn rand<W: PrimeField>() -> (W, W) {
+        (W::random(OsRng), W::random(OsRng))
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        range_config: maingate::RangeConfig,
+        main_gate_config: maingate::MainGateConfig,
+    }
+
+    struct TestCircuit<W: PrimeField, N: PrimeField> {
+        non_residue: W,
+        _marker: PhantomData<N>,
+    }
+
+    impl<W: PrimeField, N: PrimeField> Circuit<N> for TestCircuit<W, N> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let rns = Rns::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct();
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                vec![rns.bit_len_lookup],
+                rns.overflow_lengths(),
+            );
+            TestCircuitConfig {
+                range_config,
+                main_gate_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let rns = Rns::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::construct();
+            let integer_chip = IntegerChip::new(
+                IntegerConfig::new(config.range_config.clone(), config.main_gate_config.clone()),
+                Rc::new(rns),
+            );
+            let fp2_chip = Fp2Chip::new(integer_chip, self.non_residue);
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let non_residue = self.non_residue;
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let (a, b) = (rand::<W>(), rand::<W>());
+                    let assign = |ctx: &mut RegionCtx<'_, N>, e: (W, W)| {
+                        fp2_chip.assign_fp2(ctx, Value::known(e), Range::Remainder)
+                    };
+                    let a_assigned = &assign(ctx, a)?;
+                    let b_assigned = &assign(ctx, b)?;
+
+                    // add, sub, neg, double
+                    let c = fp2_chip.add(ctx, a_assigned, b_assigned)?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, (a.0 + b.0, a.1 + b.1))?)?;
+                    let c = fp2_chip.sub(ctx, a_assigned, b_assigned)?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, (a.0 - b.0, a.1 - b.1))?)?;
+                    let c = fp2_chip.neg(ctx, a_assigned)?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, (-a.0, -a.1))?)?;
+                    let c = fp2_chip.double(ctx, a_assigned)?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, (a.0.double(), a.1.double()))?)?;
+
+                    // mul and square
+                    let c = fp2_chip.mul(ctx, a_assigned, b_assigned)?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, mul(a, b, non_residue))?)?;
+                    let c = fp2_chip.mul_constant(ctx, a_assigned, b)?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, mul(a, b, non_residue))?)?;
+                    let c = fp2_chip.mul_constant(ctx, a_assigned, (b.0, W::ZERO))?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, (a.0 * b.0, a.1 * b.0))?)?;
+                    let c = fp2_chip.square(ctx, a_assigned)?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, mul(a, a, non_residue))?)?;
+
+                    // mul with unreduced inputs
+                    let a_unreduced = fp2_chip.add(ctx, a_assigned, b_assigned)?;
+                    let a_unreduced = fp2_chip.add(ctx, &a_unreduced, a_assigned)?;
+                    let a_sum = (a.0.double() + b.0, a.1.double() + b.1);
+                    let c = fp2_chip.mul(ctx, &a_unreduced, b_assigned)?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, mul(a_sum, b, non_residue))?)?;
+                    let c = fp2_chip.square(ctx, &a_unreduced)?;
+                    fp2_chip.assert_equal(
+                        ctx,
+                        &c,
+                        &assign(ctx, mul(a_sum, a_sum, non_residue))?,
+                    )?;
+
+                    // invert
+                    let (c, is_zero) = fp2_chip.invert(ctx, a_assigned)?;
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, invert(a, non_residue))?)?;
+                    main_gate.assert_zero(ctx, &is_zero)?;
+                    let zero = assign(ctx, (W::ZERO, W::ZERO))?;
+                    let (c, is_zero) = fp2_chip.invert(ctx, &zero)?;
+                    fp2_chip.assert_equal(ctx, &c, &zero)?;
+                    main_gate.assert_one(ctx, &is_zero)?;
+
+                    // division
+                    let c = fp2_chip.div_incomplete(ctx, a_assigned, b_assigned)?;
+                    let expected = mul(a, invert(b, non_residue), non_residue);
+                    fp2_chip.assert_equal(ctx, &c, &assign(ctx, expected)?)?;
+
+                    // conjugate and frobenius
+                    let conjugate = assign(ctx, (a.0, -a.1))?;
+                    let c = fp2_chip.conjugate(ctx, a_assigned)?;
+                    fp2_chip.assert_equal(ctx, &c, &conjugate)?;
+                    let c = fp2_chip.frobenius_map(ctx, a_assigned, 1)?;
+                    fp2_chip.assert_equal(ctx, &c, &conjugate)?;
+                    let c = fp2_chip.frobenius_map(ctx, a_assigned, 2)?;
+                    fp2_chip.assert_equal(ctx, &c, a_assigned)?;
+
+                    // select
+                    let cond = main_gate.assign_bit(ctx, Value::known(N::ONE))?;
+                    let c = fp2_chip.select(ctx, a_assigned, b_assigned, &cond)?;
+                    fp2_chip.assert_equal(ctx, &c, a_assigned)?;
+                    let cond = main_gate.assign_bit(ctx, Value::known(N::ZERO))?;
+                    let c = fp2_chip.select(ctx, a_assigned, b_assigned, &cond)?;
+                    fp2_chip.assert_equal(ctx, &c, b_assigned)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<N>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run<W: PrimeField, N: PrimeField>(non_residue: W) {
+        let circuit = TestCircuit::<W, N> {
+            non_residue,
+            _marker: PhantomData,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+
+    #[test]
+    fn test_fp2_reference() {
+        use crate::curves::bn256::{Fq, Fq2};
+        use halo2::ff::Field;
+
+        // Reference arithmetic must agree with `halo2curves`
+        let to_pair = |e: Fq2| (e.c0, e.c1);
+        let (a, b) = (Fq2::random(OsRng), Fq2::random(OsRng));
+        let non_residue = -Fq::ONE;
+        assert_eq!(mul(to_pair(a), to_pair(b), non_residue), to_pair(a * b));
+        assert_eq!(
+            mul(to_pair(a), to_pair(a), non_residue),
+            to_pair(a.square())
+        );
+        assert_eq!(
+            invert(to_pair(a), non_residue),
+            to_pair(a.invert().unwrap())
+        );
+        let mut frobenius = a;
+        frobenius.frobenius_map(1);
+        assert_eq!(to_pair(frobenius), (a.c0, -a.c1));
+        let exponent = modulus::<Fq>() + 12345usize;
+        assert_eq!(
+            fp2_pow(to_pair(a), &exponent, non_residue),
+            to_pair(a.pow_vartime(&exponent.to_u64_digits()))
+        );
+    }
+
+    #[test]
+    fn test_fp2_circuit() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
+
+        run::<BnBase, BnScalar>(-BnBase::ONE);
+
+        // Tonelli-Shanks prime with a non residue other than `-1`
+        let legendre_exponent = ((modulus::<PastaFq>() - 1usize) >> 1usize).to_u64_digits();
+        let non_residue = (2u64..)
+            .map(PastaFq::from)
+            .find(|e| e.pow_vartime(&legendre_exponent) == -PastaFq::ONE)
+            .unwrap();
+        run::<PastaFq, PastaFp>(non_residue);
+    }
+}
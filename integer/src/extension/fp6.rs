@@ -0,0 +1,436 @@
+use super::fp2::{fp2_pow, AssignedFp2, Fp2Chip};
+use crate::instructions::Range;
+use crate::PrimeField;
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use maingate::{halo2, modulus, AssignedCondition, RegionCtx};
+use num_bigint::BigUint as big_uint;
+
+/// Element of the cubic extension `c0 + c1 * v + c2 * v^2` where `v^3` is the
+/// non residue of the [`Fp6Chip`]
+#[derive(Debug, Clone)]
+pub struct AssignedFp6<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    c0: AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    c1: AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    c2: AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Creates a new [`AssignedFp6`] from its coefficients
+    pub fn new(
+        c0: AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c1: AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c2: AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Self {
+        AssignedFp6 { c0, c1, c2 }
+    }
+
+    /// Returns the constant coefficient
+    pub fn c0(&self) -> &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.c0
+    }
+
+    /// Returns the coefficient of `v`
+    pub fn c1(&self) -> &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.c1
+    }
+
+    /// Returns the coefficient of `v^2`
+    pub fn c2(&self) -> &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.c2
+    }
+}
+
+/// Chip for arithmetic over the cubic extension `Fp2[v] / (v^3 - non_residue)`
+/// on top of [`Fp2Chip`]. Wrong field modulus is required to be `1 mod 3` so
+/// that Frobenius map coefficients live in the quadratic extension.
+#[derive(Debug, Clone)]
+pub struct Fp6Chip<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+> {
+    fp2_chip: Fp2Chip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    non_residue: (W, W),
+    // `(non_residue^((p^k - 1) / 3), non_residue^(2 * (p^k - 1) / 3))`
+    frobenius_coeffs: Vec<((W, W), (W, W))>,
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Fp6Chip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Creates a new [`Fp6Chip`]. `non_residue` must be a cubic non residue
+    /// of the quadratic extension
+    pub fn new(
+        fp2_chip: Fp2Chip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        non_residue: (W, W),
+    ) -> Self {
+        let modulus = modulus::<W>();
+        assert_eq!(
+            &modulus % 3usize,
+            big_uint::from(1usize),
+            "wrong field modulus must be 1 mod 3"
+        );
+        let beta = fp2_chip.non_residue();
+        let one = (W::ONE, W::ZERO);
+        assert!(
+            fp2_pow(
+                non_residue,
+                &((&modulus * &modulus - 1usize) / 3usize),
+                beta
+            ) != one,
+            "extension requires a cubic non residue"
+        );
+
+        let frobenius_coeffs = (0..6u32)
+            .map(|k| {
+                let exponent = (modulus.pow(k) - 1usize) / 3usize;
+                let c1 = fp2_pow(non_residue, &exponent, beta);
+                let c2 = fp2_pow(non_residue, &(exponent * 2usize), beta);
+                (c1, c2)
+            })
+            .collect();
+
+        Fp6Chip {
+            fp2_chip,
+            non_residue,
+            frobenius_coeffs,
+        }
+    }
+
+    /// Getter for [`Fp2Chip`]
+    pub fn fp2_chip(&self) -> &Fp2Chip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        &self.fp2_chip
+    }
+
+    /// Returns the non residue that defines the extension
+    pub fn non_residue(&self) -> (W, W) {
+        self.non_residue
+    }
+
+    // Multiplies a coefficient by the non residue
+    fn mul_by_non_residue_fp2(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.fp2_chip.mul_constant(ctx, a, self.non_residue)
+    }
+
+    /// Assigns a new witness element where all coefficients are range checked
+    /// with the given [`Range`]
+    pub fn assign_fp6(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: Value<[(W, W); 3]>,
+        range: Range,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp2_chip.assign_fp2(ctx, value.map(|e| e[0]), range)?;
+        let c1 = self.fp2_chip.assign_fp2(ctx, value.map(|e| e[1]), range)?;
+        let c2 = self.fp2_chip.assign_fp2(ctx, value.map(|e| e[2]), range)?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Assigns a constant element
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: [(W, W); 3],
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp2_chip.assign_constant(ctx, value[0])?;
+        let c1 = self.fp2_chip.assign_constant(ctx, value[1])?;
+        let c2 = self.fp2_chip.assign_constant(ctx, value[2])?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Adds two elements
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp2_chip.add(ctx, &a.c0, &b.c0)?;
+        let c1 = self.fp2_chip.add(ctx, &a.c1, &b.c1)?;
+        let c2 = self.fp2_chip.add(ctx, &a.c2, &b.c2)?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Subtracts `b` from `a`
+    pub fn sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp2_chip.sub(ctx, &a.c0, &b.c0)?;
+        let c1 = self.fp2_chip.sub(ctx, &a.c1, &b.c1)?;
+        let c2 = self.fp2_chip.sub(ctx, &a.c2, &b.c2)?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Negates an element
+    pub fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp2_chip.neg(ctx, &a.c0)?;
+        let c1 = self.fp2_chip.neg(ctx, &a.c1)?;
+        let c2 = self.fp2_chip.neg(ctx, &a.c2)?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Doubles an element
+    pub fn double(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp2_chip.double(ctx, &a.c0)?;
+        let c1 = self.fp2_chip.double(ctx, &a.c1)?;
+        let c2 = self.fp2_chip.double(ctx, &a.c2)?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Multiplies two elements with six multiplications in the quadratic
+    /// extension
+    /// `c0 = a0 * b0 + non_residue * ((a1 + a2) * (b1 + b2) - a1 * b1 - a2 * b2)`
+    /// `c1 = (a0 + a1) * (b0 + b1) - a0 * b0 - a1 * b1 + non_residue * a2 * b2`
+    /// `c2 = (a0 + a2) * (b0 + b2) - a0 * b0 - a2 * b2 + a1 * b1`
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp2_chip = &self.fp2_chip;
+        let v0 = fp2_chip.mul(ctx, &a.c0, &b.c0)?;
+        let v1 = fp2_chip.mul(ctx, &a.c1, &b.c1)?;
+        let v2 = fp2_chip.mul(ctx, &a.c2, &b.c2)?;
+
+        let t = {
+            let a_sum = fp2_chip.add(ctx, &a.c1, &a.c2)?;
+            let b_sum = fp2_chip.add(ctx, &b.c1, &b.c2)?;
+            let t = fp2_chip.mul(ctx, &a_sum, &b_sum)?;
+            let t = fp2_chip.sub(ctx, &t, &v1)?;
+            fp2_chip.sub(ctx, &t, &v2)?
+        };
+        let t = self.mul_by_non_residue_fp2(ctx, &t)?;
+        let c0 = fp2_chip.add(ctx, &v0, &t)?;
+
+        let c1 = {
+            let a_sum = fp2_chip.add(ctx, &a.c0, &a.c1)?;
+            let b_sum = fp2_chip.add(ctx, &b.c0, &b.c1)?;
+            let t = fp2_chip.mul(ctx, &a_sum, &b_sum)?;
+            let t = fp2_chip.sub(ctx, &t, &v0)?;
+            let t = fp2_chip.sub(ctx, &t, &v1)?;
+            let v2 = self.mul_by_non_residue_fp2(ctx, &v2)?;
+            fp2_chip.add(ctx, &t, &v2)?
+        };
+
+        let c2 = {
+            let a_sum = fp2_chip.add(ctx, &a.c0, &a.c2)?;
+            let b_sum = fp2_chip.add(ctx, &b.c0, &b.c2)?;
+            let t = fp2_chip.mul(ctx, &a_sum, &b_sum)?;
+            let t = fp2_chip.sub(ctx, &t, &v0)?;
+            let t = fp2_chip.sub(ctx, &t, &v2)?;
+            fp2_chip.add(ctx, &t, &v1)?
+        };
+
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Squares an element with Chung-Hasan SQR2 method
+    pub fn square(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp2_chip = &self.fp2_chip;
+        // s0 = a0^2, s1 = 2 * a0 * a1, s2 = (a0 - a1 + a2)^2, s3 = 2 * a1 * a2,
+        // s4 = a2^2
+        let s0 = fp2_chip.square(ctx, &a.c0)?;
+        let s1 = fp2_chip.mul(ctx, &a.c0, &a.c1)?;
+        let s1 = fp2_chip.double(ctx, &s1)?;
+        let s2 = fp2_chip.sub(ctx, &a.c0, &a.c1)?;
+        let s2 = fp2_chip.add(ctx, &s2, &a.c2)?;
+        let s2 = fp2_chip.square(ctx, &s2)?;
+        let s3 = fp2_chip.mul(ctx, &a.c1, &a.c2)?;
+        let s3 = fp2_chip.double(ctx, &s3)?;
+        let s4 = fp2_chip.square(ctx, &a.c2)?;
+
+        // c0 = s0 + non_residue * s3
+        let t = self.mul_by_non_residue_fp2(ctx, &s3)?;
+        let c0 = fp2_chip.add(ctx, &s0, &t)?;
+        // c1 = s1 + non_residue * s4
+        let t = self.mul_by_non_residue_fp2(ctx, &s4)?;
+        let c1 = fp2_chip.add(ctx, &s1, &t)?;
+        // c2 = s1 + s2 + s3 - s0 - s4
+        let c2 = fp2_chip.add(ctx, &s1, &s2)?;
+        let c2 = fp2_chip.add(ctx, &c2, &s3)?;
+        let c2 = fp2_chip.sub(ctx, &c2, &s0)?;
+        let c2 = fp2_chip.sub(ctx, &c2, &s4)?;
+
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Multiplies an element by an element of the quadratic extension
+    pub fn mul_by_fp2(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp2_chip.mul(ctx, &a.c0, b)?;
+        let c1 = self.fp2_chip.mul(ctx, &a.c1, b)?;
+        let c2 = self.fp2_chip.mul(ctx, &a.c2, b)?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Multiplies an element by `v`
+    pub fn mul_by_non_residue(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.mul_by_non_residue_fp2(ctx, &a.c2)?;
+        Ok(AssignedFp6::new(c0, a.c0.clone(), a.c1.clone()))
+    }
+
+    /// Multiplies an element by the sparse element `b0 + b1 * v` with five
+    /// multiplications in the quadratic extension
+    pub fn mul_by_01(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b0: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b1: &AssignedFp2<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp2_chip = &self.fp2_chip;
+        let v0 = fp2_chip.mul(ctx, &a.c0, b0)?;
+        let v1 = fp2_chip.mul(ctx, &a.c1, b1)?;
+
+        // c0 = a0 * b0 + non_residue * a2 * b1
+        let t = fp2_chip.mul(ctx, &a.c2, b1)?;
+        let t = self.mul_by_non_residue_fp2(ctx, &t)?;
+        let c0 = fp2_chip.add(ctx, &v0, &t)?;
+        // c1 = (a0 + a1) * (b0 + b1) - a0 * b0 - a1 * b1
+        let a_sum = fp2_chip.add(ctx, &a.c0, &a.c1)?;
+        let b_sum = fp2_chip.add(ctx, b0, b1)?;
+        let c1 = fp2_chip.mul(ctx, &a_sum, &b_sum)?;
+        let c1 = fp2_chip.sub(ctx, &c1, &v0)?;
+        let c1 = fp2_chip.sub(ctx, &c1, &v1)?;
+        // c2 = a1 * b1 + a2 * b0
+        let t = fp2_chip.mul(ctx, &a.c2, b0)?;
+        let c2 = fp2_chip.add(ctx, &v1, &t)?;
+
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Inverts an element. An [`AssignedCondition`] is returned along with
+    /// the result indicating if the input was zero in which case the result is
+    /// zero.
+    pub fn invert(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        let fp2_chip = &self.fp2_chip;
+        // t0 = a0^2 - non_residue * a1 * a2
+        let t0 = fp2_chip.square(ctx, &a.c0)?;
+        let t = fp2_chip.mul(ctx, &a.c1, &a.c2)?;
+        let t = self.mul_by_non_residue_fp2(ctx, &t)?;
+        let t0 = fp2_chip.sub(ctx, &t0, &t)?;
+        // t1 = non_residue * a2^2 - a0 * a1
+        let t1 = fp2_chip.square(ctx, &a.c2)?;
+        let t1 = self.mul_by_non_residue_fp2(ctx, &t1)?;
+        let t = fp2_chip.mul(ctx, &a.c0, &a.c1)?;
+        let t1 = fp2_chip.sub(ctx, &t1, &t)?;
+        // t2 = a1^2 - a0 * a2
+        let t2 = fp2_chip.square(ctx, &a.c1)?;
+        let t = fp2_chip.mul(ctx, &a.c0, &a.c2)?;
+        let t2 = fp2_chip.sub(ctx, &t2, &t)?;
+
+        // norm = a0 * t0 + non_residue * (a2 * t1 + a1 * t2)
+        let u0 = fp2_chip.mul(ctx, &a.c2, &t1)?;
+        let u1 = fp2_chip.mul(ctx, &a.c1, &t2)?;
+        let u = fp2_chip.add(ctx, &u0, &u1)?;
+        let u = self.mul_by_non_residue_fp2(ctx, &u)?;
+        let norm = fp2_chip.mul(ctx, &a.c0, &t0)?;
+        let norm = fp2_chip.add(ctx, &norm, &u)?;
+        let (norm_inv, is_zero) = fp2_chip.invert(ctx, &norm)?;
+
+        let c = self.mul_by_fp2(ctx, &AssignedFp6::new(t0, t1, t2), &norm_inv)?;
+        Ok((c, is_zero))
+    }
+
+    /// Applies Frobenius map `a^(p^power)`
+    pub fn frobenius_map(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        power: usize,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let fp2_chip = &self.fp2_chip;
+        let (coeff1, coeff2) = self.frobenius_coeffs[power % 6];
+        let c0 = fp2_chip.frobenius_map(ctx, &a.c0, power)?;
+        let c1 = fp2_chip.frobenius_map(ctx, &a.c1, power)?;
+        let c1 = fp2_chip.mul_constant(ctx, &c1, coeff1)?;
+        let c2 = fp2_chip.frobenius_map(ctx, &a.c2, power)?;
+        let c2 = fp2_chip.mul_constant(ctx, &c2, coeff2)?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Selects `a` if the condition is set and `b` otherwise
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp2_chip.select(ctx, &a.c0, &b.c0, cond)?;
+        let c1 = self.fp2_chip.select(ctx, &a.c1, &b.c1, cond)?;
+        let c2 = self.fp2_chip.select(ctx, &a.c2, &b.c2, cond)?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Reduces all coefficients
+    pub fn reduce(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let c0 = self.fp2_chip.reduce(ctx, &a.c0)?;
+        let c1 = self.fp2_chip.reduce(ctx, &a.c1)?;
+        let c2 = self.fp2_chip.reduce(ctx, &a.c2)?;
+        Ok(AssignedFp6::new(c0, c1, c2))
+    }
+
+    /// Constrains two elements to be equal
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedFp6<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        self.fp2_chip.assert_equal(ctx, &a.c0, &b.c0)?;
+        self.fp2_chip.assert_equal(ctx, &a.c1, &b.c1)?;
+        self.fp2_chip.assert_equal(ctx, &a.c2, &b.c2)
+    }
+}
@@ -3,10 +3,11 @@ use crate::maingate::{halo2, AssignedCondition, RegionCtx};
 use crate::rns::Integer;
 use halo2::plonk::Error;
 use maingate::halo2::ff::PrimeField;
+use num_bigint::BigUint as big_uint;
 
 /// Signals the range mode that should be applied while assigning a new
 /// [`Integer`]
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub enum Range {
     /// Allowed range for multiplication result
     Remainder,
@@ -121,6 +122,20 @@ pub trait IntegerInstructions<
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Returns `a_0 * b_0 + a_1 * b_1 + ... + c_0 + c_1 + ... - d_0 - d_1 -
+    /// ...` where products are accumulated and reduced only once. Total
+    /// number of terms is limited by [`crate::rns::Rns`] `max_mul_add_terms`.
+    fn mul_add_many(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        products: &[(
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+        additions: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        subtractions: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
     /// Multiplies [`AssignedInteger`] by constant.
     fn mul_constant(
         &self,
@@ -145,6 +160,27 @@ pub trait IntegerInstructions<
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Raises an [`AssignedInteger`] to a constant power. Follows a sliding
+    /// window addition chain of the exponent where each step is a single
+    /// multiplication. Only the input is checked for reduction, steps of the
+    /// chain take the products of earlier steps as they are.
+    fn pow_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        exponent: &big_uint,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Raises an [`AssignedInteger`] to a power that is given as assigned bits
+    /// in little endian order. Bits are consumed in 2 bit windows so that
+    /// there is a single multiplication for each window.
+    fn pow(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        exponent: &[AssignedCondition<N>],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
     /// Divides 2 [`AssignedInteger`]. An [`AssignedCondition`] is returned
     /// along with the division result indicating if the operation was
     /// successful.
@@ -184,6 +220,25 @@ pub trait IntegerInstructions<
         Error,
     >;
 
+    /// Inverts many [`AssignedInteger`]. Each inverse comes with an
+    /// [`AssignedCondition`] that is set if the entry is zero, in which case
+    /// the inverse is one. The last returned [`AssignedCondition`] is set if
+    /// any of the entries is zero.
+    fn batch_invert(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+    ) -> Result<
+        (
+            Vec<(
+                AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+                AssignedCondition<N>,
+            )>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    >;
+
     /// Inverts an [`AssignedInteger`]. Assumes the input is not zero.
     fn invert_incomplete(
         &self,
@@ -191,6 +246,30 @@ pub trait IntegerInstructions<
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Finds square root of an [`AssignedInteger`]. An [`AssignedCondition`]
+    /// is returned along with the root indicating if the input is a quadratic
+    /// residue. If it is not, returned root is the square root of the input
+    /// multiplied by a fixed non residue of the wrong field.
+    fn sqrt(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedCondition<N>,
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        ),
+        Error,
+    >;
+
+    /// Returns an [`AssignedCondition`] that is set if the input is a
+    /// quadratic residue in the wrong field. Zero is considered as a square.
+    fn is_square(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error>;
+
     /// Applies reduction to an [`AssignedInteger`]. Reduces the input less than
     /// next power of two of the modulus
     fn reduce(
@@ -267,6 +346,40 @@ pub trait IntegerInstructions<
         input: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error>;
 
+    /// Returns 1 if an [`AssignedInteger`] is equal to zero in the field and 0
+    /// otherwise
+    fn is_zero(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error>;
+
+    /// Returns 1 if two [`AssignedInteger`] are equal in the field and 0
+    /// otherwise
+    fn is_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error>;
+
+    /// Returns 1 if limbs of two [`AssignedInteger`] are equal and 0 otherwise
+    fn is_strict_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error>;
+
+    /// Returns 1 if in-field value of `a` is less than in-field value of `b`
+    /// and 0 otherwise
+    fn is_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error>;
+
     /// Given an [`AssignedCondition`] returns picks one of two
     /// [`AssignedInteger`]
     fn select(
@@ -16,22 +16,57 @@ pub use chip::{IntegerChip, IntegerConfig};
 pub use instructions::{IntegerInstructions, Range};
 pub use maingate;
 pub use maingate::halo2;
+pub use small_field::{AssignedSmallField, SmallFieldChip};
+pub use unbounded::{AssignedBigUint, BigUintChip};
 
 #[cfg(test)]
 use halo2::halo2curves as curves;
 
 /// Chip for integer constaints
 pub mod chip;
+/// Extension field arithmetic
+pub mod extension;
 /// Commoon instructions for integer operations and assignments
 pub mod instructions;
 /// Residue number system construction and utilities
 pub mod rns;
+/// Serialization of witnesses with `serde`
+#[cfg(feature = "serde")]
+pub mod serialization;
+/// Chip for wrong fields that fit in a native cell
+pub mod small_field;
+/// Chip for unsigned integers that are not bound to a field
+pub mod unbounded;
+/// Fixed width unsigned integers for witness arithmetic
+mod wide;
 
 /// `RangeChip` supports upto four full limbs decomposition of a value
 /// `AssignedLimb` is mostly subjected to the range check. Say we have 68-bit
 /// limb and it is decomposed to four 17-bit limbs.
 pub const NUMBER_OF_LOOKUP_LIMBS: usize = 4;
 
+/// Upper bound for bit length of sublimbs. Limbs that would have wider sublimbs
+/// with `NUMBER_OF_LOOKUP_LIMBS` decomposition are decomposed into more
+/// sublimbs. Say we have 88-bit limb and it is decomposed to eight 11-bit
+/// limbs.
+pub const MAX_SUBLIMB_BIT_LEN: usize = 18;
+
+/// Returns bit length of sublimbs that a limb is decomposed into for the
+/// lookup range checks. Limbs are decomposed into at least
+/// `NUMBER_OF_LOOKUP_LIMBS` sublimbs and more if sublimbs would be wider than
+/// `MAX_SUBLIMB_BIT_LEN`
+pub fn sublimb_bit_len(bit_len_limb: usize) -> usize {
+    checked_sublimb_bit_len(bit_len_limb).expect("limb cannot be decomposed into lookup limbs")
+}
+
+/// Same as [`sublimb_bit_len`] but returns `None` if the limb cannot be
+/// decomposed into lookup limbs
+pub fn checked_sublimb_bit_len(bit_len_limb: usize) -> Option<usize> {
+    (NUMBER_OF_LOOKUP_LIMBS..=bit_len_limb)
+        .find(|n| bit_len_limb % n == 0 && bit_len_limb / n <= MAX_SUBLIMB_BIT_LEN)
+        .map(|number_of_lookup_limbs| bit_len_limb / number_of_lookup_limbs)
+}
+
 /// AssignedLimb is a limb of an non native integer
 #[derive(Debug, Clone)]
 pub struct AssignedLimb<F: PrimeField> {
@@ -109,6 +144,11 @@ impl<F: PrimeField> AssignedLimb<F> {
     }
 }
 
+/// Assigned value that stands for a single byte. Chips taking bytes as input
+/// constrain them to be less than `2^8` and chips returning bytes compose them
+/// from bits.
+pub type AssignedByte<F> = AssignedValue<F>;
+
 /// Witness integer that is about to be assigned.
 #[derive(Debug, Clone)]
 pub struct UnassignedInteger<
@@ -1,9 +1,11 @@
-use crate::NUMBER_OF_LOOKUP_LIMBS;
+use crate::checked_sublimb_bit_len;
+use crate::wide::Wide;
 use halo2::circuit::Value;
 use maingate::halo2::ff::PrimeField;
-use maingate::{big_to_fe, compose, decompose_big, fe_to_big, halo2, modulus};
+use maingate::{
+    big_to_fe, compose, compose_fe, decompose_big, fe_to_big, halo2, modulus, power_of_two,
+};
 use num_bigint::BigUint as big_uint;
-use num_integer::Integer as _;
 use num_traits::{Num, One, Zero};
 use std::fmt;
 use std::marker::PhantomData;
@@ -110,8 +112,8 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.0.as_ref().map(|u| u.result.clone())
     }
 
-    pub(crate) fn residues(&self) -> Vec<Value<N>> {
-        let u_len = (NUMBER_OF_LIMBS + 1) / 2;
+    pub(crate) fn residues(&self, residue_limbs: usize) -> Vec<Value<N>> {
+        let u_len = (NUMBER_OF_LIMBS + residue_limbs - 1) / residue_limbs;
         (0..u_len)
             .map(|i| self.0.as_ref().map(|witness| witness.residues[i]))
             .collect()
@@ -155,6 +157,74 @@ pub(crate) struct ComparisionWitness<
     pub(crate) borrow: [bool; NUMBER_OF_LIMBS],
 }
 
+/// Bound that fails while constructing an [`Rns`] for a choice of
+/// `NUMBER_OF_LIMBS` and `BIT_LEN_LIMB`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RnsError {
+    /// `NUMBER_OF_LIMBS` must be at least three
+    NumberOfLimbs,
+    /// `BIT_LEN_LIMB` must be a multiple of four
+    LimbBitLength,
+    /// Limbs cannot be decomposed into lookup sublimbs
+    SublimbBitLength,
+    /// `binary_modulus` must be above both the wrong and the native modulus
+    BinaryModulus,
+    /// `max_remainder` must be above the wrong modulus and below the binary
+    /// modulus
+    MaxRemainder,
+    /// `max_mul_quotient` must be above the wrong modulus and below the
+    /// binary modulus
+    MaxMulQuotient,
+    /// `max_operand` must be above the wrong modulus and below the binary
+    /// modulus and its square must be reducible
+    MaxOperand,
+    /// `max_mul_quotient * wrong_modulus + max_remainder` wraps `crt_modulus`
+    CrtModulus,
+    /// `max_unreduced_limb` is not wider than a limb
+    MaxUnreducedLimb,
+    /// Residues of multiplication wrap the native modulus
+    MulVBitLen,
+    /// Residues of reduction wrap the native modulus
+    RedVBitLen,
+    /// Intermediate values of multiplication wrap the native modulus
+    Intermediate,
+    /// `base_aux` is not a multiple of the wrong modulus above
+    /// `max_remainder` limb by limb
+    BaseAux,
+    /// Not even a single product fits into a sum of products
+    MaxMulAddTerms,
+    /// Integers with `max_unreduced_limb` limbs are not reducible with a
+    /// single limb quotient
+    Unreducible,
+}
+
+impl fmt::Display for RnsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self {
+            RnsError::NumberOfLimbs => "number of limbs must be at least three",
+            RnsError::LimbBitLength => "limb bit length must be a multiple of four",
+            RnsError::SublimbBitLength => "limb cannot be decomposed into lookup limbs",
+            RnsError::BinaryModulus => {
+                "binary modulus must be above the wrong and the native modulus"
+            }
+            RnsError::MaxRemainder => "max_remainder is out of bounds",
+            RnsError::MaxMulQuotient => "max_mul_quotient is out of bounds",
+            RnsError::MaxOperand => "max_operand is out of bounds",
+            RnsError::CrtModulus => "multiplication wraps crt_modulus",
+            RnsError::MaxUnreducedLimb => "max_unreduced_limb is not wider than a limb",
+            RnsError::MulVBitLen => "mul_v_bit_len wraps the native modulus",
+            RnsError::RedVBitLen => "red_v_bit_len wraps the native modulus",
+            RnsError::Intermediate => "multiplication intermediates wrap the native modulus",
+            RnsError::BaseAux => "base_aux is out of bounds",
+            RnsError::MaxMulAddTerms => "sum of products doesn't fit a single product",
+            RnsError::Unreducible => "max_unreduced_limb is not reducible",
+        };
+        write!(f, "invalid rns: {}", reason)
+    }
+}
+
+impl std::error::Error for RnsError {}
+
 /// Residue Numeral System
 /// Representation of an integer holding its values modulo several coprime
 /// integers.
@@ -173,6 +243,8 @@ pub struct Rns<
 
     /// Order of the wrong field W. (In the article `p`).
     pub wrong_modulus: big_uint,
+    /// Wrong modulus as fixed width integer for witness arithmetic.
+    pub(crate) wrong_modulus_wide: Wide,
     /// Order of the native field N. (In the article `n`).
     pub native_modulus: big_uint,
     /// Order of the binary field (In the article: 2^t).
@@ -198,6 +270,12 @@ pub struct Rns<
     pub wrong_modulus_minus_one: [N; NUMBER_OF_LIMBS],
     /// Wrong modulus as native field element: `p mod n`.
     pub wrong_modulus_in_native_modulus: N,
+    /// Offset `c` of a pseudo-Mersenne wrong modulus `p = 2^k - c` where `c`
+    /// fits in a single limb. Negative wrong modulus of such moduli has zero
+    /// limbs between the first limb and the limb of bit `k`, which
+    /// multiplication and reduction exploit. `None` if the wrong modulus is
+    /// not in this form or if none of the limbs is zero.
+    pub pseudo_mersenne_offset: Option<big_uint>,
 
     /// Maximum value for a reduced limb.
     pub max_reduced_limb: big_uint,
@@ -218,8 +296,26 @@ pub struct Rns<
     /// Maximum value of most significant limb for `max_mul_quotient`.
     pub max_most_significant_mul_quotient_limb: big_uint,
 
+    /// Maximum values of each limb of `max_remainder`. Limbs above the most
+    /// significant limb of the wrong modulus are zero.
+    pub max_remainder_limbs: [big_uint; NUMBER_OF_LIMBS],
+    /// Maximum values of each limb of `max_operand`.
+    pub max_operand_limbs: [big_uint; NUMBER_OF_LIMBS],
+    /// Maximum values of each limb of `max_mul_quotient`.
+    pub max_mul_quotient_limbs: [big_uint; NUMBER_OF_LIMBS],
+
+    /// Number of limbs that are composed into a single residue in the binary
+    /// part of the CRT check. Residues of wide limbs are composed one by one
+    /// so that the check does not wrap the native modulus.
+    pub residue_limbs: usize,
+
     /// Bit length of the maximum value allowed for residues in multiplication
     pub mul_v_bit_len: usize,
+    /// Maximum number of terms in a sum of products that is reduced once
+    pub max_mul_add_terms: usize,
+    /// Bit length of the maximum value allowed for residues in sum of
+    /// products
+    pub mul_add_v_bit_len: usize,
     /// Bit length of the maximum value allowed for residues in reduction
     /// circuit.
     pub red_v_bit_len: usize,
@@ -247,12 +343,14 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .map(|limb| fe_to_big(limb) << 1usize)
             .collect();
 
-        // If value of a limb is not above dense limb borrow from the next one
+        // If value of a limb is not above dense limb borrow from the next one.
+        // Limbs above the most significant limb of the wrong modulus are left
+        // as zero
         for i in 0..NUMBER_OF_LIMBS - 1 {
             let hidx = NUMBER_OF_LIMBS - i - 1;
             let lidx = hidx - 1;
 
-            if (base_aux[lidx].bits() as usize) < (BIT_LEN_LIMB + 1) {
+            if (base_aux[lidx].bits() as usize) < (BIT_LEN_LIMB + 1) && !base_aux[hidx].is_zero() {
                 base_aux[hidx] = base_aux[hidx].clone() - 1usize;
                 base_aux[lidx] = base_aux[lidx].clone() + r;
             }
@@ -262,12 +360,33 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     }
 
     /// Calculates and builds a [`Rns`] with all its necessary values given
-    /// the bit length used for its limbs.
+    /// the bit length used for its limbs. Panics if the parameters are not
+    /// valid, see [`Rns::try_construct`].
     pub fn construct() -> Self {
-        assert!(NUMBER_OF_LIMBS > 2);
+        match Self::try_construct() {
+            Ok(rns) => rns,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Calculates and builds a [`Rns`] with all its necessary values given
+    /// the bit length used for its limbs. Returns the bound that fails if the
+    /// parameters are not valid.
+    pub fn try_construct() -> Result<Self, RnsError> {
+        macro_rules! ensure {
+            ($cond:expr, $err:expr) => {
+                if !$cond {
+                    return Err($err);
+                }
+            };
+        }
+
+        ensure!(NUMBER_OF_LIMBS > 2, RnsError::NumberOfLimbs);
 
         // Limitation of range chip!
-        assert!(BIT_LEN_LIMB % 4 == 0);
+        ensure!(BIT_LEN_LIMB % 4 == 0, RnsError::LimbBitLength);
+        let bit_len_lookup =
+            checked_sublimb_bit_len(BIT_LEN_LIMB).ok_or(RnsError::SublimbBitLength)?;
 
         let one = &big_uint::one();
 
@@ -294,6 +413,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         let wrong_modulus = &modulus::<W>();
         // native field modulus: `n`
         let native_modulus = &modulus::<N>();
+        ensure!(
+            binary_modulus > wrong_modulus && binary_modulus > native_modulus,
+            RnsError::BinaryModulus
+        );
 
         // Multiplication is constrained as:
         //
@@ -310,6 +433,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         // Find maxium quotient that won't wrap `quotient * wrong + remainder` side of
         // the equation under `crt_modulus`.
         let pre_max_quotient = &((crt_modulus - max_remainder) / wrong_modulus);
+        ensure!(pre_max_quotient.bits() > 1, RnsError::MaxMulQuotient);
         // Lower this value to make this value suitable for bit range checks.
         let max_quotient = &(log_floor!(pre_max_quotient) - one);
 
@@ -324,7 +448,16 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         // `max_operand^2 < crt_modulus`
         //
         // must hold.
+        //
+        // Operands are also limited to the limbs that the wrong modulus spans so
+        // that more significant limbs of integers other than quotients are zero.
         let max_operand_bit_len = ((max_quotient * wrong_modulus + max_remainder).bits() - 1) / 2;
+        let number_of_wrong_modulus_limbs =
+            (wrong_modulus.bits() as usize + BIT_LEN_LIMB - 1) / BIT_LEN_LIMB;
+        let max_operand_bit_len = std::cmp::min(
+            max_operand_bit_len as usize,
+            number_of_wrong_modulus_limbs * BIT_LEN_LIMB,
+        );
         let max_operand = &((one << max_operand_bit_len) - one);
 
         // Sanity check
@@ -332,19 +465,21 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             let lhs = &(max_operand * max_operand);
             let rhs = &(max_quotient * wrong_modulus + max_remainder);
 
-            assert!(binary_modulus > wrong_modulus);
-            assert!(binary_modulus > native_modulus);
-
-            assert!(max_remainder > wrong_modulus);
-            assert!(max_operand > wrong_modulus);
-            assert!(max_quotient > wrong_modulus);
-
-            assert!(max_remainder < binary_modulus);
-            assert!(max_operand < binary_modulus);
-            assert!(max_quotient < binary_modulus);
-
-            assert!(rhs < crt_modulus);
-            assert!(lhs < rhs);
+            ensure!(
+                max_remainder > wrong_modulus && max_remainder < binary_modulus,
+                RnsError::MaxRemainder
+            );
+            ensure!(
+                max_quotient > wrong_modulus && max_quotient < binary_modulus,
+                RnsError::MaxMulQuotient
+            );
+            ensure!(
+                max_operand > wrong_modulus && max_operand < binary_modulus,
+                RnsError::MaxOperand
+            );
+
+            ensure!(rhs < crt_modulus, RnsError::CrtModulus);
+            ensure!(lhs < rhs, RnsError::MaxOperand);
         }
 
         // negative wrong field modulus moduli binary modulus `w'`
@@ -376,10 +511,6 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         // Full dense limb without overflow
         let max_reduced_limb = &(one << BIT_LEN_LIMB) - one;
 
-        // Keep this much lower than what we can reduce with single limb quotient to
-        // take extra measure for overflow issues
-        let max_unreduced_limb = &(one << (BIT_LEN_LIMB + BIT_LEN_LIMB / 2)) - one;
-
         // Most significant limbs are subjected to different range checks which will be
         // probably less than full sized limbs.
         let max_most_significant_reduced_limb =
@@ -389,121 +520,121 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         let max_most_significant_mul_quotient_limb =
             &(max_quotient >> ((NUMBER_OF_LIMBS - 1) * BIT_LEN_LIMB));
 
-        // Emulate a multiplication to find out max residue overflows:
-        let mut mul_v_bit_len: usize = BIT_LEN_LIMB;
-        {
-            // Maximum operand
-            let a = (0..NUMBER_OF_LIMBS)
+        // Splits a maximum value into maximum values of limbs where the last limb
+        // takes the rest
+        let max_limbs = |max: &big_uint| -> [big_uint; NUMBER_OF_LIMBS] {
+            (0..NUMBER_OF_LIMBS)
                 .map(|i| {
-                    if i != NUMBER_OF_LIMBS - 1 {
-                        max_reduced_limb.clone()
+                    let limb = max >> (i * BIT_LEN_LIMB);
+                    if i == NUMBER_OF_LIMBS - 1 {
+                        limb
                     } else {
-                        max_most_significant_operand_limb.clone()
+                        std::cmp::min(limb, max_reduced_limb.clone())
                     }
                 })
-                .collect::<Vec<big_uint>>();
-
-            let p: Vec<big_uint> = negative_wrong_modulus_decomposed
-                .iter()
-                .map(|e| fe_to_big(*e))
-                .collect();
-
-            // Maximum quotient
-            let q = (0..NUMBER_OF_LIMBS)
-                .map(|i| {
-                    if i != NUMBER_OF_LIMBS - 1 {
-                        max_reduced_limb.clone()
-                    } else {
-                        max_most_significant_mul_quotient_limb.clone()
-                    }
-                })
-                .collect::<Vec<big_uint>>();
+                .collect::<Vec<big_uint>>()
+                .try_into()
+                .unwrap()
+        };
+        let max_remainder_limbs = max_limbs(max_remainder);
+        let max_operand_limbs = max_limbs(max_operand);
+        let max_mul_quotient_limbs = max_limbs(max_quotient);
 
-            // Find intermediate maximums
-            let mut t = vec![big_uint::zero(); 2 * NUMBER_OF_LIMBS - 1];
-            for i in 0..NUMBER_OF_LIMBS {
-                for j in 0..NUMBER_OF_LIMBS {
-                    t[i + j] = &t[i + j] + &a[i] * &a[j] + &p[i] * &q[j];
+        // Keep this much lower than what we can reduce with single limb quotient to
+        // take extra measure for overflow issues. Limbs are allowed to grow two
+        // more bits by an operation like `a - b + aux` before they are reduced.
+        let max_unreduced_limb = {
+            let mut bit_len = BIT_LEN_LIMB + BIT_LEN_LIMB / 2;
+            let max_reducible_value = &max_reduced_limb * wrong_modulus + max_remainder;
+            loop {
+                let limb = &((one << (bit_len + 2)) - one);
+                let limbs = max_remainder_limbs
+                    .iter()
+                    .map(|max| {
+                        if max.is_zero() {
+                            big_uint::zero()
+                        } else {
+                            limb.clone()
+                        }
+                    })
+                    .collect();
+                if compose(limbs, BIT_LEN_LIMB) < max_reducible_value {
+                    break;
                 }
+                ensure!(bit_len > BIT_LEN_LIMB + 1, RnsError::MaxUnreducedLimb);
+                bit_len -= 1;
             }
-
-            let is_odd = NUMBER_OF_LIMBS & 1 == 1;
-            let u_len = (NUMBER_OF_LIMBS + 1) / 2;
-
-            let mut carry = big_uint::zero();
-            for i in 0..u_len {
-                let v = if (i == u_len - 1) && is_odd {
-                    // odd and last iter
-                    let u = &t[i] + &carry;
-                    u >> BIT_LEN_LIMB
-                } else {
-                    let u = &t[i] + (&t[i + 1] << BIT_LEN_LIMB) + &carry;
-                    u >> (2 * BIT_LEN_LIMB)
-                };
-                carry = v.clone();
-                mul_v_bit_len = std::cmp::max(v.bits() as usize, mul_v_bit_len)
-            }
+            (one << bit_len) - one
         };
 
-        // Emulate a multiplication to find out max residue overflows:
-        let mut red_v_bit_len: usize = BIT_LEN_LIMB;
-        {
-            // Maximum operand
-            let a = (0..NUMBER_OF_LIMBS)
-                .map(|i| {
-                    if i != NUMBER_OF_LIMBS - 1 {
-                        max_reduced_limb.clone()
-                    } else {
-                        max_most_significant_operand_limb.clone()
-                    }
-                })
-                .collect::<Vec<big_uint>>();
-
-            let p: Vec<big_uint> = negative_wrong_modulus_decomposed
-                .iter()
-                .map(|e| fe_to_big(*e))
-                .collect();
+        let p: Vec<big_uint> = negative_wrong_modulus_decomposed
+            .iter()
+            .map(|e| fe_to_big(*e))
+            .collect();
 
-            // Maximum quorient
-            let q = (0..NUMBER_OF_LIMBS)
-                .map(|i| {
-                    if i != NUMBER_OF_LIMBS - 1 {
-                        max_reduced_limb.clone()
-                    } else {
-                        max_most_significant_mul_quotient_limb.clone()
-                    }
-                })
-                .collect::<Vec<big_uint>>();
+        // Maximum operand
+        let a = &max_operand_limbs;
+        // Maximum quotient
+        let q = &max_mul_quotient_limbs;
 
-            // Find intermediate maximums
-            let mut t = vec![big_uint::zero(); 2 * NUMBER_OF_LIMBS - 1];
-            for i in 0..NUMBER_OF_LIMBS {
-                for j in 0..NUMBER_OF_LIMBS {
-                    t[i + j] = &t[i + j] + &a[i] + &p[i] * &q[j];
-                }
+        // Find intermediate maximums of multiplication
+        let mut t_mul = vec![big_uint::zero(); NUMBER_OF_LIMBS];
+        for i in 0..NUMBER_OF_LIMBS {
+            for j in 0..NUMBER_OF_LIMBS - i {
+                t_mul[i + j] = &t_mul[i + j] + &a[i] * &a[j] + &p[i] * &q[j];
             }
+        }
 
-            let is_odd = NUMBER_OF_LIMBS & 1 == 1;
-            let u_len = (NUMBER_OF_LIMBS + 1) / 2;
+        // Find intermediate maximums of reduction
+        let mut t_red = vec![big_uint::zero(); NUMBER_OF_LIMBS];
+        for i in 0..NUMBER_OF_LIMBS {
+            for j in 0..NUMBER_OF_LIMBS - i {
+                t_red[i + j] = &t_red[i + j] + &a[i] + &p[i] * &q[j];
+            }
+        }
 
+        // Emulate residue calculation to find out max residue overflows where
+        // `residue_limbs` limbs are composed into a residue
+        let residue_bit_len = |t: &[big_uint], residue_limbs: usize| -> usize {
+            let mut bit_len = BIT_LEN_LIMB;
             let mut carry = big_uint::zero();
-            for i in 0..u_len {
-                let v = if (i == u_len - 1) && is_odd {
-                    // odd and last iter
-                    let u = &t[i] + &carry;
-                    u >> BIT_LEN_LIMB
-                } else {
-                    let u = &t[i] + (&t[i + 1] << BIT_LEN_LIMB) + &carry;
-                    u >> (2 * BIT_LEN_LIMB)
-                };
-                carry = v.clone();
-                red_v_bit_len = std::cmp::max(v.bits() as usize, red_v_bit_len)
+            for t in t.chunks(residue_limbs) {
+                let u = t
+                    .iter()
+                    .enumerate()
+                    .fold(carry.clone(), |acc, (i, t)| acc + (t << (i * BIT_LEN_LIMB)));
+                let v = u >> (t.len() * BIT_LEN_LIMB);
+                bit_len = std::cmp::max(v.bits() as usize, bit_len);
+                carry = v;
             }
+            bit_len
         };
 
-        let bit_len_lookup = BIT_LEN_LIMB / NUMBER_OF_LOOKUP_LIMBS;
-        // Assert that bit length of limbs is divisible by sub limbs for lookup
-        assert!(bit_len_lookup * NUMBER_OF_LOOKUP_LIMBS == BIT_LEN_LIMB);
+        // Two limbs are composed into a residue unless the composition wraps the
+        // native modulus
+        let capacity = N::CAPACITY as usize;
+        let residue_limbs = if 2 * BIT_LEN_LIMB + residue_bit_len(&t_mul, 2) < capacity
+            && 2 * BIT_LEN_LIMB + residue_bit_len(&t_red, 2) < capacity
+        {
+            2
+        } else {
+            1
+        };
+        let mul_v_bit_len = residue_bit_len(&t_mul, residue_limbs);
+        let red_v_bit_len = residue_bit_len(&t_red, residue_limbs);
+        ensure!(
+            residue_limbs * BIT_LEN_LIMB + mul_v_bit_len < capacity,
+            RnsError::MulVBitLen
+        );
+        ensure!(
+            residue_limbs * BIT_LEN_LIMB + red_v_bit_len < capacity,
+            RnsError::RedVBitLen
+        );
+        // Intermediate values must not wrap the native modulus either
+        ensure!(
+            t_mul.iter().all(|t| (t.bits() as usize) < capacity),
+            RnsError::Intermediate
+        );
 
         // Calculate auxillary value for subtraction
         let base_aux = Self::calculate_base_aux();
@@ -511,25 +642,73 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         {
             let base_aux_value = compose(base_aux.to_vec(), BIT_LEN_LIMB);
             // Must be equal to wrong modulus
-            assert!(base_aux_value.clone() % wrong_modulus == big_uint::zero());
+            ensure!(
+                base_aux_value.clone() % wrong_modulus == big_uint::zero(),
+                RnsError::BaseAux
+            );
             // Expected to be above next power of two
-            assert!(base_aux_value > *max_remainder);
+            ensure!(base_aux_value > *max_remainder, RnsError::BaseAux);
 
             // Assert limbs are above max values
-            for (i, aux) in base_aux.iter().enumerate() {
-                let is_last_limb = i == NUMBER_OF_LIMBS - 1;
-                let target = if is_last_limb {
-                    max_most_significant_reduced_limb.clone()
-                } else {
-                    max_reduced_limb.clone()
-                };
-                assert!(*aux >= target);
+            for (aux, target) in base_aux.iter().zip(max_remainder_limbs.iter()) {
+                ensure!(aux >= target, RnsError::BaseAux);
             }
         }
 
+        // Sum of products `a_0 * b_0 + a_1 * b_1 + ... + c_0 + ... - d_0 - ...`
+        // is reduced once where all operands are in remainder range. Each
+        // addition and subtraction counts as a term as well since with
+        // auxillary value added a subtraction term is bounded by `base_aux`.
+        // Number of terms is limited so that the sum is reducible with a single
+        // multiplication quotient and intermediate values and residues don't
+        // wrap the native modulus.
+        let r = &max_remainder_limbs;
+        let max_term_value = std::cmp::max(
+            max_remainder * max_remainder,
+            compose(base_aux.to_vec(), BIT_LEN_LIMB),
+        );
+        let mut max_term_limbs = base_aux.to_vec();
+        for i in 0..NUMBER_OF_LIMBS {
+            let mut t = big_uint::zero();
+            for j in 0..=i {
+                t += &r[j] * &r[i - j];
+            }
+            max_term_limbs[i] = std::cmp::max(t, max_term_limbs[i].clone());
+        }
+        let t_mul_add = |number_of_terms: usize| -> Vec<big_uint> {
+            let mut t: Vec<big_uint> = max_term_limbs.iter().map(|t| t * number_of_terms).collect();
+            for i in 0..NUMBER_OF_LIMBS {
+                for j in 0..NUMBER_OF_LIMBS - i {
+                    t[i + j] = &t[i + j] + &p[i] * &q[j];
+                }
+            }
+            t
+        };
+        let fits = |number_of_terms: usize| -> bool {
+            let t = t_mul_add(number_of_terms);
+            max_term_value.clone() * number_of_terms <= max_quotient * wrong_modulus
+                && residue_limbs * BIT_LEN_LIMB + residue_bit_len(&t, residue_limbs) < capacity
+                && t.iter().all(|t| (t.bits() as usize) < capacity)
+        };
+        let mut max_mul_add_terms = 16;
+        while !fits(max_mul_add_terms) {
+            max_mul_add_terms /= 2;
+            ensure!(max_mul_add_terms > 0, RnsError::MaxMulAddTerms);
+        }
+        let mul_add_v_bit_len = residue_bit_len(&t_mul_add(max_mul_add_terms), residue_limbs);
+
         let wrong_modulus_in_native_modulus: N =
             big_to_fe(wrong_modulus.clone() % native_modulus.clone());
 
+        // `p = 2^k - c` with `k` the bit length of the wrong modulus
+        let pseudo_mersenne_offset = {
+            let offset = (big_uint::one() << wrong_modulus.bits()) - &wrong_modulus;
+            let is_sparse = negative_wrong_modulus_decomposed
+                .iter()
+                .any(|limb| limb.is_zero_vartime());
+            (offset.bits() as usize <= BIT_LEN_LIMB && is_sparse).then_some(offset)
+        };
+
         // Calculate shifter elements
         let two = N::from(2);
         let two_inv = two.invert().unwrap();
@@ -555,6 +734,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             left_shifters,
 
             wrong_modulus: wrong_modulus.clone(),
+            wrong_modulus_wide: Wide::from_big(wrong_modulus),
             native_modulus: native_modulus.clone(),
             binary_modulus: binary_modulus.clone(),
             crt_modulus: crt_modulus.clone(),
@@ -565,6 +745,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             wrong_modulus_decomposed,
             wrong_modulus_minus_one,
             wrong_modulus_in_native_modulus,
+            pseudo_mersenne_offset,
 
             max_reduced_limb: max_reduced_limb.clone(),
             max_unreduced_limb: max_unreduced_limb.clone(),
@@ -576,27 +757,40 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             max_most_significant_operand_limb: max_most_significant_operand_limb.clone(),
             max_most_significant_mul_quotient_limb: max_most_significant_mul_quotient_limb.clone(),
 
+            max_remainder_limbs: max_remainder_limbs.clone(),
+            max_operand_limbs,
+            max_mul_quotient_limbs,
+
+            residue_limbs,
             mul_v_bit_len,
             red_v_bit_len,
+            max_mul_add_terms,
+            mul_add_v_bit_len,
 
             _marker_wrong: PhantomData,
         };
 
-        // Another sanity check for maximum reducible value:
+        // Another sanity check for maximum reducible value: an integer with
+        // `max_unreduced_limb` limbs must be reducible with a short quotient
         {
-            let max_with_max_unreduced_limbs = &[big_to_fe(max_unreduced_limb); NUMBER_OF_LIMBS];
-            let max_with_max_unreduced =
-                Integer::from_limbs(max_with_max_unreduced_limbs, Rc::new(rns.clone()));
-            let reduction_result = max_with_max_unreduced.reduce();
-            let quotient = match reduction_result.quotient {
-                Quotient::Short(quotient) => quotient,
-                _ => panic!("short quotient is expected"),
-            };
-            let quotient = fe_to_big(quotient);
-            assert!(quotient < max_reduced_limb);
+            let max_with_max_unreduced = compose(
+                max_remainder_limbs
+                    .iter()
+                    .map(|max| {
+                        if max.is_zero() {
+                            big_uint::zero()
+                        } else {
+                            max_unreduced_limb.clone()
+                        }
+                    })
+                    .collect(),
+                BIT_LEN_LIMB,
+            );
+            let quotient = max_with_max_unreduced / wrong_modulus;
+            ensure!(quotient < max_reduced_limb, RnsError::Unreducible);
         }
 
-        rns
+        Ok(rns)
     }
 
     /// Right shifters by limb size
@@ -609,25 +803,186 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.left_shifters[i]
     }
 
+    /// Groups limbs of `number_of_integers` in-field integers to be packed into
+    /// native field elements. Each group lists indexes of limbs over the
+    /// concatenated limbs of integers together with the bit offset of the limb
+    /// in the packed element. Packed values are kept below `2^CAPACITY` of the
+    /// native field so that packing is injective.
+    pub fn packing(&self, number_of_integers: usize) -> Vec<Vec<(usize, usize)>> {
+        let capacity = N::CAPACITY as usize;
+        assert!(BIT_LEN_LIMB <= capacity);
+
+        let mut groups: Vec<Vec<(usize, usize)>> = vec![];
+        let mut shift = 0;
+        for i in 0..number_of_integers * NUMBER_OF_LIMBS {
+            let bit_len = self.max_remainder_limbs[i % NUMBER_OF_LIMBS].bits() as usize;
+            if groups.is_empty() || shift + bit_len > capacity {
+                groups.push(vec![]);
+                shift = 0;
+            }
+            groups.last_mut().unwrap().push((i, shift));
+            shift += bit_len;
+        }
+        groups
+    }
+
     /// Computes the overflow that each component of the [`Rns`] must support.
     // TODO: consider soundness of only single overflow length
     pub fn overflow_lengths(&self) -> Vec<usize> {
-        let max_most_significant_mul_quotient_limb_size =
-            self.max_most_significant_mul_quotient_limb.bits() as usize % self.bit_len_lookup;
-        let max_most_significant_operand_limb_size =
-            self.max_most_significant_operand_limb.bits() as usize % self.bit_len_lookup;
-        let max_most_significant_reduced_limb_size =
-            self.max_most_significant_reduced_limb.bits() as usize % self.bit_len_lookup;
+        let limb_sizes = self
+            .max_mul_quotient_limbs
+            .iter()
+            .chain(self.max_operand_limbs.iter())
+            .chain(self.max_remainder_limbs.iter())
+            .map(|max| max.bits() as usize % self.bit_len_lookup);
         vec![
             self.mul_v_bit_len % self.bit_len_lookup,
             self.red_v_bit_len % self.bit_len_lookup,
-            max_most_significant_mul_quotient_limb_size,
-            max_most_significant_operand_limb_size,
-            max_most_significant_reduced_limb_size,
+            self.mul_add_v_bit_len % self.bit_len_lookup,
         ]
+        .into_iter()
+        .chain(limb_sizes)
+        // Values that fit in full sublimbs don't need an overflow table
+        .filter(|bit_len| *bit_len != 0)
+        .collect()
+    }
+
+    /// Estimated number of main gate rows to range check a value of `bit_len`
+    /// bits. Sublimbs are composed four per row.
+    fn range_row_cost(&self, bit_len: usize) -> usize {
+        let number_of_sublimbs = (bit_len + self.bit_len_lookup - 1) / self.bit_len_lookup;
+        std::cmp::max((number_of_sublimbs + 3) / 4, 1)
+    }
+
+    /// Estimated number of main gate rows to assign an integer in the range
+    /// given with maximum values of its limbs
+    fn assign_row_cost(&self, max_limbs: &[big_uint; NUMBER_OF_LIMBS]) -> usize {
+        let limbs: usize = max_limbs
+            .iter()
+            .map(|max| self.range_row_cost(max.bits() as usize))
+            .sum();
+        // and the native value is composed four terms per row
+        limbs + (NUMBER_OF_LIMBS + 3) / 4
+    }
+
+    /// Estimated number of main gate rows of the binary part of the crt check
+    fn crt_row_cost(&self, v_bit_len: usize) -> usize {
+        let number_of_residues = (NUMBER_OF_LIMBS + self.residue_limbs - 1) / self.residue_limbs;
+        // Residue check is a sum of `t` and `r` limbs, the residue and the
+        // carry where the first row takes five terms and the rest four
+        let number_of_terms = 2 * self.residue_limbs + 2;
+        let crt_rows = 1 + (number_of_terms.saturating_sub(5) + 3) / 4;
+        number_of_residues * (self.range_row_cost(v_bit_len) + crt_rows)
+    }
+
+    /// Number of nonzero limbs of the negative wrong modulus that contribute
+    /// to the intermediate value `t_i`
+    fn quotient_terms(&self, i: usize) -> usize {
+        (0..=i)
+            .filter(|j| !self.negative_wrong_modulus_decomposed[*j].is_zero_vartime())
+            .count()
+    }
+
+    /// Estimated number of main gate rows of the intermediate values of a
+    /// multiplication. In the pseudo-Mersenne path products without a
+    /// quotient term are paired in rows with both multiplication gates open,
+    /// see [`Rns::pseudo_mersenne_offset`].
+    fn mul_intermediates_row_cost(&self, pseudo_mersenne: bool) -> usize {
+        if !pseudo_mersenne {
+            return NUMBER_OF_LIMBS * (NUMBER_OF_LIMBS + 1) / 2;
+        }
+        (0..NUMBER_OF_LIMBS)
+            .map(|i| {
+                let products = i + 1;
+                let quotient_terms = self.quotient_terms(i);
+                // A single multiplication row takes two quotient terms
+                let single = std::cmp::min(products, (quotient_terms + 1) / 2);
+                let linear = (quotient_terms.saturating_sub(2 * single) + 3) / 4;
+                let double = (products - single + 1) / 2;
+                single + linear + double
+            })
+            .sum()
+    }
+
+    /// Estimated number of main gate rows of a multiplication whose operands
+    /// don't need to be reduced first. `pseudo_mersenne` selects the path as
+    /// in [`crate::IntegerChip::with_pseudo_mersenne`]. Rows of the lookup
+    /// tables are not included.
+    pub fn mul_row_cost(&self, pseudo_mersenne: bool) -> usize {
+        let result = self.assign_row_cost(&self.max_remainder_limbs);
+        let quotient = self.assign_row_cost(&self.max_mul_quotient_limbs);
+        let intermediates = self.mul_intermediates_row_cost(pseudo_mersenne);
+        let native = 1;
+        result + quotient + intermediates + self.crt_row_cost(self.mul_v_bit_len) + native
+    }
+
+    /// Estimated number of main gate rows of a reduction. `pseudo_mersenne`
+    /// selects the path as in [`crate::IntegerChip::with_pseudo_mersenne`].
+    /// Rows of the lookup tables are not included.
+    pub fn reduce_row_cost(&self, pseudo_mersenne: bool) -> usize {
+        let result = self.assign_row_cost(&self.max_remainder_limbs);
+        let quotient = self.range_row_cost(BIT_LEN_LIMB);
+        // In the pseudo-Mersenne path intermediate values are the limbs
+        // themselves where the negative wrong modulus limb is zero
+        let intermediates = if pseudo_mersenne {
+            self.quotient_terms(NUMBER_OF_LIMBS - 1)
+        } else {
+            NUMBER_OF_LIMBS
+        };
+        let native = 1;
+        result + quotient + intermediates + self.crt_row_cost(self.red_v_bit_len) + native
     }
 }
 
+/// Valid [`Rns`] parameters found by [`search`] together with estimated row
+/// costs of its main operations. Costs are of the path that
+/// [`crate::IntegerChip`] takes by default, which is the pseudo-Mersenne path
+/// if [`Rns::pseudo_mersenne_offset`] is found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RnsConfiguration {
+    /// Number of limbs, `NUMBER_OF_LIMBS`
+    pub number_of_limbs: usize,
+    /// Bit length of limbs, `BIT_LEN_LIMB`
+    pub bit_len_limb: usize,
+    /// Estimated rows of a multiplication, see [`Rns::mul_row_cost`]
+    pub mul_rows: usize,
+    /// Estimated rows of a reduction, see [`Rns::reduce_row_cost`]
+    pub reduce_rows: usize,
+}
+
+/// Enumerates valid [`Rns`] parameters to emulate `W` over `N` with three to
+/// eight limbs of 48 to 120 bits. Configurations are sorted by estimated cost
+/// of multiplication.
+pub fn search<W: PrimeField, N: PrimeField>() -> Vec<RnsConfiguration> {
+    macro_rules! try_configurations {
+        (@limbs $configurations:ident, $number_of_limbs:literal, [$($bit_len_limb:literal),*]) => {
+            $(
+                if let Ok(rns) = Rns::<W, N, $number_of_limbs, $bit_len_limb>::try_construct() {
+                    let pseudo_mersenne = rns.pseudo_mersenne_offset.is_some();
+                    $configurations.push(RnsConfiguration {
+                        number_of_limbs: $number_of_limbs,
+                        bit_len_limb: $bit_len_limb,
+                        mul_rows: rns.mul_row_cost(pseudo_mersenne),
+                        reduce_rows: rns.reduce_row_cost(pseudo_mersenne),
+                    });
+                }
+            )*
+        };
+        ($configurations:ident, [$($number_of_limbs:literal),*], $bit_lens:tt) => {
+            $(try_configurations!(@limbs $configurations, $number_of_limbs, $bit_lens);)*
+        };
+    }
+
+    let mut configurations = vec![];
+    try_configurations!(
+        configurations,
+        [3, 4, 5, 6, 7, 8],
+        [48, 52, 56, 60, 64, 68, 72, 76, 80, 84, 88, 92, 96, 100, 104, 108, 112, 116, 120]
+    );
+    configurations.sort_by_key(|c| (c.mul_rows, c.reduce_rows));
+    configurations
+}
+
 /// Limb of an [`Integer`].
 #[derive(Debug, Clone)]
 pub struct Limb<F: PrimeField>(F);
@@ -668,8 +1023,8 @@ impl<F: PrimeField> Limb<F> {
 
 /// Representation of an integer.
 ///
-/// The integer is represented as a vector of [`Limb`]s with values in the
-/// native field plus a reference to the [`Rns`] used.
+/// The integer is represented as a fixed size array of [`Limb`]s with values
+/// in the native field plus a reference to the [`Rns`] used.
 #[derive(Clone)]
 pub struct Integer<
     W: PrimeField,
@@ -677,7 +1032,7 @@ pub struct Integer<
     const NUMBER_OF_LIMBS: usize,
     const BIT_LEN_LIMB: usize,
 > {
-    limbs: Vec<Limb<N>>,
+    limbs: [Limb<N>; NUMBER_OF_LIMBS],
     rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
 }
 
@@ -701,8 +1056,16 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     Common<N> for Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
     fn value(&self) -> big_uint {
-        let limb_values = self.limbs.iter().map(|limb| limb.value()).collect();
-        compose(limb_values, BIT_LEN_LIMB)
+        let limbs: [N; NUMBER_OF_LIMBS] = std::array::from_fn(|i| self.limbs[i].fe());
+        compose_fe(&limbs, BIT_LEN_LIMB)
+    }
+
+    fn native(&self) -> N {
+        // Limbs are composed in the native field without reducing the value
+        self.limbs
+            .iter()
+            .zip(self.rns.left_shifters.iter())
+            .fold(N::ZERO, |acc, (limb, shifter)| acc + limb.0 * shifter)
     }
 }
 
@@ -713,7 +1076,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     /// [`Rns`].
     pub fn new(limbs: Vec<Limb<N>>, rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>) -> Self {
         assert!(limbs.len() == NUMBER_OF_LIMBS);
-        Self { limbs, rns }
+        Self {
+            limbs: limbs.try_into().unwrap(),
+            rns,
+        }
     }
 
     /// Creates a new [`Integer`] from a wrong field element and reference to
@@ -726,7 +1092,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     /// used [`Rns`].
     pub fn from_big(e: big_uint, rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>) -> Self {
         let limbs = decompose_big::<N>(e, NUMBER_OF_LIMBS, BIT_LEN_LIMB);
-        let limbs = limbs.iter().map(|e| Limb::<N>::new(*e)).collect();
+        let limbs = std::array::from_fn(|i| Limb::<N>::new(limbs[i]));
         Self { limbs, rns }
     }
 
@@ -736,7 +1102,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         limbs: &[N; NUMBER_OF_LIMBS],
         rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
     ) -> Self {
-        let limbs = limbs.iter().map(|limb| Limb::<N>::new(*limb)).collect();
+        let limbs = std::array::from_fn(|i| Limb::<N>::new(limbs[i]));
         Integer { limbs, rns }
     }
 
@@ -753,6 +1119,35 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.limbs.iter().map(|limb| limb.fe()).collect()
     }
 
+    /// Packs limbs of the in-field representative of the integer into as few
+    /// native field elements as possible. This is the off-circuit encoder
+    /// matching `IntegerChip::expose_public_packed`.
+    pub fn packed(&self) -> Vec<N> {
+        Self::pack(&[self])
+    }
+
+    /// Packs limbs of in-field representatives of given integers together
+    /// following [`Rns::packing`]
+    pub fn pack(integers: &[&Self]) -> Vec<N> {
+        assert!(!integers.is_empty());
+        let rns = &integers[0].rns;
+        let limbs: Vec<N> = integers
+            .iter()
+            .flat_map(|integer| {
+                let reduced = integer.value() % &rns.wrong_modulus;
+                Self::from_big(reduced, Rc::clone(rns)).limbs()
+            })
+            .collect();
+        rns.packing(integers.len())
+            .iter()
+            .map(|group| {
+                group.iter().fold(N::ZERO, |acc, (i, shift)| {
+                    acc + limbs[*i] * power_of_two::<N>(*shift)
+                })
+            })
+            .collect()
+    }
+
     /// Returns the [`Limb`] at the `idx` position.
     pub fn limb(&self, idx: usize) -> Limb<N> {
         self.limbs[idx].clone()
@@ -776,6 +1171,16 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .into()
     }
 
+    /// Returns the value as fixed width integer
+    fn wide(&self) -> Wide {
+        let limbs: [N; NUMBER_OF_LIMBS] = std::array::from_fn(|i| self.limbs[i].fe());
+        Wide::compose(&limbs, BIT_LEN_LIMB)
+    }
+
+    fn from_wide(e: &Wide, rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>) -> Self {
+        Self::from_limbs(&e.decompose(BIT_LEN_LIMB), rns)
+    }
+
     /// Computes the witness values for squaring operation
     pub(crate) fn square(&self) -> ReductionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
         self.mul(self)
@@ -786,24 +1191,23 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         &self,
         other: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> ReductionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
-        let modulus = self.rns.wrong_modulus.clone();
+        let modulus = &self.rns.wrong_modulus_wide;
         let negative_modulus = self.rns.negative_wrong_modulus_decomposed;
-        let (quotient, result) = (self.value() * other.value()).div_rem(&modulus);
-        let quotient = Self::from_big(quotient, Rc::clone(&self.rns));
-        let result = Self::from_big(result, Rc::clone(&self.rns));
+        let (quotient, result) = self.wide().mul(&other.wide()).div_rem(modulus);
+        let quotient = Self::from_wide(&quotient, Rc::clone(&self.rns));
+        let result = Self::from_wide(&result, Rc::clone(&self.rns));
 
         let l = NUMBER_OF_LIMBS;
-        let mut t: Vec<N> = vec![N::ZERO; l];
+        let mut t = [N::ZERO; NUMBER_OF_LIMBS];
         for k in 0..l {
             for i in 0..=k {
                 let j = k - i;
                 t[i + j] = t[i + j]
-                    + self.limb(i).0 * other.limb(j).0
-                    + negative_modulus[i] * quotient.limb(j).0;
+                    + self.limbs[i].0 * other.limbs[j].0
+                    + negative_modulus[i] * quotient.limbs[j].0;
             }
         }
 
-        let t = t.try_into().unwrap();
         let residues = result.residues(&t);
 
         ReductionWitness {
@@ -823,29 +1227,33 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         // self = other * result
         // self + w * quotient = other * result
 
-        let modulus = &self.rns.wrong_modulus.clone();
-        let result = &(other.invert().unwrap().value() * self.value() % &self.rns.wrong_modulus);
+        let modulus = &self.rns.wrong_modulus_wide;
+        let (_, result) = other
+            .invert()
+            .unwrap()
+            .wide()
+            .mul(&self.wide())
+            .div_rem(modulus);
 
-        let tmp = &(other.value() * result);
+        let tmp = other.wide().mul(&result);
         let negative_modulus = self.rns.negative_wrong_modulus_decomposed;
         let (quotient, reduced_self) = tmp.div_rem(modulus);
-        let (k, must_be_zero) = (self.value() - &reduced_self).div_rem(modulus);
-        assert_eq!(must_be_zero, big_uint::zero());
-        let quotient = Self::from_big(quotient - &k, Rc::clone(&self.rns));
-        let result = Self::from_big(result.clone(), Rc::clone(&self.rns));
+        let (k, must_be_zero) = self.wide().sub(&reduced_self).div_rem(modulus);
+        assert!(must_be_zero.is_zero());
+        let quotient = Self::from_wide(&quotient.sub(&k), Rc::clone(&self.rns));
+        let result = Self::from_wide(&result, Rc::clone(&self.rns));
 
         let l = NUMBER_OF_LIMBS;
-        let mut intermediate: Vec<N> = vec![N::ZERO; l];
+        let mut intermediate = [N::ZERO; NUMBER_OF_LIMBS];
         for k in 0..l {
             for i in 0..=k {
                 let j = k - i;
                 intermediate[i + j] = intermediate[i + j]
-                    + result.limb(i).0 * other.limb(j).0
-                    + negative_modulus[i] * quotient.limb(j).0;
+                    + result.limbs[i].0 * other.limbs[j].0
+                    + negative_modulus[i] * quotient.limbs[j].0;
             }
         }
 
-        let intermediate = intermediate.try_into().unwrap();
         let residues = self.residues(&intermediate);
 
         ReductionWitness {
@@ -856,25 +1264,70 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         }
     }
 
+    /// Computes the witness values for sum of products reduction where `aux`
+    /// is added to keep subtractions non negative
+    pub(crate) fn mul_add_many(
+        products: &[(Self, Self)],
+        additions: &[Self],
+        subtractions: &[Self],
+        aux: &Self,
+    ) -> ReductionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        let rns = Rc::clone(&aux.rns);
+        let modulus = &rns.wrong_modulus_wide;
+        let negative_modulus = rns.negative_wrong_modulus_decomposed;
+
+        let value = products
+            .iter()
+            .map(|(a, b)| a.wide().mul(&b.wide()))
+            .chain(additions.iter().map(|c| c.wide()))
+            .fold(aux.wide(), |acc, e| acc.add(&e));
+        let value = subtractions.iter().fold(value, |acc, d| acc.sub(&d.wide()));
+        let (quotient, result) = value.div_rem(modulus);
+        let quotient = Self::from_wide(&quotient, Rc::clone(&rns));
+        let result = Self::from_wide(&result, Rc::clone(&rns));
+
+        let l = NUMBER_OF_LIMBS;
+        let mut t: [N; NUMBER_OF_LIMBS] = std::array::from_fn(|i| aux.limbs[i].0);
+        for k in 0..l {
+            for (a, b) in products.iter() {
+                for i in 0..=k {
+                    t[k] += a.limbs[i].0 * b.limbs[k - i].0;
+                }
+            }
+            for i in 0..=k {
+                t[k] += negative_modulus[i] * quotient.limbs[k - i].0;
+            }
+            for c in additions.iter() {
+                t[k] += c.limbs[k].0;
+            }
+            for d in subtractions.iter() {
+                t[k] -= d.limbs[k].0;
+            }
+        }
+
+        let residues = result.residues(&t);
+
+        ReductionWitness {
+            result,
+            intermediate: t,
+            quotient: Quotient::Long(quotient),
+            residues,
+        }
+    }
+
     /// Computes the witness values for reduction operation
     pub(crate) fn reduce(&self) -> ReductionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
-        let modulus = self.rns.wrong_modulus.clone();
+        let modulus = &self.rns.wrong_modulus_wide;
         let negative_modulus = self.rns.negative_wrong_modulus_decomposed;
 
-        let (quotient, result) = self.value().div_rem(&modulus);
-        assert!(quotient < big_uint::one() << BIT_LEN_LIMB);
+        let (quotient, result) = self.wide().div_rem(modulus);
+        assert!(quotient.bits() <= BIT_LEN_LIMB);
 
-        let quotient: N = big_to_fe(quotient);
-        let t: [N; NUMBER_OF_LIMBS] = self
-            .limbs()
-            .iter()
-            .zip(negative_modulus.iter())
-            .map(|(a, p)| *a + *p * quotient)
-            .collect::<Vec<N>>()
-            .try_into()
-            .unwrap();
+        let [quotient]: [N; 1] = quotient.decompose(BIT_LEN_LIMB);
+        let t: [N; NUMBER_OF_LIMBS] =
+            std::array::from_fn(|i| self.limbs[i].0 + negative_modulus[i] * quotient);
 
-        let result = Integer::from_big(result, Rc::clone(&self.rns));
+        let result = Self::from_wide(&result, Rc::clone(&self.rns));
         let residues = result.residues(&t);
 
         ReductionWitness {
@@ -886,26 +1339,22 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     }
 
     fn residues(&self, t: &[N; NUMBER_OF_LIMBS]) -> Vec<N> {
-        let is_odd = NUMBER_OF_LIMBS & 1 == 1;
-        let u_len = (NUMBER_OF_LIMBS + 1) / 2;
-        let lsh1 = self.rns.left_shifter(1);
-        let (rsh1, rsh2) = (self.rns.right_shifter(1), self.rns.right_shifter(2));
+        let residue_limbs = self.rns.residue_limbs;
 
+        // `v * R^k = (t_0 - r_0) + R * (t_1 - r_1) + ... + carry` where `k` is the
+        // number of limbs in the chunk
         let mut carry = N::ZERO;
-        // TODO: use chunks
-        (0..u_len)
-            .map(|i| {
-                let j = 2 * i;
-                let v = if (i == u_len - 1) && is_odd {
-                    let r = self.limb(j).0;
-                    let u = t[j] - r;
-                    u * rsh1
-                } else {
-                    let (r_0, r_1) = (self.limb(j).0, self.limb(j + 1).0);
-                    let (t_0, t_1) = (t[j], t[j + 1]);
-                    let u = t_0 + (t_1 * lsh1) - r_0 - (lsh1 * r_1) + carry;
-                    u * rsh2
-                };
+        t.chunks(residue_limbs)
+            .zip(self.limbs.chunks(residue_limbs))
+            .map(|(t, r)| {
+                let u = t
+                    .iter()
+                    .zip(r.iter())
+                    .enumerate()
+                    .fold(carry, |acc, (i, (t, r))| {
+                        acc + (*t - r.fe()) * self.rns.left_shifter(i)
+                    });
+                let v = u * self.rns.right_shifter(t.len());
                 carry = v;
                 v
             })
@@ -970,3 +1419,115 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         Self::from_limbs(&limbs, rns)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{search, Common, Integer, Quotient, Rns, RnsError};
+    use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+    use crate::curves::secp256k1::Fp as Secp256k1Base;
+    use maingate::{big_to_fe, fe_to_big};
+    use num_bigint::{BigUint as big_uint, RandomBits};
+    use num_integer::Integer as _;
+    use rand::Rng;
+    use rand_core::OsRng;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_rns_try_construct() {
+        assert!(Rns::<BnBase, BnScalar, 4, 68>::try_construct().is_ok());
+        assert_eq!(
+            Rns::<BnBase, BnScalar, 2, 68>::try_construct().err(),
+            Some(RnsError::NumberOfLimbs)
+        );
+        assert_eq!(
+            Rns::<BnBase, BnScalar, 4, 66>::try_construct().err(),
+            Some(RnsError::LimbBitLength)
+        );
+        assert_eq!(
+            Rns::<BnBase, BnScalar, 3, 64>::try_construct().err(),
+            Some(RnsError::BinaryModulus)
+        );
+    }
+
+    #[test]
+    fn test_rns_pseudo_mersenne() {
+        let rns = Rns::<Secp256k1Base, BnScalar, 4, 68>::construct();
+        let offset = (1u64 << 32) + 977;
+        assert_eq!(rns.pseudo_mersenne_offset, Some(offset.into()));
+        assert_eq!(rns.quotient_terms(3), 2);
+        assert!(rns.mul_intermediates_row_cost(true) < 10);
+        assert_eq!(rns.mul_intermediates_row_cost(false), 10);
+        assert!(rns.mul_row_cost(true) < rns.mul_row_cost(false));
+        assert!(rns.reduce_row_cost(true) < rns.reduce_row_cost(false));
+        let rns = Rns::<BnBase, BnScalar, 4, 68>::construct();
+        assert_eq!(rns.pseudo_mersenne_offset, None);
+        assert_eq!(rns.mul_intermediates_row_cost(false), 10);
+    }
+
+    #[test]
+    fn test_rns_search() {
+        let configurations = search::<Secp256k1Base, BnScalar>();
+        assert!(!configurations.is_empty());
+        assert!(configurations
+            .iter()
+            .any(|c| c.number_of_limbs == 4 && c.bit_len_limb == 68));
+        for c in configurations.iter() {
+            assert!(c.number_of_limbs * c.bit_len_limb >= 256);
+        }
+        assert!(configurations
+            .windows(2)
+            .all(|w| w[0].mul_rows <= w[1].mul_rows));
+    }
+
+    #[test]
+    fn test_witness_arithmetic_against_big_uint() {
+        let rns = Rc::new(Rns::<BnBase, BnScalar, 4, 68>::construct());
+        let modulus = &rns.wrong_modulus;
+        // Limbs are wider than the limb bit length as in unreduced integers
+        let rand_integer = || {
+            let limbs = std::array::from_fn(|_| {
+                big_to_fe::<BnScalar>(OsRng.sample::<big_uint, _>(RandomBits::new(72)))
+            });
+            Integer::from_limbs(&limbs, Rc::clone(&rns))
+        };
+        let long = |quotient: &Quotient<_, _, 4, 68>| match quotient {
+            Quotient::Long(quotient) => quotient.value(),
+            _ => panic!("long quotient expected"),
+        };
+
+        for _ in 0..100 {
+            let (a, b, c, d) = (
+                rand_integer(),
+                rand_integer(),
+                rand_integer(),
+                rand_integer(),
+            );
+
+            let witness = a.mul(&b);
+            let (quotient, result) = (a.value() * b.value()).div_rem(modulus);
+            assert_eq!(witness.result.value(), result);
+            assert_eq!(long(&witness.quotient), quotient);
+
+            let witness = a.reduce();
+            let (quotient, result) = a.value().div_rem(modulus);
+            assert_eq!(witness.result.value(), result);
+            match witness.quotient {
+                Quotient::Short(short) => assert_eq!(fe_to_big(short), quotient),
+                _ => panic!("short quotient expected"),
+            }
+
+            let aux = Integer::from_big(modulus * 4u32, Rc::clone(&rns));
+            let witness = Integer::mul_add_many(
+                &[(a.clone(), b.clone()), (c.clone(), d.clone())],
+                &[c.clone()],
+                &[d.clone()],
+                &aux,
+            );
+            let value =
+                aux.value() + a.value() * b.value() + c.value() * d.value() + c.value() - d.value();
+            let (quotient, result) = value.div_rem(modulus);
+            assert_eq!(witness.result.value(), result);
+            assert_eq!(long(&witness.quotient), quotient);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::rns::{Common, Integer, Limb, Rns};
+use crate::PrimeField;
+use maingate::modulus;
+use num_bigint::BigUint as big_uint;
+use num_traits::Num;
+use serde::de::{DeserializeSeed, Deserializer, Error};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+/// Encodes a field element as hex string of its little endian representation
+pub fn fe_to_hex<F: PrimeField>(fe: &F) -> String {
+    fe.to_repr()
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Decodes a field element from hex string of its little endian
+/// representation. Fails if the length doesn't match the representation or
+/// if the value is not canonical.
+pub fn fe_from_hex<F: PrimeField, E: Error>(hex: &str) -> Result<F, E> {
+    let mut repr = F::Repr::default();
+    let bytes = repr.as_mut();
+    if !hex.is_ascii() || hex.len() != 2 * bytes.len() {
+        return Err(E::custom("invalid length of field element"));
+    }
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).map_err(E::custom)?;
+    }
+    Option::from(F::from_repr(repr)).ok_or_else(|| E::custom("non canonical field element"))
+}
+
+impl<F: PrimeField> Serialize for Limb<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&fe_to_hex(&self.fe()))
+    }
+}
+
+impl<'de, F: PrimeField> Deserialize<'de> for Limb<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        fe_from_hex(&hex).map(Limb::new)
+    }
+}
+
+/// Integers are serialized as the sequence of their limbs
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Serialize for Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq((0..NUMBER_OF_LIMBS).map(|i| self.limb(i)))
+    }
+}
+
+/// Deserialized limbs are bounded by `max_unreduced_limb` of the [`Rns`] that
+/// is constructed for the integer. Constructing an [`Rns`] is costly compared
+/// to decoding the limbs so [`IntegerSeed`] should be used to deserialize
+/// many integers with a shared [`Rns`].
+impl<
+        'de,
+        W: PrimeField,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > Deserialize<'de> for Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rns = Rns::try_construct().map_err(D::Error::custom)?;
+        IntegerSeed(Rc::new(rns)).deserialize(deserializer)
+    }
+}
+
+/// Deserializes an [`Integer`] that refers to the given [`Rns`]. Deserialized
+/// limbs are bounded by `max_unreduced_limb`.
+#[derive(Debug, Clone)]
+pub struct IntegerSeed<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+>(pub Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>);
+
+impl<
+        'de,
+        W: PrimeField,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > DeserializeSeed<'de> for IntegerSeed<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    type Value = Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let limbs = Vec::<Limb<N>>::deserialize(deserializer)?;
+        if limbs.len() != NUMBER_OF_LIMBS {
+            return Err(D::Error::invalid_length(
+                limbs.len(),
+                &"number of limbs of the integer",
+            ));
+        }
+        if limbs
+            .iter()
+            .any(|limb| limb.value() > self.0.max_unreduced_limb)
+        {
+            return Err(D::Error::custom("limb exceeds max_unreduced_limb"));
+        }
+        Ok(Integer::new(limbs, self.0))
+    }
+}
+
+/// [`Rns`] is fully determined by its type parameters. It is serialized as
+/// these parameters which are checked against the type when deserialized.
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    Serialize for Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Rns", 4)?;
+        state.serialize_field("wrong_modulus", &self.wrong_modulus.to_str_radix(16))?;
+        state.serialize_field("native_modulus", &self.native_modulus.to_str_radix(16))?;
+        state.serialize_field("number_of_limbs", &NUMBER_OF_LIMBS)?;
+        state.serialize_field("bit_len_limb", &BIT_LEN_LIMB)?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+struct RnsParameters {
+    wrong_modulus: String,
+    native_modulus: String,
+    number_of_limbs: usize,
+    bit_len_limb: usize,
+}
+
+impl<
+        'de,
+        W: PrimeField,
+        N: PrimeField,
+        const NUMBER_OF_LIMBS: usize,
+        const BIT_LEN_LIMB: usize,
+    > Deserialize<'de> for Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let parameters = RnsParameters::deserialize(deserializer)?;
+        let parse = |hex: &str| big_uint::from_str_radix(hex, 16).map_err(D::Error::custom);
+        if parse(&parameters.wrong_modulus)? != modulus::<W>()
+            || parse(&parameters.native_modulus)? != modulus::<N>()
+            || parameters.number_of_limbs != NUMBER_OF_LIMBS
+            || parameters.bit_len_limb != BIT_LEN_LIMB
+        {
+            return Err(D::Error::custom("rns parameters don't match"));
+        }
+        Rns::try_construct().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntegerSeed;
+    use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+    use crate::curves::pasta::Fp as PastaFp;
+    use crate::rns::{Common, Integer, Rns};
+    use num_bigint::RandBigInt;
+    use rand_core::OsRng;
+    use serde::de::DeserializeSeed;
+    use std::rc::Rc;
+
+    type BnInteger = Integer<BnBase, BnScalar, 4, 68>;
+
+    #[test]
+    fn test_integer_serde() {
+        let rns = Rc::new(Rns::<BnBase, BnScalar, 4, 68>::construct());
+
+        // Unreduced limbs survive the round trip
+        let value = OsRng.gen_biguint_below(&(&rns.max_remainder * 2usize));
+        let integer = Integer::from_big(value.clone(), Rc::clone(&rns));
+        let encoded = serde_json::to_string(&integer).unwrap();
+        let decoded: BnInteger = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded.limbs(), integer.limbs());
+        assert_eq!(decoded.value(), value);
+
+        // Shared rns
+        let mut deserializer = serde_json::Deserializer::from_str(&encoded);
+        let decoded = IntegerSeed(Rc::clone(&rns))
+            .deserialize(&mut deserializer)
+            .unwrap();
+        // Held by `rns`, `integer` and `decoded`
+        assert_eq!(Rc::strong_count(&rns), 3);
+        assert_eq!(decoded.value(), value);
+
+        // Wrong number of limbs
+        let mut limbs: Vec<String> = serde_json::from_str(&encoded).unwrap();
+        limbs.pop();
+        let encoded = serde_json::to_string(&limbs).unwrap();
+        assert!(serde_json::from_str::<BnInteger>(&encoded).is_err());
+
+        // Limb above the maximum unreduced limb
+        let mut limbs = integer.limbs();
+        limbs[0] = crate::maingate::big_to_fe(&rns.max_unreduced_limb + 1usize);
+        let integer = Integer::from_limbs(&limbs.try_into().unwrap(), Rc::clone(&rns));
+        let encoded = serde_json::to_string(&integer).unwrap();
+        assert!(serde_json::from_str::<BnInteger>(&encoded).is_err());
+
+        // Non canonical limb
+        let encoded = format!("[\"{}\",\"00\",\"00\",\"00\"]", "ff".repeat(32));
+        assert!(serde_json::from_str::<BnInteger>(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_rns_serde() {
+        let rns = Rns::<BnBase, BnScalar, 4, 68>::construct();
+        let encoded = serde_json::to_string(&rns).unwrap();
+        let decoded: Rns<BnBase, BnScalar, 4, 68> = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded.wrong_modulus, rns.wrong_modulus);
+        assert!(serde_json::from_str::<Rns<BnBase, BnScalar, 3, 88>>(&encoded).is_err());
+        assert!(serde_json::from_str::<Rns<PastaFp, BnScalar, 4, 68>>(&encoded).is_err());
+    }
+}
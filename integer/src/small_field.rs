@@ -0,0 +1,612 @@
+use crate::{sublimb_bit_len, IntegerConfig, NUMBER_OF_LOOKUP_LIMBS};
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use maingate::halo2::ff::PrimeField;
+use maingate::{
+    big_to_fe, fe_to_big, halo2, modulus, AssignedCondition, AssignedValue, MainGate,
+    MainGateInstructions, RangeChip, RangeInstructions, RegionCtx, Term,
+};
+use num_bigint::BigUint as big_uint;
+use num_integer::Integer as _;
+use num_traits::{One, Zero};
+use std::marker::PhantomData;
+
+/// Extensions of small wrong fields
+pub mod extension;
+/// Generic FRI verification gadgets over small wrong fields
+pub mod fri;
+
+/// Element of a wrong field that is smaller than the native field such as
+/// Goldilocks or BabyBear. Unlike `AssignedInteger` it is represented as a
+/// single native cell. The cell value is not necessarily reduced, `max_val`
+/// tracks how large it can grow so that reduction can be delayed across
+/// many operations.
+#[derive(Debug, Clone)]
+pub struct AssignedSmallField<W: PrimeField, N: PrimeField> {
+    value: AssignedValue<N>,
+    max_val: big_uint,
+    _marker: PhantomData<W>,
+}
+
+impl<W: PrimeField, N: PrimeField> AssignedSmallField<W, N> {
+    fn new(value: AssignedValue<N>, max_val: big_uint) -> Self {
+        AssignedSmallField {
+            value,
+            max_val,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the native cell that holds the unreduced value
+    pub fn native(&self) -> &AssignedValue<N> {
+        &self.value
+    }
+
+    /// Maximum value that the native cell can take
+    pub fn max_val(&self) -> big_uint {
+        self.max_val.clone()
+    }
+
+    /// Witness value reduced into the wrong field
+    pub fn value(&self) -> Value<W> {
+        self.value
+            .value()
+            .map(|value| big_to_fe(fe_to_big(*value) % modulus::<W>()))
+    }
+}
+
+/// Chip for arithmetic of wrong fields that fit in a native cell with enough
+/// room for products. Values are reduced with a witnessed quotient such that
+/// `a = q * p + r` where both `q` and `r` are range checked with the lookup
+/// tables of `IntegerConfig`. Range chip must be configured with
+/// `sublimb_bit_len()` composition bit length and no overflow bit length is
+/// required.
+#[derive(Debug, Clone)]
+pub struct SmallFieldChip<W: PrimeField, N: PrimeField> {
+    /// RangeChip
+    range_chip: RangeChip<N>,
+    /// MainGate
+    main_gate: MainGate<N>,
+    _marker: PhantomData<W>,
+}
+
+impl<W: PrimeField, N: PrimeField> SmallFieldChip<W, N> {
+    /// Creates a new [`SmallFieldChip`]. Panics if products of two reduced
+    /// values cannot be reduced without overflowing the native field.
+    pub fn new(config: IntegerConfig) -> Self {
+        let max_reduced = Self::max_reduced();
+        assert!(
+            Self::quotient_bit_len(&(&max_reduced * &max_reduced)).is_some(),
+            "wrong field is too large"
+        );
+        SmallFieldChip {
+            range_chip: RangeChip::new(config.range_config),
+            main_gate: MainGate::new(config.main_gate_config),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Getter for [`RangeChip`]
+    pub fn range_chip(&self) -> &RangeChip<N> {
+        &self.range_chip
+    }
+
+    /// Getter for [`MainGate`]
+    pub fn main_gate(&self) -> &MainGate<N> {
+        &self.main_gate
+    }
+
+    /// Bit length that reduced values are range checked to. It is bit length
+    /// of the wrong modulus rounded up so that it can be decomposed into
+    /// lookup limbs.
+    pub fn bit_len_reduced() -> usize {
+        let bit_len = W::NUM_BITS as usize;
+        (bit_len + NUMBER_OF_LOOKUP_LIMBS - 1) / NUMBER_OF_LOOKUP_LIMBS * NUMBER_OF_LOOKUP_LIMBS
+    }
+
+    /// Bit length of sublimbs of lookup range checks
+    pub fn sublimb_bit_len() -> usize {
+        sublimb_bit_len(Self::bit_len_reduced())
+    }
+
+    fn max_reduced() -> big_uint {
+        (big_uint::one() << Self::bit_len_reduced()) - 1usize
+    }
+
+    fn wrong_modulus() -> N {
+        big_to_fe(modulus::<W>())
+    }
+
+    // Returns bit length that the quotient of reducing a value upto `max_val`
+    // is range checked to or `None` if `q * p + r` could overflow the native
+    // field. Quotient is checked in full sublimbs.
+    fn quotient_bit_len(max_val: &big_uint) -> Option<usize> {
+        let sublimb_bit_len = Self::sublimb_bit_len();
+        let bit_len = (max_val / modulus::<W>()).bits() as usize;
+        let bit_len = (bit_len + sublimb_bit_len - 1) / sublimb_bit_len * sublimb_bit_len;
+        let max_composed =
+            ((big_uint::one() << bit_len) - 1usize) * modulus::<W>() + Self::max_reduced();
+        ((max_composed.bits() as usize) < N::CAPACITY as usize).then_some(bit_len)
+    }
+
+    // Smallest multiple of the wrong modulus that is not less than `max_val`.
+    // Added to subtrahends so that subtraction doesn't underflow.
+    fn aux(max_val: &big_uint) -> big_uint {
+        let wrong_modulus = modulus::<W>();
+        max_val.div_ceil(&wrong_modulus) * wrong_modulus
+    }
+
+    /// Assigns new witness that is range checked to `bit_len_reduced()` bits
+    pub fn assign(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: Value<W>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let value = value.map(|value| big_to_fe(fe_to_big(value)));
+        let assigned =
+            self.range_chip
+                .assign(ctx, value, Self::sublimb_bit_len(), Self::bit_len_reduced())?;
+        Ok(AssignedSmallField::new(assigned, Self::max_reduced()))
+    }
+
+    /// Assigns a constant
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        constant: W,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let constant = fe_to_big(constant);
+        let assigned = self
+            .main_gate
+            .assign_constant(ctx, big_to_fe(constant.clone()))?;
+        Ok(AssignedSmallField::new(assigned, constant))
+    }
+
+    /// Reduces the value so that it fits in `bit_len_reduced()` bits. Result
+    /// is not necessarily the canonical representative, see
+    /// [`SmallFieldChip::reduce_strict`]
+    pub fn reduce(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        if a.max_val <= Self::max_reduced() {
+            return Ok(a.clone());
+        }
+        self.reduce_with_quotient(ctx, a)
+    }
+
+    // Witnesses `a = q * p + r` regardless of whether `a` is already reduced
+    fn reduce_with_quotient(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let quotient_bit_len = Self::quotient_bit_len(&a.max_val).expect("value cannot be reduced");
+
+        let (quotient, remainder) = a
+            .value
+            .value()
+            .map(|value| {
+                let (q, r) = fe_to_big(*value).div_rem(&modulus::<W>());
+                (big_to_fe::<N>(q), big_to_fe::<N>(r))
+            })
+            .unzip();
+        let quotient =
+            self.range_chip
+                .assign(ctx, quotient, Self::sublimb_bit_len(), quotient_bit_len)?;
+        let remainder = self.range_chip.assign(
+            ctx,
+            remainder,
+            Self::sublimb_bit_len(),
+            Self::bit_len_reduced(),
+        )?;
+
+        // a - q * p - r = 0
+        self.main_gate.assert_zero_sum(
+            ctx,
+            &[
+                Term::assigned_to_add(&a.value),
+                Term::Assigned(&quotient, -Self::wrong_modulus()),
+                Term::assigned_to_sub(&remainder),
+            ],
+            N::ZERO,
+        )?;
+
+        Ok(AssignedSmallField::new(remainder, Self::max_reduced()))
+    }
+
+    /// Reduces the value into the canonical representative that is less than
+    /// the wrong modulus
+    pub fn reduce_strict(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let wrong_modulus = modulus::<W>();
+        if a.max_val < wrong_modulus {
+            return Ok(a.clone());
+        }
+        // Reduced values might still be larger than the wrong modulus so
+        // the quotient is always witnessed
+        let a = self.reduce_with_quotient(ctx, a)?;
+
+        // p - 1 - r is in range so that r < p
+        let diff = a
+            .value
+            .value()
+            .map(|value| Self::wrong_modulus() - N::ONE - value);
+        let diff =
+            self.range_chip
+                .assign(ctx, diff, Self::sublimb_bit_len(), Self::bit_len_reduced())?;
+        self.main_gate.assert_zero_sum(
+            ctx,
+            &[
+                Term::assigned_to_add(&a.value),
+                Term::assigned_to_add(&diff),
+            ],
+            N::ONE - Self::wrong_modulus(),
+        )?;
+
+        Ok(AssignedSmallField::new(a.value, wrong_modulus - 1usize))
+    }
+
+    // Reduces the operand if `max_val` that it would contribute to a result
+    // makes the result irreducible
+    fn reduce_if(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+        max_val: impl Fn(&big_uint) -> big_uint,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        if Self::quotient_bit_len(&max_val(&a.max_val)).is_some() {
+            Ok(a.clone())
+        } else {
+            self.reduce(ctx, a)
+        }
+    }
+
+    /// Adds two values without reduction unless the sum would be irreducible
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+        b: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let a = self.reduce_if(ctx, a, |a| a + &b.max_val)?;
+        let b = self.reduce_if(ctx, b, |b| b + &a.max_val)?;
+        let c = self.main_gate.add(ctx, &a.value, &b.value)?;
+        Ok(AssignedSmallField::new(c, a.max_val + b.max_val))
+    }
+
+    /// Subtracts `b` from `a`. A multiple of the wrong modulus that is larger
+    /// than `b` is added so that the result doesn't underflow.
+    pub fn sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+        b: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let b = self.reduce_if(ctx, b, |b| Self::aux(b) + &a.max_val)?;
+        let aux = Self::aux(&b.max_val);
+        let a = self.reduce_if(ctx, a, |a| a + &aux)?;
+        let c = self.main_gate.compose(
+            ctx,
+            &[
+                Term::assigned_to_add(&a.value),
+                Term::assigned_to_sub(&b.value),
+            ],
+            big_to_fe(aux.clone()),
+        )?;
+        Ok(AssignedSmallField::new(c, a.max_val + aux))
+    }
+
+    /// Negates the value
+    pub fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let aux = Self::aux(&a.max_val);
+        let c = self.main_gate.compose(
+            ctx,
+            &[Term::assigned_to_sub(&a.value)],
+            big_to_fe(aux.clone()),
+        )?;
+        Ok(AssignedSmallField::new(c, aux))
+    }
+
+    /// Adds a constant without reduction unless the sum would be irreducible
+    pub fn add_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+        constant: W,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let constant = fe_to_big(constant);
+        let a = self.reduce_if(ctx, a, |a| a + &constant)?;
+        let c = self.main_gate.compose(
+            ctx,
+            &[Term::assigned_to_add(&a.value)],
+            big_to_fe(constant.clone()),
+        )?;
+        Ok(AssignedSmallField::new(c, a.max_val + constant))
+    }
+
+    /// Multiplies by a constant without reduction unless the product would be
+    /// irreducible
+    pub fn mul_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+        constant: W,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let constant = fe_to_big(constant);
+        let a = self.reduce_if(ctx, a, |a| a * &constant)?;
+        let c = self.main_gate.compose(
+            ctx,
+            &[Term::Assigned(&a.value, big_to_fe(constant.clone()))],
+            N::ZERO,
+        )?;
+        Ok(AssignedSmallField::new(c, a.max_val * constant))
+    }
+
+    /// Multiplies two values without reduction unless the product would be
+    /// irreducible
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+        b: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let a = self.reduce_if(ctx, a, |a| a * &b.max_val)?;
+        let b = self.reduce_if(ctx, b, |b| b * &a.max_val)?;
+        let c = self.main_gate.mul(ctx, &a.value, &b.value)?;
+        Ok(AssignedSmallField::new(c, a.max_val * b.max_val))
+    }
+
+    /// Returns `a * b + c` in a single row without reduction unless the result
+    /// would be irreducible
+    pub fn mul_add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+        b: &AssignedSmallField<W, N>,
+        c: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let c = self.reduce_if(ctx, c, |c| c + Self::max_reduced() * Self::max_reduced())?;
+        let (a, b) = if Self::quotient_bit_len(&(&a.max_val * &b.max_val + &c.max_val)).is_some() {
+            (a.clone(), b.clone())
+        } else {
+            (self.reduce(ctx, a)?, self.reduce(ctx, b)?)
+        };
+        let d = self.main_gate.mul_add(ctx, &a.value, &b.value, &c.value)?;
+        Ok(AssignedSmallField::new(
+            d,
+            a.max_val * b.max_val + c.max_val,
+        ))
+    }
+
+    /// Returns the inverse of the value. Fails to satisfy constraints if the
+    /// value is zero.
+    pub fn invert(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let a_inv = self.assign(ctx, a.value().map(|a| a.invert().unwrap_or(W::ZERO)))?;
+        let one = self.assign_constant(ctx, W::ONE)?;
+        let product = self.mul(ctx, a, &a_inv)?;
+        self.assert_equal(ctx, &product, &one)?;
+        Ok(a_inv)
+    }
+
+    /// Selects `a` if the condition is set and `b` otherwise
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+        b: &AssignedSmallField<W, N>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let selected = self.main_gate.select(ctx, &a.value, &b.value, cond)?;
+        let max_val = std::cmp::max(a.max_val(), b.max_val());
+        Ok(AssignedSmallField::new(selected, max_val))
+    }
+
+    /// Constrains the value to be zero in the wrong field
+    pub fn assert_zero(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+    ) -> Result<(), Error> {
+        if a.max_val.is_zero() {
+            return Ok(());
+        }
+        let a = self.reduce_strict(ctx, a)?;
+        self.main_gate.assert_zero(ctx, &a.value)
+    }
+
+    /// Constrains two values to be equal in the wrong field
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+        b: &AssignedSmallField<W, N>,
+    ) -> Result<(), Error> {
+        let a = self.reduce_strict(ctx, a)?;
+        let b = self.reduce_strict(ctx, b)?;
+        self.main_gate.assert_equal(ctx, &a.value, &b.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SmallFieldChip;
+    use crate::curves::bn256::Fr as BnScalar;
+    use crate::{IntegerConfig, PrimeField};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use maingate::halo2::ff::Field;
+    use maingate::{
+        halo2, mock_prover_verify, MainGate, MainGateConfig, MainGateInstructions, RangeChip,
+        RangeConfig, RangeInstructions, RegionCtx,
+    };
+    use rand_core::OsRng;
+
+    pub(super) mod goldilocks {
+        use ff::PrimeField;
+
+        #[derive(PrimeField)]
+        #[PrimeFieldModulus = "18446744069414584321"]
+        #[PrimeFieldGenerator = "7"]
+        #[PrimeFieldReprEndianness = "little"]
+        pub struct Fp([u64; 2]);
+    }
+
+    mod baby_bear {
+        use ff::PrimeField;
+
+        #[derive(PrimeField)]
+        #[PrimeFieldModulus = "2013265921"]
+        #[PrimeFieldGenerator = "31"]
+        #[PrimeFieldReprEndianness = "little"]
+        pub struct Fp([u64; 1]);
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        range_config: RangeConfig,
+        main_gate_config: MainGateConfig,
+    }
+
+    #[derive(Default)]
+    struct TestCircuit<W: PrimeField> {
+        a: W,
+        b: W,
+        c: W,
+    }
+
+    impl<W: PrimeField> Circuit<BnScalar> for TestCircuit<W> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<BnScalar>) -> Self::Config {
+            let main_gate_config = MainGate::<BnScalar>::configure(meta);
+            let range_config = RangeChip::<BnScalar>::configure(
+                meta,
+                &main_gate_config,
+                vec![SmallFieldChip::<W, BnScalar>::sublimb_bit_len()],
+                vec![],
+            );
+            TestCircuitConfig {
+                range_config,
+                main_gate_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<BnScalar>,
+        ) -> Result<(), Error> {
+            let chip = SmallFieldChip::<W, BnScalar>::new(IntegerConfig::new(
+                config.range_config.clone(),
+                config.main_gate_config.clone(),
+            ));
+            let main_gate = chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let a = chip.assign(ctx, Value::known(self.a))?;
+                    let b = chip.assign(ctx, Value::known(self.b))?;
+                    let c = chip.assign(ctx, Value::known(self.c))?;
+
+                    // add, sub and neg
+                    let sum = chip.add(ctx, &a, &b)?;
+                    let expected = chip.assign_constant(ctx, self.a + self.b)?;
+                    chip.assert_equal(ctx, &sum, &expected)?;
+                    let diff = chip.sub(ctx, &a, &b)?;
+                    let expected = chip.assign_constant(ctx, self.a - self.b)?;
+                    chip.assert_equal(ctx, &diff, &expected)?;
+                    let neg = chip.neg(ctx, &a)?;
+                    let zero = chip.add(ctx, &neg, &a)?;
+                    chip.assert_zero(ctx, &zero)?;
+
+                    // operations with constants
+                    let d = chip.add_constant(ctx, &a, self.b)?;
+                    chip.assert_equal(ctx, &d, &sum)?;
+                    let d = chip.mul_constant(ctx, &a, self.b)?;
+                    let expected = chip.assign_constant(ctx, self.a * self.b)?;
+                    chip.assert_equal(ctx, &d, &expected)?;
+
+                    // mul and mul add
+                    let prod = chip.mul(ctx, &a, &b)?;
+                    let expected = chip.assign_constant(ctx, self.a * self.b)?;
+                    chip.assert_equal(ctx, &prod, &expected)?;
+                    let d = chip.mul_add(ctx, &a, &b, &c)?;
+                    let expected = chip.assign_constant(ctx, self.a * self.b + self.c)?;
+                    chip.assert_equal(ctx, &d, &expected)?;
+
+                    // delayed reduction over a long chain of operations
+                    let mut acc = c.clone();
+                    let mut expected = self.c;
+                    for _ in 0..20 {
+                        acc = chip.mul_add(ctx, &acc, &a, &b)?;
+                        acc = chip.sub(ctx, &acc, &c)?;
+                        expected = expected * self.a + self.b - self.c;
+                    }
+                    let expected = chip.assign_constant(ctx, expected)?;
+                    chip.assert_equal(ctx, &acc, &expected)?;
+                    let reduced = chip.reduce_strict(ctx, &acc)?;
+                    assert!(reduced.max_val() < maingate::modulus::<W>());
+                    reduced
+                        .value()
+                        .zip(expected.value())
+                        .assert_if_known(|(a, b)| a == b);
+
+                    // invert
+                    let a_inv = chip.invert(ctx, &a)?;
+                    let one = chip.mul(ctx, &a, &a_inv)?;
+                    let expected = chip.assign_constant(ctx, W::ONE)?;
+                    chip.assert_equal(ctx, &one, &expected)?;
+
+                    // select
+                    let cond = main_gate.assign_bit(ctx, Value::known(BnScalar::ONE))?;
+                    let selected = chip.select(ctx, &a, &prod, &cond)?;
+                    chip.assert_equal(ctx, &selected, &a)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<BnScalar>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run<W: PrimeField>() {
+        let circuit = TestCircuit::<W> {
+            a: W::random(OsRng),
+            b: W::random(OsRng),
+            c: W::random(OsRng),
+        };
+        let instance = vec![vec![]];
+        mock_prover_verify(&circuit, instance);
+    }
+
+    #[test]
+    fn test_small_field_circuit() {
+        run::<goldilocks::Fp>();
+        run::<baby_bear::Fp>();
+    }
+}
@@ -0,0 +1,456 @@
+use super::{AssignedSmallField, SmallFieldChip};
+use crate::PrimeField;
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use maingate::{halo2, modulus, AssignedCondition, RegionCtx};
+use num_bigint::BigUint as big_uint;
+use num_traits::Zero;
+
+// Off-circuit arithmetic over coefficient arrays that is used to find
+// witnesses and constants of the extension
+
+pub(crate) fn ext_mul<W: PrimeField, const D: usize>(
+    a: &[W; D],
+    b: &[W; D],
+    non_residue: W,
+) -> [W; D] {
+    let mut c = [W::ZERO; D];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            if i + j < D {
+                c[i + j] += *a * b;
+            } else {
+                c[i + j - D] += non_residue * a * b;
+            }
+        }
+    }
+    c
+}
+
+pub(crate) fn ext_pow<W: PrimeField, const D: usize>(
+    a: &[W; D],
+    exponent: &big_uint,
+    non_residue: W,
+) -> [W; D] {
+    let mut one = [W::ZERO; D];
+    one[0] = W::ONE;
+    (0..exponent.bits()).rev().fold(one, |acc, i| {
+        let acc = ext_mul(&acc, &acc, non_residue);
+        if exponent.bit(i) {
+            ext_mul(&acc, a, non_residue)
+        } else {
+            acc
+        }
+    })
+}
+
+pub(crate) fn ext_invert<W: PrimeField, const D: usize>(
+    a: &[W; D],
+    non_residue: W,
+) -> Option<[W; D]> {
+    if a.iter().all(|c| bool::from(c.is_zero())) {
+        return None;
+    }
+    // Multiplicative group of the extension has order `p^D - 1`
+    let exponent = modulus::<W>().pow(D as u32) - 2usize;
+    Some(ext_pow(a, &exponent, non_residue))
+}
+
+/// Element of the extension `c_0 + c_1 * x + ... + c_{D-1} * x^(D-1)` where
+/// `x^D` is the non residue of the [`SmallExtensionChip`]
+#[derive(Debug, Clone)]
+pub struct AssignedSmallExtension<W: PrimeField, N: PrimeField, const D: usize> {
+    coeffs: [AssignedSmallField<W, N>; D],
+}
+
+impl<W: PrimeField, N: PrimeField, const D: usize> AssignedSmallExtension<W, N, D> {
+    /// Creates a new [`AssignedSmallExtension`] from its coefficients
+    pub fn new(coeffs: [AssignedSmallField<W, N>; D]) -> Self {
+        AssignedSmallExtension { coeffs }
+    }
+
+    /// Returns coefficients in ascending degree
+    pub fn coeffs(&self) -> &[AssignedSmallField<W, N>; D] {
+        &self.coeffs
+    }
+
+    /// Witness value as coefficients reduced into the wrong field
+    pub fn value(&self) -> Value<[W; D]> {
+        let coeffs: Value<Vec<W>> = self.coeffs.iter().map(|c| c.value()).collect();
+        coeffs.map(|coeffs| coeffs.try_into().unwrap())
+    }
+}
+
+/// Chip for arithmetic over the extension `W[x] / (x^D - non_residue)` of a
+/// small wrong field such as quadratic and quintic extensions of Goldilocks.
+/// Coefficient arithmetic is delegated to [`SmallFieldChip`] so that sums of
+/// products are reduced only when they would overflow the native field.
+#[derive(Debug, Clone)]
+pub struct SmallExtensionChip<W: PrimeField, N: PrimeField, const D: usize> {
+    small_field_chip: SmallFieldChip<W, N>,
+    non_residue: W,
+}
+
+impl<W: PrimeField, N: PrimeField, const D: usize> SmallExtensionChip<W, N, D> {
+    /// Creates a new [`SmallExtensionChip`]. `D` is expected to divide
+    /// `p - 1` and not to be a multiple of four, then `x^D - non_residue` is
+    /// irreducible if `non_residue` is not an `r`-th power for any prime `r`
+    /// that divides `D`.
+    pub fn new(small_field_chip: SmallFieldChip<W, N>, non_residue: W) -> Self {
+        assert!(D > 1);
+        let p_minus_one = modulus::<W>() - 1usize;
+        assert!(
+            (&p_minus_one % D).is_zero() && D % 4 != 0,
+            "unsupported extension degree"
+        );
+        for r in (2..=D).filter(|r| D % r == 0 && (2..*r).all(|d| r % d != 0)) {
+            let exponent = &p_minus_one / r;
+            assert!(
+                non_residue.pow_vartime(exponent.to_u64_digits()) != W::ONE,
+                "extension requires a non residue"
+            );
+        }
+        SmallExtensionChip {
+            small_field_chip,
+            non_residue,
+        }
+    }
+
+    /// Getter for [`SmallFieldChip`]
+    pub fn small_field_chip(&self) -> &SmallFieldChip<W, N> {
+        &self.small_field_chip
+    }
+
+    /// Returns the non residue that defines the extension
+    pub fn non_residue(&self) -> W {
+        self.non_residue
+    }
+
+    fn map<F>(&self, mut f: F) -> Result<AssignedSmallExtension<W, N, D>, Error>
+    where
+        F: FnMut(usize) -> Result<AssignedSmallField<W, N>, Error>,
+    {
+        let coeffs = (0..D).map(&mut f).collect::<Result<Vec<_>, Error>>()?;
+        Ok(AssignedSmallExtension::new(coeffs.try_into().unwrap()))
+    }
+
+    /// Assigns a new witness element where coefficients are range checked
+    pub fn assign(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: Value<[W; D]>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.map(|i| {
+            self.small_field_chip
+                .assign(ctx, value.map(|value| value[i]))
+        })
+    }
+
+    /// Assigns a constant element
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        constant: [W; D],
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.map(|i| self.small_field_chip.assign_constant(ctx, constant[i]))
+    }
+
+    /// Embeds an element of the base field
+    pub fn from_base(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.map(|i| match i {
+            0 => Ok(a.clone()),
+            _ => self.small_field_chip.assign_constant(ctx, W::ZERO),
+        })
+    }
+
+    /// Adds two elements
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+        b: &AssignedSmallExtension<W, N, D>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.map(|i| self.small_field_chip.add(ctx, &a.coeffs[i], &b.coeffs[i]))
+    }
+
+    /// Subtracts `b` from `a`
+    pub fn sub(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+        b: &AssignedSmallExtension<W, N, D>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.map(|i| self.small_field_chip.sub(ctx, &a.coeffs[i], &b.coeffs[i]))
+    }
+
+    /// Negates the element
+    pub fn neg(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.map(|i| self.small_field_chip.neg(ctx, &a.coeffs[i]))
+    }
+
+    /// Multiplies each coefficient by an element of the base field
+    pub fn mul_by_base(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+        b: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.map(|i| self.small_field_chip.mul(ctx, &a.coeffs[i], b))
+    }
+
+    /// Multiplies each coefficient by a constant of the base field
+    pub fn mul_by_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+        constant: W,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.map(|i| {
+            self.small_field_chip
+                .mul_constant(ctx, &a.coeffs[i], constant)
+        })
+    }
+
+    /// Multiplies two elements. Each coefficient of the result is a sum of
+    /// coefficient products where products that wrap around `x^D` are
+    /// multiplied by the non residue.
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+        b: &AssignedSmallExtension<W, N, D>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        let chip = &self.small_field_chip;
+        self.map(|k| {
+            let mut acc: Option<AssignedSmallField<W, N>> = None;
+            let mul_add =
+                |ctx: &mut RegionCtx<'_, N>,
+                 i: usize,
+                 j: usize,
+                 acc: Option<AssignedSmallField<W, N>>| match acc {
+                    None => chip.mul(ctx, &a.coeffs[i], &b.coeffs[j]),
+                    Some(acc) => chip.mul_add(ctx, &a.coeffs[i], &b.coeffs[j], &acc),
+                };
+            // c_k = non_residue * sum a_i * b_j where i + j = k + D
+            for i in k + 1..D {
+                acc = Some(mul_add(ctx, i, k + D - i, acc)?);
+            }
+            if let Some(wrapped) = acc {
+                acc = Some(chip.mul_constant(ctx, &wrapped, self.non_residue)?);
+            }
+            // + sum a_i * b_j where i + j = k
+            for i in 0..=k {
+                acc = Some(mul_add(ctx, i, k - i, acc)?);
+            }
+            Ok(acc.unwrap())
+        })
+    }
+
+    /// Squares the element
+    pub fn square(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.mul(ctx, a, a)
+    }
+
+    /// Returns the inverse of the element. Fails to satisfy constraints if
+    /// the element is zero.
+    pub fn invert(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        let a_inv = a
+            .value()
+            .map(|a| ext_invert(&a, self.non_residue).unwrap_or([W::ZERO; D]));
+        let a_inv = self.assign(ctx, a_inv)?;
+        let product = self.mul(ctx, a, &a_inv)?;
+        let mut one = [W::ZERO; D];
+        one[0] = W::ONE;
+        let one = self.assign_constant(ctx, one)?;
+        self.assert_equal(ctx, &product, &one)?;
+        Ok(a_inv)
+    }
+
+    /// Selects `a` if the condition is set and `b` otherwise
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+        b: &AssignedSmallExtension<W, N, D>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        self.map(|i| {
+            self.small_field_chip
+                .select(ctx, &a.coeffs[i], &b.coeffs[i], cond)
+        })
+    }
+
+    /// Constrains two elements to be equal
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+        b: &AssignedSmallExtension<W, N, D>,
+    ) -> Result<(), Error> {
+        for (a, b) in a.coeffs.iter().zip(b.coeffs.iter()) {
+            self.small_field_chip.assert_equal(ctx, a, b)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ext_invert, ext_mul, SmallExtensionChip};
+    use crate::curves::bn256::Fr as BnScalar;
+    use crate::small_field::tests::goldilocks::Fp as Goldilocks;
+    use crate::small_field::SmallFieldChip;
+    use crate::{IntegerConfig, PrimeField};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use maingate::halo2::ff::Field;
+    use maingate::{
+        halo2, mock_prover_verify, MainGate, MainGateConfig, RangeChip, RangeConfig,
+        RangeInstructions, RegionCtx,
+    };
+    use rand_core::OsRng;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        range_config: RangeConfig,
+        main_gate_config: MainGateConfig,
+    }
+
+    struct TestCircuit<W: PrimeField, const D: usize> {
+        non_residue: W,
+        a: [W; D],
+        b: [W; D],
+    }
+
+    impl<W: PrimeField, const D: usize> Circuit<BnScalar> for TestCircuit<W, D> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<BnScalar>) -> Self::Config {
+            let main_gate_config = MainGate::<BnScalar>::configure(meta);
+            let range_config = RangeChip::<BnScalar>::configure(
+                meta,
+                &main_gate_config,
+                vec![SmallFieldChip::<W, BnScalar>::sublimb_bit_len()],
+                vec![],
+            );
+            TestCircuitConfig {
+                range_config,
+                main_gate_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<BnScalar>,
+        ) -> Result<(), Error> {
+            let small_field_chip = SmallFieldChip::<W, BnScalar>::new(IntegerConfig::new(
+                config.range_config.clone(),
+                config.main_gate_config.clone(),
+            ));
+            let chip = SmallExtensionChip::<W, BnScalar, D>::new(
+                small_field_chip.clone(),
+                self.non_residue,
+            );
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let a = chip.assign(ctx, Value::known(self.a))?;
+                    let b = chip.assign(ctx, Value::known(self.b))?;
+
+                    // add and sub
+                    let c = chip.add(ctx, &a, &b)?;
+                    let c = chip.sub(ctx, &c, &b)?;
+                    chip.assert_equal(ctx, &c, &a)?;
+                    let c = chip.neg(ctx, &a)?;
+                    let c = chip.add(ctx, &c, &a)?;
+                    let zero = chip.assign_constant(ctx, [W::ZERO; D])?;
+                    chip.assert_equal(ctx, &c, &zero)?;
+
+                    // mul and square
+                    let expected = ext_mul(&self.a, &self.b, self.non_residue);
+                    let expected = chip.assign_constant(ctx, expected)?;
+                    let c = chip.mul(ctx, &a, &b)?;
+                    chip.assert_equal(ctx, &c, &expected)?;
+                    let expected = ext_mul(&self.a, &self.a, self.non_residue);
+                    let expected = chip.assign_constant(ctx, expected)?;
+                    let c = chip.square(ctx, &a)?;
+                    chip.assert_equal(ctx, &c, &expected)?;
+
+                    // mul by base
+                    let e = small_field_chip.assign(ctx, Value::known(self.b[0]))?;
+                    let e = chip.from_base(ctx, &e)?;
+                    let expected = chip.mul(ctx, &a, &e)?;
+                    let c = chip.mul_by_base(ctx, &a, &e.coeffs()[0])?;
+                    chip.assert_equal(ctx, &c, &expected)?;
+                    let c = chip.mul_by_constant(ctx, &a, self.b[0])?;
+                    chip.assert_equal(ctx, &c, &expected)?;
+
+                    // invert
+                    let a_inv = chip.invert(ctx, &a)?;
+                    let expected = ext_invert(&self.a, self.non_residue).unwrap();
+                    let expected = chip.assign_constant(ctx, expected)?;
+                    chip.assert_equal(ctx, &a_inv, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<BnScalar>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run<const D: usize>(non_residue: Goldilocks) {
+        let circuit = TestCircuit::<Goldilocks, D> {
+            non_residue,
+            a: [(); D].map(|_| Goldilocks::random(OsRng)),
+            b: [(); D].map(|_| Goldilocks::random(OsRng)),
+        };
+        let instance = vec![vec![]];
+        mock_prover_verify(&circuit, instance);
+    }
+
+    #[test]
+    fn test_small_extension_circuit() {
+        run::<2>(Goldilocks::from(7));
+        run::<5>(Goldilocks::from(3));
+    }
+
+    #[test]
+    fn test_ext_invert() {
+        let non_residue = Goldilocks::from(3);
+        let a: [Goldilocks; 5] = [(); 5].map(|_| Goldilocks::random(OsRng));
+        let a_inv = ext_invert(&a, non_residue).unwrap();
+        let mut one = [Goldilocks::ZERO; 5];
+        one[0] = Goldilocks::ONE;
+        assert_eq!(ext_mul(&a, &a_inv, non_residue), one);
+        assert!(ext_invert(&[Goldilocks::ZERO; 5], non_residue).is_none());
+    }
+}
@@ -0,0 +1,844 @@
+use super::extension::{AssignedSmallExtension, SmallExtensionChip};
+use super::{AssignedSmallField, SmallFieldChip};
+use crate::PrimeField;
+use halo2::plonk::Error;
+use maingate::{
+    big_to_fe, fe_to_big, halo2, AssignedCondition, MainGateInstructions, RegionCtx, Term,
+};
+use num_bigint::BigUint as big_uint;
+use num_traits::One;
+
+/// Digest of the hash function that Merkle trees are built with
+pub type AssignedDigest<W, N> = Vec<AssignedSmallField<W, N>>;
+
+/// Hash function that Merkle trees of FRI commitments are built with. This
+/// crate doesn't provide an implementation, callers bring their own such as
+/// Poseidon over Goldilocks.
+pub trait FriHasherInstructions<W: PrimeField, N: PrimeField> {
+    /// Number of elements in a digest
+    fn digest_len(&self) -> usize;
+
+    /// Hashes elements of a Merkle tree leaf into a digest
+    fn hash(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        inputs: &[AssignedSmallField<W, N>],
+    ) -> Result<AssignedDigest<W, N>, Error>;
+
+    /// Compresses two child digests into the digest of their parent
+    fn two_to_one(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        left: &AssignedDigest<W, N>,
+        right: &AssignedDigest<W, N>,
+    ) -> Result<AssignedDigest<W, N>, Error>;
+}
+
+/// Order in which evaluations of a round are committed. Round with arity
+/// `2^a` is over the domain `g * <w>` of `n` points where `g` is the
+/// multiplicative generator of the wrong field to the power of the arities of
+/// earlier rounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FriLayout {
+    /// Index `j` stands for the point `g * w^j`. Leaf `j` of the Merkle tree
+    /// holds evaluations at the coset `g * w^j * <w^(n / 2^a)>` in ascending
+    /// order and folding maps a queried index `j` to `j mod (n / 2^a)`.
+    Natural,
+    /// Layout of Plonky2. Index `j` stands for the point
+    /// `g * w^reverse_bits(j)`. Leaf `j` holds evaluations at indices
+    /// `j * 2^a, ..., (j + 1) * 2^a - 1` and folding maps a queried index `j`
+    /// to `j >> a`. Leaves that are not longer than a digest are padded with
+    /// zeros instead of being hashed.
+    Plonky2,
+}
+
+/// Parameters of a FRI proof. Domain of the first round is the coset
+/// `g * <w>` of `2^lde_bits` points where `g` is the multiplicative generator
+/// of the wrong field. Folding with arity `2^a` maps the domain to
+/// `g^(2^a) * <w^(2^a)>`.
+#[derive(Debug, Clone)]
+pub struct FriConfig {
+    /// Bit length of the size of the first domain
+    pub lde_bits: usize,
+    /// Bit length of the arity of each folding round
+    pub reduction_arity_bits: Vec<usize>,
+    /// Height of the Merkle caps that are committed instead of roots
+    pub cap_height: usize,
+    /// Order of committed evaluations and layout of Merkle leaves
+    pub layout: FriLayout,
+}
+
+/// Opening of a query in one round
+#[derive(Debug, Clone)]
+pub struct AssignedFriQueryStep<W: PrimeField, N: PrimeField, const D: usize> {
+    /// Evaluations at the coset that contains the queried point
+    pub evals: Vec<AssignedSmallExtension<W, N, D>>,
+    /// Sibling digests from the leaf upto the cap
+    pub merkle_proof: Vec<AssignedDigest<W, N>>,
+}
+
+// Generator of the subgroup of order `2^bit_len`
+fn root_of_unity<W: PrimeField>(bit_len: usize) -> W {
+    assert!(bit_len <= W::S as usize, "domain is too large");
+    W::ROOT_OF_UNITY.pow_vartime([1u64 << (W::S as usize - bit_len)])
+}
+
+// Reverses lower `bit_len` bits of the index
+fn reverse_bits(index: usize, bit_len: usize) -> usize {
+    (0..bit_len).fold(0, |acc, i| (acc << 1) | ((index >> i) & 1))
+}
+
+/// Chip for verification of FRI query rounds over an extension of a small
+/// wrong field such as the quadratic extension of Goldilocks. Challenges and
+/// query indices are expected to be derived by the caller's transcript.
+///
+/// With [`FriLayout::Plonky2`] commitments and query openings of Plonky2
+/// proofs are taken as they are. The Merkle tree hash is left to
+/// [`FriHasherInstructions`] so that Plonky2 proofs are verified with a
+/// Poseidon over Goldilocks implementation of it.
+#[derive(Debug, Clone)]
+pub struct FriChip<W: PrimeField, N: PrimeField, H: FriHasherInstructions<W, N>, const D: usize> {
+    extension_chip: SmallExtensionChip<W, N, D>,
+    hasher: H,
+    config: FriConfig,
+}
+
+impl<W: PrimeField, N: PrimeField, H: FriHasherInstructions<W, N>, const D: usize>
+    FriChip<W, N, H, D>
+{
+    /// Creates a new [`FriChip`]
+    pub fn new(extension_chip: SmallExtensionChip<W, N, D>, hasher: H, config: FriConfig) -> Self {
+        let total_arity_bits: usize = config.reduction_arity_bits.iter().sum();
+        assert!(
+            total_arity_bits <= config.lde_bits,
+            "domain is smaller than the total folding arity"
+        );
+        FriChip {
+            extension_chip,
+            hasher,
+            config,
+        }
+    }
+
+    /// Getter for [`SmallExtensionChip`]
+    pub fn extension_chip(&self) -> &SmallExtensionChip<W, N, D> {
+        &self.extension_chip
+    }
+
+    /// Getter for the hasher
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Getter for [`FriConfig`]
+    pub fn config(&self) -> &FriConfig {
+        &self.config
+    }
+
+    fn small_field_chip(&self) -> &SmallFieldChip<W, N> {
+        self.extension_chip.small_field_chip()
+    }
+
+    fn select_digest(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedDigest<W, N>,
+        b: &AssignedDigest<W, N>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedDigest<W, N>, Error> {
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| self.small_field_chip().select(ctx, a, b, cond))
+            .collect()
+    }
+
+    // Selects `items[index]` where the index is given in little endian bits
+    fn select_by_bits<T: Clone>(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        items: &[T],
+        bits: &[AssignedCondition<N>],
+        select: impl Fn(&mut RegionCtx<'_, N>, &T, &T, &AssignedCondition<N>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        assert_eq!(items.len(), 1 << bits.len());
+        let mut items = items.to_vec();
+        for bit in bits.iter() {
+            items = items
+                .chunks(2)
+                .map(|pair| select(ctx, &pair[1], &pair[0], bit))
+                .collect::<Result<Vec<_>, Error>>()?;
+        }
+        Ok(items.remove(0))
+    }
+
+    /// Verifies that the leaf at the given index opens to the Merkle cap.
+    /// Index is given in little endian bits where low bits are path
+    /// directions and high bits select the cap node. In
+    /// [`FriLayout::Plonky2`] a leaf that is not longer than a digest is
+    /// padded with zeros instead of being hashed.
+    pub fn verify_merkle_proof_to_cap(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        leaf: &[AssignedSmallField<W, N>],
+        index_bits: &[AssignedCondition<N>],
+        cap: &[AssignedDigest<W, N>],
+        merkle_proof: &[AssignedDigest<W, N>],
+    ) -> Result<(), Error> {
+        assert!(cap.len().is_power_of_two());
+        let cap_height = cap.len().trailing_zeros() as usize;
+        assert_eq!(index_bits.len(), merkle_proof.len() + cap_height);
+
+        let digest_len = self.hasher.digest_len();
+        let mut digest = if self.config.layout == FriLayout::Plonky2 && leaf.len() <= digest_len {
+            let mut digest = leaf.to_vec();
+            for _ in leaf.len()..digest_len {
+                digest.push(self.small_field_chip().assign_constant(ctx, W::ZERO)?);
+            }
+            digest
+        } else {
+            self.hasher.hash(ctx, leaf)?
+        };
+        for (bit, sibling) in index_bits.iter().zip(merkle_proof.iter()) {
+            // Current node is the right child if the bit is set
+            let left = self.select_digest(ctx, sibling, &digest, bit)?;
+            let right = self.select_digest(ctx, &digest, sibling, bit)?;
+            digest = self.hasher.two_to_one(ctx, &left, &right)?;
+        }
+
+        let cap_bits = &index_bits[merkle_proof.len()..];
+        let cap_node = self.select_by_bits(ctx, cap, cap_bits, |ctx, a, b, cond| {
+            self.select_digest(ctx, a, b, cond)
+        })?;
+        for (a, b) in digest.iter().zip(cap_node.iter()) {
+            self.small_field_chip().assert_equal(ctx, a, b)?;
+        }
+        Ok(())
+    }
+
+    /// Returns `shift * generator^index` where the index is given in little
+    /// endian bits
+    pub fn domain_point(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        index_bits: &[AssignedCondition<N>],
+        shift: W,
+        generator: W,
+    ) -> Result<AssignedSmallField<W, N>, Error> {
+        let chip = self.small_field_chip();
+        let main_gate = chip.main_gate();
+
+        let mut acc: Option<AssignedSmallField<W, N>> = None;
+        let mut power = generator;
+        for bit in index_bits.iter() {
+            // 1 + bit * (generator^(2^i) - 1)
+            let factor = main_gate.compose(
+                ctx,
+                &[Term::Assigned(bit, big_to_fe(fe_to_big(power - W::ONE)))],
+                N::ONE,
+            )?;
+            let factor =
+                AssignedSmallField::new(factor, std::cmp::max(fe_to_big(power), big_uint::one()));
+            acc = Some(match acc {
+                None => factor,
+                Some(acc) => chip.mul(ctx, &acc, &factor)?,
+            });
+            power = power.square();
+        }
+        match acc {
+            None => chip.assign_constant(ctx, shift),
+            Some(acc) => chip.mul_constant(ctx, &acc, shift),
+        }
+    }
+
+    // Subtracts an element of the base field from an element of the extension
+    fn sub_base(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedSmallExtension<W, N, D>,
+        b: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        let mut coeffs = a.coeffs().clone();
+        coeffs[0] = self.small_field_chip().sub(ctx, &coeffs[0], b)?;
+        Ok(AssignedSmallExtension::new(coeffs))
+    }
+
+    /// Evaluates the polynomial that interpolates `evals` at the coset
+    /// `coset_start * <h>` at `beta` where `h` generates the subgroup of
+    /// order `2^arity_bits`. Barycentric formula over the coset is used
+    /// which requires `beta` not to be in the coset.
+    pub fn interpolate_coset(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        coset_start: &AssignedSmallField<W, N>,
+        arity_bits: usize,
+        evals: &[AssignedSmallExtension<W, N, D>],
+        beta: &AssignedSmallExtension<W, N, D>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        let chip = self.small_field_chip();
+        let ext = &self.extension_chip;
+        let arity = 1 << arity_bits;
+        assert_eq!(evals.len(), arity);
+
+        // p(beta) = (beta^n - s^n) / (n * s^n) * sum e_i * x_i / (beta - x_i)
+        let mut s_n = coset_start.clone();
+        let mut beta_n = beta.clone();
+        for _ in 0..arity_bits {
+            s_n = chip.mul(ctx, &s_n, &s_n)?;
+            beta_n = ext.square(ctx, &beta_n)?;
+        }
+
+        let generator = root_of_unity::<W>(arity_bits);
+        let mut power = W::ONE;
+        let mut sum: Option<AssignedSmallExtension<W, N, D>> = None;
+        for eval in evals.iter() {
+            let x = chip.mul_constant(ctx, coset_start, power)?;
+            let denominator = self.sub_base(ctx, beta, &x)?;
+            let denominator = ext.invert(ctx, &denominator)?;
+            let term = ext.mul(ctx, eval, &denominator)?;
+            let term = ext.mul_by_base(ctx, &term, &x)?;
+            sum = Some(match sum {
+                None => term,
+                Some(sum) => ext.add(ctx, &sum, &term)?,
+            });
+            power *= generator;
+        }
+
+        let vanishing = self.sub_base(ctx, &beta_n, &s_n)?;
+        let scale = chip.mul_constant(ctx, &s_n, W::from(arity as u64))?;
+        let scale = chip.invert(ctx, &scale)?;
+        let vanishing = ext.mul_by_base(ctx, &vanishing, &scale)?;
+        ext.mul(ctx, &vanishing, &sum.unwrap())
+    }
+
+    /// Evaluates a polynomial given in ascending coefficients at a point of
+    /// the base field
+    pub fn eval_polynomial(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        coeffs: &[AssignedSmallExtension<W, N, D>],
+        x: &AssignedSmallField<W, N>,
+    ) -> Result<AssignedSmallExtension<W, N, D>, Error> {
+        let ext = &self.extension_chip;
+        let (last, coeffs) = coeffs.split_last().expect("empty polynomial");
+        coeffs.iter().rev().try_fold(last.clone(), |acc, coeff| {
+            let acc = ext.mul_by_base(ctx, &acc, x)?;
+            ext.add(ctx, &acc, coeff)
+        })
+    }
+
+    /// Verifies a query of the FRI proof. `index_bits` are `lde_bits` little
+    /// endian bits of the queried index and `initial_eval` is the evaluation
+    /// of the first committed polynomial at the queried point. In each round
+    /// the opened coset is checked against the commitment and the value that
+    /// is folded with `beta` must be opened in the next round. Folded value
+    /// of the last round must agree with `final_poly`. Evaluations of a step
+    /// are expected in the order of the Merkle leaf as in [`FriLayout`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_query_round(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        index_bits: &[AssignedCondition<N>],
+        initial_eval: &AssignedSmallExtension<W, N, D>,
+        betas: &[AssignedSmallExtension<W, N, D>],
+        commit_caps: &[Vec<AssignedDigest<W, N>>],
+        final_poly: &[AssignedSmallExtension<W, N, D>],
+        steps: &[AssignedFriQueryStep<W, N, D>],
+    ) -> Result<(), Error> {
+        let config = &self.config;
+        let number_of_rounds = config.reduction_arity_bits.len();
+        assert_eq!(index_bits.len(), config.lde_bits);
+        assert_eq!(betas.len(), number_of_rounds);
+        assert_eq!(commit_caps.len(), number_of_rounds);
+        assert_eq!(steps.len(), number_of_rounds);
+
+        let ext = &self.extension_chip;
+        let mut domain_bits = config.lde_bits;
+        let mut shift = W::MULTIPLICATIVE_GENERATOR;
+        let mut eval = initial_eval.clone();
+        let mut index_bits = index_bits.to_vec();
+        for (((arity_bits, beta), cap), step) in config
+            .reduction_arity_bits
+            .iter()
+            .zip(betas.iter())
+            .zip(commit_caps.iter())
+            .zip(steps.iter())
+        {
+            let arity_bits = *arity_bits;
+            let coset_bits = domain_bits - arity_bits;
+            let (leaf_bits, position_bits) = match config.layout {
+                // Low bits point to the leaf and high bits to the position of
+                // the queried point in the coset
+                FriLayout::Natural => index_bits.split_at(coset_bits),
+                // Low bits point to the position in the leaf and high bits to
+                // the leaf
+                FriLayout::Plonky2 => {
+                    let (position_bits, leaf_bits) = index_bits.split_at(arity_bits);
+                    (leaf_bits, position_bits)
+                }
+            };
+            let (leaf_bits, position_bits) = (leaf_bits.to_vec(), position_bits.to_vec());
+
+            let opened =
+                self.select_by_bits(ctx, &step.evals, &position_bits, |ctx, a, b, cond| {
+                    ext.select(ctx, a, b, cond)
+                })?;
+            ext.assert_equal(ctx, &opened, &eval)?;
+
+            let leaf: Vec<AssignedSmallField<W, N>> = step
+                .evals
+                .iter()
+                .flat_map(|eval| eval.coeffs().iter().cloned())
+                .collect();
+            self.verify_merkle_proof_to_cap(ctx, &leaf, &leaf_bits, cap, &step.merkle_proof)?;
+
+            // Coset and its evaluations are brought to ascending order
+            let generator = root_of_unity(domain_bits);
+            let (coset_start, evals) = match config.layout {
+                FriLayout::Natural => (
+                    self.domain_point(ctx, &leaf_bits, shift, generator)?,
+                    step.evals.clone(),
+                ),
+                FriLayout::Plonky2 => {
+                    let reversed: Vec<_> = leaf_bits.iter().rev().cloned().collect();
+                    let evals = (0..1 << arity_bits)
+                        .map(|k| step.evals[reverse_bits(k, arity_bits)].clone())
+                        .collect::<Vec<_>>();
+                    (self.domain_point(ctx, &reversed, shift, generator)?, evals)
+                }
+            };
+            eval = self.interpolate_coset(ctx, &coset_start, arity_bits, &evals, beta)?;
+
+            shift = shift.pow_vartime([1u64 << arity_bits]);
+            domain_bits = coset_bits;
+            index_bits = leaf_bits;
+        }
+
+        if config.layout == FriLayout::Plonky2 {
+            index_bits.reverse();
+        }
+        let x = self.domain_point(ctx, &index_bits, shift, root_of_unity(domain_bits))?;
+        let expected = self.eval_polynomial(ctx, final_poly, &x)?;
+        ext.assert_equal(ctx, &eval, &expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        reverse_bits, root_of_unity, AssignedDigest, AssignedFriQueryStep, FriChip, FriConfig,
+        FriHasherInstructions, FriLayout,
+    };
+    use crate::curves::bn256::Fr as BnScalar;
+    use crate::small_field::extension::{ext_mul, SmallExtensionChip};
+    use crate::small_field::tests::goldilocks::Fp as Goldilocks;
+    use crate::small_field::{AssignedSmallField, SmallFieldChip};
+    use crate::{IntegerConfig, PrimeField};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use maingate::halo2::ff::Field;
+    use maingate::{
+        halo2, mock_prover_verify, MainGate, MainGateConfig, MainGateInstructions, RangeChip,
+        RangeConfig, RangeInstructions, RegionCtx,
+    };
+    use rand_core::OsRng;
+
+    const WIDTH: usize = 6;
+    // Digests are of the same length as in Plonky2
+    const RATE: usize = 4;
+    const ROUNDS: usize = 4;
+
+    type Ext = [Goldilocks; 2];
+    const NON_RESIDUE: u64 = 7;
+
+    // Toy sponge that is only meant to test Merkle proofs. Each round adds
+    // round constants, applies `x^7` and mixes lanes with their sum.
+    fn round_constant(round: usize, lane: usize) -> Goldilocks {
+        Goldilocks::from((round * WIDTH + lane + 1) as u64)
+    }
+
+    fn native_hash(inputs: &[Goldilocks]) -> Vec<Goldilocks> {
+        let mut state = [Goldilocks::ZERO; WIDTH];
+        for chunk in inputs.chunks(RATE) {
+            for (lane, input) in state.iter_mut().zip(chunk.iter()) {
+                *lane += input;
+            }
+            for round in 0..ROUNDS {
+                for (i, lane) in state.iter_mut().enumerate() {
+                    *lane = (*lane + round_constant(round, i)).pow_vartime([7]);
+                }
+                let sum: Goldilocks = state.iter().sum();
+                for lane in state.iter_mut() {
+                    *lane += sum;
+                }
+            }
+        }
+        state[..RATE].to_vec()
+    }
+
+    fn native_hash_leaf(layout: FriLayout, leaf: &[Goldilocks]) -> Vec<Goldilocks> {
+        if layout == FriLayout::Plonky2 && leaf.len() <= RATE {
+            let mut digest = leaf.to_vec();
+            digest.resize(RATE, Goldilocks::ZERO);
+            digest
+        } else {
+            native_hash(leaf)
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct ToyHasher {
+        chip: SmallFieldChip<Goldilocks, BnScalar>,
+    }
+
+    impl FriHasherInstructions<Goldilocks, BnScalar> for ToyHasher {
+        fn digest_len(&self) -> usize {
+            RATE
+        }
+
+        fn hash(
+            &self,
+            ctx: &mut RegionCtx<'_, BnScalar>,
+            inputs: &[AssignedSmallField<Goldilocks, BnScalar>],
+        ) -> Result<AssignedDigest<Goldilocks, BnScalar>, Error> {
+            let chip = &self.chip;
+            let mut state = (0..WIDTH)
+                .map(|_| chip.assign_constant(ctx, Goldilocks::ZERO))
+                .collect::<Result<Vec<_>, Error>>()?;
+            for chunk in inputs.chunks(RATE) {
+                for (lane, input) in state.iter_mut().zip(chunk.iter()) {
+                    *lane = chip.add(ctx, lane, input)?;
+                }
+                for round in 0..ROUNDS {
+                    for (i, lane) in state.iter_mut().enumerate() {
+                        let x = chip.add_constant(ctx, lane, round_constant(round, i))?;
+                        let x2 = chip.mul(ctx, &x, &x)?;
+                        let x4 = chip.mul(ctx, &x2, &x2)?;
+                        let x6 = chip.mul(ctx, &x4, &x2)?;
+                        *lane = chip.mul(ctx, &x6, &x)?;
+                    }
+                    let mut sum = state[0].clone();
+                    for lane in state[1..].iter() {
+                        sum = chip.add(ctx, &sum, lane)?;
+                    }
+                    for lane in state.iter_mut() {
+                        *lane = chip.add(ctx, lane, &sum)?;
+                    }
+                }
+            }
+            state.truncate(RATE);
+            Ok(state)
+        }
+
+        fn two_to_one(
+            &self,
+            ctx: &mut RegionCtx<'_, BnScalar>,
+            left: &AssignedDigest<Goldilocks, BnScalar>,
+            right: &AssignedDigest<Goldilocks, BnScalar>,
+        ) -> Result<AssignedDigest<Goldilocks, BnScalar>, Error> {
+            let inputs: Vec<_> = left.iter().chain(right.iter()).cloned().collect();
+            self.hash(ctx, &inputs)
+        }
+    }
+
+    fn ext_eval(coeffs: &[Ext], x: Goldilocks) -> Ext {
+        coeffs
+            .iter()
+            .rev()
+            .fold([Goldilocks::ZERO; 2], |acc, coeff| {
+                [acc[0] * x + coeff[0], acc[1] * x + coeff[1]]
+            })
+    }
+
+    struct QueryStep {
+        evals: Vec<Ext>,
+        merkle_proof: Vec<Vec<Goldilocks>>,
+    }
+
+    struct Query {
+        index: usize,
+        initial_eval: Ext,
+        steps: Vec<QueryStep>,
+    }
+
+    struct FriProof {
+        caps: Vec<Vec<Vec<Goldilocks>>>,
+        betas: Vec<Ext>,
+        final_poly: Vec<Ext>,
+        queries: Vec<Query>,
+    }
+
+    // Commits, folds and opens a random polynomial in the layout of the config
+    // with a transcript that is built with the toy sponge
+    fn prove(config: &FriConfig, degree_bits: usize, number_of_queries: usize) -> FriProof {
+        let non_residue = Goldilocks::from(NON_RESIDUE);
+        let mut poly: Vec<Ext> = (0..1 << degree_bits)
+            .map(|_| [Goldilocks::random(OsRng), Goldilocks::random(OsRng)])
+            .collect();
+        let mut transcript = vec![];
+
+        let mut domain_bits = config.lde_bits;
+        let mut shift = Goldilocks::MULTIPLICATIVE_GENERATOR;
+        let mut rounds = vec![];
+        let mut caps = vec![];
+        let mut betas = vec![];
+        for arity_bits in config.reduction_arity_bits.iter() {
+            let generator = root_of_unity::<Goldilocks>(domain_bits);
+            let n = 1 << domain_bits;
+            let arity = 1 << arity_bits;
+            let evals: Vec<Ext> = (0..n)
+                .map(|j| ext_eval(&poly, shift * generator.pow_vartime([j as u64])))
+                .collect();
+
+            let (evals, leaves): (Vec<Ext>, Vec<Vec<Ext>>) = match config.layout {
+                FriLayout::Natural => {
+                    let leaves = (0..n / arity)
+                        .map(|j| (0..arity).map(|t| evals[j + t * n / arity]).collect())
+                        .collect();
+                    (evals, leaves)
+                }
+                FriLayout::Plonky2 => {
+                    let evals: Vec<Ext> = (0..n)
+                        .map(|j| evals[reverse_bits(j, domain_bits)])
+                        .collect();
+                    let leaves = evals.chunks(arity).map(|leaf| leaf.to_vec()).collect();
+                    (evals, leaves)
+                }
+            };
+            let mut layers = vec![leaves
+                .iter()
+                .map(|leaf| native_hash_leaf(config.layout, &leaf.concat()))
+                .collect::<Vec<_>>()];
+            while layers.last().unwrap().len() > 1 << config.cap_height {
+                let layer = layers
+                    .last()
+                    .unwrap()
+                    .chunks(2)
+                    .map(|pair| native_hash(&pair.concat()))
+                    .collect();
+                layers.push(layer);
+            }
+            let cap = layers.last().unwrap().clone();
+            transcript.extend(cap.concat());
+            let beta: Ext = native_hash(&transcript)[..2].try_into().unwrap();
+
+            // f(X) = sum X^k * f_k(X^arity) is folded into sum beta^k * f_k(Y)
+            let mut beta_power = [Goldilocks::ONE, Goldilocks::ZERO];
+            let mut folded = vec![[Goldilocks::ZERO; 2]; poly.len() / arity];
+            for k in 0..arity {
+                for (i, folded) in folded.iter_mut().enumerate() {
+                    let term = ext_mul(&poly[i * arity + k], &beta_power, non_residue);
+                    *folded = [folded[0] + term[0], folded[1] + term[1]];
+                }
+                beta_power = ext_mul(&beta_power, &beta, non_residue);
+            }
+
+            rounds.push((evals, leaves, layers));
+            caps.push(cap);
+            betas.push(beta);
+            poly = folded;
+            shift = shift.pow_vartime([arity as u64]);
+            domain_bits -= arity_bits;
+        }
+        transcript.extend(poly.concat());
+
+        let queries = (0..number_of_queries)
+            .map(|i| {
+                transcript.push(Goldilocks::from(i as u64));
+                let challenge = native_hash(&transcript)[0];
+                let index = (challenge.to_repr().as_ref()[0] as usize) % (1 << config.lde_bits);
+                let mut x_index = index;
+                let steps = rounds
+                    .iter()
+                    .zip(config.reduction_arity_bits.iter())
+                    .map(|((_, leaves, layers), arity_bits)| {
+                        let mut leaf_index = match config.layout {
+                            FriLayout::Natural => x_index % leaves.len(),
+                            FriLayout::Plonky2 => x_index >> arity_bits,
+                        };
+                        x_index = leaf_index;
+                        let evals = leaves[leaf_index].clone();
+                        let merkle_proof = layers[..layers.len() - 1]
+                            .iter()
+                            .map(|layer| {
+                                let sibling = layer[leaf_index ^ 1].clone();
+                                leaf_index >>= 1;
+                                sibling
+                            })
+                            .collect();
+                        QueryStep {
+                            evals,
+                            merkle_proof,
+                        }
+                    })
+                    .collect();
+                Query {
+                    index,
+                    initial_eval: rounds[0].0[index],
+                    steps,
+                }
+            })
+            .collect();
+
+        FriProof {
+            caps,
+            betas,
+            final_poly: poly,
+            queries,
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        range_config: RangeConfig,
+        main_gate_config: MainGateConfig,
+    }
+
+    struct TestCircuit {
+        config: FriConfig,
+        proof: FriProof,
+    }
+
+    impl Circuit<BnScalar> for TestCircuit {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<BnScalar>) -> Self::Config {
+            let main_gate_config = MainGate::<BnScalar>::configure(meta);
+            let range_config = RangeChip::<BnScalar>::configure(
+                meta,
+                &main_gate_config,
+                vec![SmallFieldChip::<Goldilocks, BnScalar>::sublimb_bit_len()],
+                vec![],
+            );
+            TestCircuitConfig {
+                range_config,
+                main_gate_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<BnScalar>,
+        ) -> Result<(), Error> {
+            let small_field_chip = SmallFieldChip::new(IntegerConfig::new(
+                config.range_config.clone(),
+                config.main_gate_config.clone(),
+            ));
+            let extension_chip = SmallExtensionChip::<_, _, 2>::new(
+                small_field_chip.clone(),
+                Goldilocks::from(NON_RESIDUE),
+            );
+            let hasher = ToyHasher {
+                chip: small_field_chip.clone(),
+            };
+            let chip = FriChip::new(extension_chip.clone(), hasher, self.config.clone());
+            let main_gate = small_field_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let proof = &self.proof;
+
+                    let assign_digest =
+                        |ctx: &mut RegionCtx<'_, BnScalar>, digest: &Vec<Goldilocks>| {
+                            digest
+                                .iter()
+                                .map(|e| small_field_chip.assign(ctx, Value::known(*e)))
+                                .collect::<Result<Vec<_>, Error>>()
+                        };
+                    let assign_ext = |ctx: &mut RegionCtx<'_, BnScalar>, e: &Ext| {
+                        extension_chip.assign(ctx, Value::known(*e))
+                    };
+
+                    let caps = proof
+                        .caps
+                        .iter()
+                        .map(|cap| cap.iter().map(|d| assign_digest(ctx, d)).collect())
+                        .collect::<Result<Vec<Vec<_>>, Error>>()?;
+                    let betas = proof
+                        .betas
+                        .iter()
+                        .map(|beta| assign_ext(ctx, beta))
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let final_poly = proof
+                        .final_poly
+                        .iter()
+                        .map(|coeff| assign_ext(ctx, coeff))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    for query in proof.queries.iter() {
+                        let index_bits = (0..self.config.lde_bits)
+                            .map(|i| {
+                                let bit = BnScalar::from(((query.index >> i) & 1) as u64);
+                                main_gate.assign_bit(ctx, Value::known(bit))
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+                        let initial_eval = assign_ext(ctx, &query.initial_eval)?;
+                        let steps = query
+                            .steps
+                            .iter()
+                            .map(|step| {
+                                Ok(AssignedFriQueryStep {
+                                    evals: step
+                                        .evals
+                                        .iter()
+                                        .map(|e| assign_ext(ctx, e))
+                                        .collect::<Result<Vec<_>, Error>>()?,
+                                    merkle_proof: step
+                                        .merkle_proof
+                                        .iter()
+                                        .map(|d| assign_digest(ctx, d))
+                                        .collect::<Result<Vec<_>, Error>>()?,
+                                })
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+
+                        chip.verify_query_round(
+                            ctx,
+                            &index_bits,
+                            &initial_eval,
+                            &betas,
+                            &caps,
+                            &final_poly,
+                            &steps,
+                        )?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<BnScalar>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fri_query_rounds() {
+        // Leaves of the second round are as long as a digest and are not
+        // hashed in Plonky2 layout
+        for layout in [FriLayout::Natural, FriLayout::Plonky2] {
+            let config = FriConfig {
+                lde_bits: 6,
+                reduction_arity_bits: vec![2, 1],
+                cap_height: 1,
+                layout,
+            };
+            let proof = prove(&config, 4, 2);
+            assert_eq!(proof.final_poly.len(), 2);
+
+            let circuit = TestCircuit { config, proof };
+            let instance = vec![vec![]];
+            mock_prover_verify(&circuit, instance);
+        }
+    }
+}
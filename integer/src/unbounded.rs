@@ -0,0 +1,608 @@
+use crate::{sublimb_bit_len, AssignedLimb, IntegerConfig};
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use maingate::halo2::ff::PrimeField;
+use maingate::{
+    big_to_fe, compose, decompose_big, fe_to_big, halo2, power_of_two, AssignedCondition,
+    AssignedValue, MainGate, MainGateInstructions, RangeChip, RangeInstructions, RegionCtx, Term,
+};
+use num_bigint::BigUint as big_uint;
+use num_integer::Integer as _;
+use num_traits::{One, Zero};
+
+/// Unsigned integer of arbitrary size that is represented with
+/// `BIT_LEN_LIMB` bit limbs in little endian order. Unlike `AssignedInteger`
+/// it is not bound to a wrong field and the number of limbs is decided at
+/// synthesis time.
+#[derive(Debug, Clone)]
+pub struct AssignedBigUint<N: PrimeField, const BIT_LEN_LIMB: usize> {
+    limbs: Vec<AssignedLimb<N>>,
+}
+
+impl<N: PrimeField, const BIT_LEN_LIMB: usize> AssignedBigUint<N, BIT_LEN_LIMB> {
+    fn new(limbs: Vec<AssignedLimb<N>>) -> Self {
+        assert!(!limbs.is_empty());
+        AssignedBigUint { limbs }
+    }
+
+    /// Returns assigned limbs
+    pub fn limbs(&self) -> &[AssignedLimb<N>] {
+        &self.limbs
+    }
+
+    /// Returns number of limbs
+    pub fn number_of_limbs(&self) -> usize {
+        self.limbs.len()
+    }
+
+    /// Witness value of the integer
+    pub fn value(&self) -> Value<big_uint> {
+        let limbs: Value<Vec<big_uint>> = self
+            .limbs
+            .iter()
+            .map(|limb| limb.value().map(fe_to_big))
+            .collect();
+        limbs.map(|limbs| compose(limbs, BIT_LEN_LIMB))
+    }
+
+    /// Maximum value that the integer can take
+    pub fn max_val(&self) -> big_uint {
+        let max_vals = self.limbs.iter().map(|limb| limb.max_val()).collect();
+        compose(max_vals, BIT_LEN_LIMB)
+    }
+
+    /// Returns true if all limbs are known to fit in `BIT_LEN_LIMB` bits
+    pub fn is_normalized(&self) -> bool {
+        self.limbs
+            .iter()
+            .all(|limb| limb.max_val.bits() as usize <= BIT_LEN_LIMB)
+    }
+}
+
+/// Chip for unsigned integers that are wider than the native field such as
+/// RSA moduli. Limbs are range checked with the same lookup tables that
+/// `IntegerChip` uses so that both chips can be built from an `IntegerConfig`.
+/// Range chip must be configured with `sublimb_bit_len()` composition bit
+/// length and no overflow bit length is required.
+#[derive(Debug, Clone)]
+pub struct BigUintChip<N: PrimeField, const BIT_LEN_LIMB: usize> {
+    /// RangeChip
+    range_chip: RangeChip<N>,
+    /// MainGate
+    main_gate: MainGate<N>,
+}
+
+impl<N: PrimeField, const BIT_LEN_LIMB: usize> BigUintChip<N, BIT_LEN_LIMB> {
+    /// Creates a new [`BigUintChip`]
+    pub fn new(config: IntegerConfig) -> Self {
+        BigUintChip {
+            range_chip: RangeChip::new(config.range_config),
+            main_gate: MainGate::new(config.main_gate_config),
+        }
+    }
+
+    /// Getter for [`RangeChip`]
+    pub fn range_chip(&self) -> &RangeChip<N> {
+        &self.range_chip
+    }
+
+    /// Getter for [`MainGate`]
+    pub fn main_gate(&self) -> &MainGate<N> {
+        &self.main_gate
+    }
+
+    /// Bit length of sublimbs of lookup range checks
+    pub fn sublimb_bit_len() -> usize {
+        sublimb_bit_len(BIT_LEN_LIMB)
+    }
+
+    fn max_limb() -> big_uint {
+        (big_uint::one() << BIT_LEN_LIMB) - 1usize
+    }
+
+    // Minimum number of limbs to represent values upto `max_val`
+    fn number_of_limbs(max_val: &big_uint) -> usize {
+        let number_of_limbs = (max_val.bits() as usize + BIT_LEN_LIMB - 1) / BIT_LEN_LIMB;
+        std::cmp::max(number_of_limbs, 1)
+    }
+
+    // Limb values must never wrap the native modulus
+    fn assert_in_native(max_val: &big_uint) {
+        assert!(
+            (max_val.bits() as usize) < N::CAPACITY as usize,
+            "limb overflows native field"
+        );
+    }
+
+    /// Assigns new witness integer with `number_of_limbs` limbs where each
+    /// limb is range checked to `BIT_LEN_LIMB` bits
+    pub fn assign_integer(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: Value<big_uint>,
+        number_of_limbs: usize,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        let limbs = integer
+            .map(|integer| decompose_big::<N>(integer, number_of_limbs, BIT_LEN_LIMB))
+            .transpose_vec(number_of_limbs);
+        let limbs = limbs
+            .into_iter()
+            .map(|limb| {
+                let limb =
+                    self.range_chip
+                        .assign(ctx, limb, Self::sublimb_bit_len(), BIT_LEN_LIMB)?;
+                Ok(AssignedLimb::from(limb, Self::max_limb()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(AssignedBigUint::new(limbs))
+    }
+
+    /// Assigns a constant integer with the least number of limbs that
+    /// represents it
+    pub fn assign_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        integer: big_uint,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        let number_of_limbs = Self::number_of_limbs(&integer);
+        let limbs = decompose_big::<N>(integer, number_of_limbs, BIT_LEN_LIMB)
+            .into_iter()
+            .map(|limb| {
+                let assigned = self.main_gate.assign_constant(ctx, limb)?;
+                Ok(AssignedLimb::from(assigned, fe_to_big(limb)))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(AssignedBigUint::new(limbs))
+    }
+
+    /// Adds two integers limb by limb. Carries are not propagated
+    pub fn add(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        b: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        let number_of_limbs = std::cmp::max(a.number_of_limbs(), b.number_of_limbs());
+        let limbs = (0..number_of_limbs)
+            .map(|i| match (a.limbs.get(i), b.limbs.get(i)) {
+                (Some(a), Some(b)) => {
+                    let max_val = a.add(b);
+                    Self::assert_in_native(&max_val);
+                    let c = self.main_gate.add(ctx, a.as_ref(), b.as_ref())?;
+                    Ok(AssignedLimb::from(c, max_val))
+                }
+                (Some(limb), None) | (None, Some(limb)) => Ok(limb.clone()),
+                (None, None) => unreachable!(),
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(AssignedBigUint::new(limbs))
+    }
+
+    /// Multiplies two integers. Limbs of the result are sums of limb products
+    /// and carries are not propagated
+    pub fn mul(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        b: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        let number_of_limbs = a.number_of_limbs() + b.number_of_limbs() - 1;
+        let mut limbs = Vec::with_capacity(number_of_limbs);
+        for k in 0..number_of_limbs {
+            // t_k = sum a_i * b_j where i + j = k
+            let mut t: Option<AssignedValue<N>> = None;
+            let mut max_val = big_uint::zero();
+            for (i, a_i) in a.limbs.iter().enumerate().take(k + 1) {
+                let b_j = match b.limbs.get(k - i) {
+                    Some(b_j) => b_j,
+                    None => continue,
+                };
+                max_val += &a_i.max_val * &b_j.max_val;
+                t = Some(match t {
+                    None => self.main_gate.mul(ctx, a_i.as_ref(), b_j.as_ref())?,
+                    Some(t) => self
+                        .main_gate
+                        .mul_add(ctx, a_i.as_ref(), b_j.as_ref(), &t)?,
+                });
+            }
+            Self::assert_in_native(&max_val);
+            limbs.push(AssignedLimb::from(t.unwrap(), max_val));
+        }
+        Ok(AssignedBigUint::new(limbs))
+    }
+
+    /// Selects `a` if the condition is set and `b` otherwise. Missing limbs of
+    /// the shorter integer are taken as zero
+    pub fn select(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        b: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        cond: &AssignedCondition<N>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        let number_of_limbs = std::cmp::max(a.number_of_limbs(), b.number_of_limbs());
+        let zero = if a.number_of_limbs() != b.number_of_limbs() {
+            let zero = self.main_gate.assign_constant(ctx, N::ZERO)?;
+            Some(AssignedLimb::from(zero, big_uint::zero()))
+        } else {
+            None
+        };
+        let limbs = (0..number_of_limbs)
+            .map(|i| {
+                let a = a.limbs.get(i).or(zero.as_ref()).unwrap();
+                let b = b.limbs.get(i).or(zero.as_ref()).unwrap();
+                let max_val = std::cmp::max(a.max_val(), b.max_val());
+                let selected = self.main_gate.select(ctx, a.as_ref(), b.as_ref(), cond)?;
+                Ok(AssignedLimb::from(selected, max_val))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(AssignedBigUint::new(limbs))
+    }
+
+    /// Propagates carries so that each limb of the result fits in
+    /// `BIT_LEN_LIMB` bits. Result has as many limbs as the maximum value of
+    /// the input requires.
+    pub fn normalize(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        if a.is_normalized() {
+            return Ok(a.clone());
+        }
+
+        let sublimb_bit_len = Self::sublimb_bit_len();
+        let base = power_of_two::<N>(BIT_LEN_LIMB);
+        let number_of_limbs =
+            std::cmp::max(a.number_of_limbs(), Self::number_of_limbs(&a.max_val()));
+
+        let mut limbs = Vec::with_capacity(number_of_limbs);
+        let mut carry: Option<AssignedLimb<N>> = None;
+        for i in 0..number_of_limbs {
+            let is_last = i == number_of_limbs - 1;
+            // Limb and the incoming carry is split as `r + c * 2^B`
+            let inputs = a.limbs.get(i).into_iter().chain(carry.iter());
+            let (sum, max_val) = inputs.clone().fold(
+                (Value::known(big_uint::zero()), big_uint::zero()),
+                |(sum, max_val), limb| {
+                    let sum = sum
+                        .zip(limb.value())
+                        .map(|(sum, limb)| sum + fe_to_big(limb));
+                    (sum, max_val + &limb.max_val)
+                },
+            );
+            Self::assert_in_native(&max_val);
+
+            let r = sum
+                .as_ref()
+                .map(|sum| big_to_fe(sum % (big_uint::one() << BIT_LEN_LIMB)));
+            let r = self
+                .range_chip
+                .assign(ctx, r, sublimb_bit_len, BIT_LEN_LIMB)?;
+
+            // Last carry must be zero. Carries are range checked in full sublimbs
+            // so that no overflow table is required
+            let carry_bit_len = if is_last {
+                0
+            } else {
+                let bit_len = (max_val >> BIT_LEN_LIMB).bits() as usize;
+                (bit_len + sublimb_bit_len - 1) / sublimb_bit_len * sublimb_bit_len
+            };
+            let next_carry = if carry_bit_len == 0 {
+                None
+            } else {
+                assert!(BIT_LEN_LIMB + carry_bit_len < N::CAPACITY as usize);
+                let c = sum.map(|sum| big_to_fe(sum >> BIT_LEN_LIMB));
+                let c = self
+                    .range_chip
+                    .assign(ctx, c, sublimb_bit_len, carry_bit_len)?;
+                let max_val = (big_uint::one() << carry_bit_len) - 1usize;
+                Some(AssignedLimb::from(c, max_val))
+            };
+
+            let mut terms = inputs
+                .map(|limb| Term::assigned_to_add(limb.as_ref()))
+                .collect::<Vec<_>>();
+            terms.push(Term::assigned_to_sub(&r));
+            if let Some(next_carry) = next_carry.as_ref() {
+                terms.push(Term::Assigned(next_carry.as_ref(), -base));
+            }
+            self.main_gate.assert_zero_sum(ctx, &terms, N::ZERO)?;
+
+            limbs.push(AssignedLimb::from(r, Self::max_limb()));
+            carry = next_carry;
+        }
+
+        Ok(AssignedBigUint::new(limbs))
+    }
+
+    /// Constrains two integers to be equal. Inputs are normalized so that
+    /// limb representations can be compared
+    pub fn assert_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        b: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let a = self.normalize(ctx, a)?;
+        let b = self.normalize(ctx, b)?;
+        let number_of_limbs = std::cmp::max(a.number_of_limbs(), b.number_of_limbs());
+        for i in 0..number_of_limbs {
+            match (a.limbs.get(i), b.limbs.get(i)) {
+                (Some(a), Some(b)) => self.main_gate.assert_equal(ctx, a.as_ref(), b.as_ref())?,
+                (Some(limb), None) | (None, Some(limb)) => {
+                    self.main_gate.assert_zero(ctx, limb.as_ref())?
+                }
+                (None, None) => unreachable!(),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns 1 if two integers are equal and 0 otherwise
+    pub fn is_equal(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        b: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let a = self.normalize(ctx, a)?;
+        let b = self.normalize(ctx, b)?;
+        let number_of_limbs = std::cmp::max(a.number_of_limbs(), b.number_of_limbs());
+        let mut is_equal: Option<AssignedCondition<N>> = None;
+        for i in 0..number_of_limbs {
+            let is_limb_equal = match (a.limbs.get(i), b.limbs.get(i)) {
+                (Some(a), Some(b)) => self.main_gate.is_equal(ctx, a.as_ref(), b.as_ref())?,
+                (Some(limb), None) | (None, Some(limb)) => {
+                    self.main_gate.is_zero(ctx, limb.as_ref())?
+                }
+                (None, None) => unreachable!(),
+            };
+            is_equal = Some(match is_equal {
+                None => is_limb_equal,
+                Some(is_equal) => self.main_gate.and(ctx, &is_equal, &is_limb_equal)?,
+            });
+        }
+        Ok(is_equal.unwrap())
+    }
+
+    /// Returns 1 if `a < b` and 0 otherwise
+    pub fn is_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        b: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let a = self.normalize(ctx, a)?;
+        let b = self.normalize(ctx, b)?;
+        let number_of_limbs = std::cmp::max(a.number_of_limbs(), b.number_of_limbs());
+
+        // Both integers are below `2^(n*B)` so the bit at `n*B` of
+        // `a + (2^(n*B) - 1 - b) + 1` is set if and only if `a >= b`
+        let limbs = (0..number_of_limbs)
+            .map(|i| {
+                let constant = if i == 0 {
+                    big_uint::one() << BIT_LEN_LIMB
+                } else {
+                    Self::max_limb()
+                };
+                let mut terms = vec![];
+                let mut max_val = constant.clone();
+                if let Some(a) = a.limbs.get(i) {
+                    terms.push(Term::assigned_to_add(a.as_ref()));
+                    max_val += &a.max_val;
+                }
+                if let Some(b) = b.limbs.get(i) {
+                    terms.push(Term::assigned_to_sub(b.as_ref()));
+                }
+                let limb = self.main_gate.compose(ctx, &terms, big_to_fe(constant))?;
+                Ok(AssignedLimb::from(limb, max_val))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let dif = self.normalize(ctx, &AssignedBigUint::new(limbs))?;
+        assert_eq!(dif.number_of_limbs(), number_of_limbs + 1);
+
+        let is_greater_or_equal = dif.limbs[number_of_limbs].as_ref();
+        self.main_gate.not(ctx, is_greater_or_equal)
+    }
+
+    /// Constrains `a < b`
+    pub fn assert_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        b: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let is_less_than = self.is_less_than(ctx, a, b)?;
+        self.main_gate.assert_one(ctx, &is_less_than)
+    }
+
+    /// Multiplies two integers modulo `modulus` which might be either an
+    /// assigned or a constant integer. Quotient and remainder are witnessed and
+    /// `a * b = q * modulus + r` with `r < modulus` is constrained. Most
+    /// significant limb of the modulus is expected to be non zero so that the
+    /// quotient fits in the allocated limbs.
+    pub fn mul_mod(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        b: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        modulus: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        let number_of_quotient_limbs = (a.number_of_limbs() + b.number_of_limbs())
+            .saturating_sub(modulus.number_of_limbs())
+            + 1;
+        let (quotient, remainder) = a
+            .value()
+            .zip(b.value())
+            .zip(modulus.value())
+            .map(|((a, b), modulus)| (a * b).div_rem(&modulus))
+            .unzip();
+        let quotient = self.assign_integer(ctx, quotient, number_of_quotient_limbs)?;
+        let remainder = self.assign_integer(ctx, remainder, modulus.number_of_limbs())?;
+        self.assert_less_than(ctx, &remainder, modulus)?;
+
+        let ab = self.mul(ctx, a, b)?;
+        let qm = self.mul(ctx, &quotient, modulus)?;
+        let qm_r = self.add(ctx, &qm, &remainder)?;
+        self.assert_equal(ctx, &ab, &qm_r)?;
+
+        Ok(remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BigUintChip;
+    use crate::{IntegerConfig, PrimeField};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use maingate::{
+        halo2, mock_prover_verify, MainGate, MainGateConfig, MainGateInstructions, RangeChip,
+        RangeConfig, RangeInstructions, RegionCtx,
+    };
+    use num_bigint::{BigUint as big_uint, RandBigInt};
+    use num_traits::One;
+    use rand_core::OsRng;
+    use std::marker::PhantomData;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        range_config: RangeConfig,
+        main_gate_config: MainGateConfig,
+    }
+
+    struct TestCircuit<N: PrimeField, const BIT_LEN_LIMB: usize> {
+        number_of_limbs: usize,
+        a: big_uint,
+        b: big_uint,
+        modulus: big_uint,
+        _marker: PhantomData<N>,
+    }
+
+    impl<N: PrimeField, const BIT_LEN_LIMB: usize> Circuit<N> for TestCircuit<N, BIT_LEN_LIMB> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                vec![BigUintChip::<N, BIT_LEN_LIMB>::sublimb_bit_len()],
+                vec![],
+            );
+            TestCircuitConfig {
+                range_config,
+                main_gate_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let chip = BigUintChip::<N, BIT_LEN_LIMB>::new(IntegerConfig::new(
+                config.range_config.clone(),
+                config.main_gate_config.clone(),
+            ));
+            let main_gate = chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let n = self.number_of_limbs;
+                    let known = |e: &big_uint| Value::known(e.clone());
+
+                    let a = chip.assign_integer(ctx, known(&self.a), n)?;
+                    let b = chip.assign_integer(ctx, known(&self.b), n)?;
+                    let modulus = chip.assign_integer(ctx, known(&self.modulus), n)?;
+
+                    // add
+                    let c = chip.add(ctx, &a, &b)?;
+                    let expected = chip.assign_integer(ctx, known(&(&self.a + &self.b)), n + 1)?;
+                    chip.assert_equal(ctx, &c, &expected)?;
+
+                    // mul and normalize
+                    let c = chip.mul(ctx, &a, &b)?;
+                    let c = chip.normalize(ctx, &c)?;
+                    assert_eq!(c.number_of_limbs(), 2 * n);
+                    let expected = chip.assign_integer(ctx, known(&(&self.a * &self.b)), 2 * n)?;
+                    chip.assert_equal(ctx, &c, &expected)?;
+                    let is_equal = chip.is_equal(ctx, &c, &expected)?;
+                    main_gate.assert_one(ctx, &is_equal)?;
+                    let is_equal = chip.is_equal(ctx, &a, &c)?;
+                    main_gate.assert_zero(ctx, &is_equal)?;
+
+                    // mul mod with assigned and constant modulus
+                    let expected = (&self.a * &self.b) % &self.modulus;
+                    let expected = chip.assign_integer(ctx, known(&expected), n)?;
+                    let c = chip.mul_mod(ctx, &a, &b, &modulus)?;
+                    chip.assert_equal(ctx, &c, &expected)?;
+                    let modulus = chip.assign_constant(ctx, self.modulus.clone())?;
+                    let c = chip.mul_mod(ctx, &a, &b, &modulus)?;
+                    chip.assert_equal(ctx, &c, &expected)?;
+
+                    // select
+                    let cond = main_gate.assign_bit(ctx, Value::known(N::ONE))?;
+                    let one = chip.assign_constant(ctx, big_uint::one())?;
+                    let c = chip.select(ctx, &a, &one, &cond)?;
+                    chip.assert_equal(ctx, &c, &a)?;
+                    let c = chip.select(ctx, &one, &a, &cond)?;
+                    chip.assert_equal(ctx, &c, &one)?;
+
+                    // comparisons
+                    let is_less_than = chip.is_less_than(ctx, &a, &b)?;
+                    let expected = N::from((self.a < self.b) as u64);
+                    let expected = main_gate.assign_constant(ctx, expected)?;
+                    main_gate.assert_equal(ctx, &is_less_than, &expected)?;
+                    let is_less_than = chip.is_less_than(ctx, &a, &a)?;
+                    main_gate.assert_zero(ctx, &is_less_than)?;
+                    chip.assert_less_than(ctx, &a, &modulus)?;
+                    chip.assert_less_than(ctx, &b, &modulus)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<N>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run<N: PrimeField, const BIT_LEN_LIMB: usize>(number_of_limbs: usize) {
+        let bit_len = (number_of_limbs * BIT_LEN_LIMB) as u64;
+        let modulus = OsRng.gen_biguint(bit_len - 1) | (big_uint::one() << (bit_len - 1));
+        let a = OsRng.gen_biguint_below(&modulus);
+        let b = OsRng.gen_biguint_below(&modulus);
+
+        let circuit = TestCircuit::<N, BIT_LEN_LIMB> {
+            number_of_limbs,
+            a,
+            b,
+            modulus,
+            _marker: PhantomData,
+        };
+        let instance = vec![vec![]];
+        mock_prover_verify(&circuit, instance);
+    }
+
+    #[test]
+    fn test_big_uint_circuit() {
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::pasta::Fp as PastaFp;
+
+        run::<BnScalar, 64>(4);
+        run::<BnScalar, 64>(7);
+        run::<PastaFp, 68>(3);
+    }
+}
@@ -0,0 +1,348 @@
+use maingate::halo2::ff::PrimeField;
+use num_bigint::BigUint as big_uint;
+
+/// Number of 64 bit words of [`Wide`]. Products of integers with unreduced
+/// limbs of wide native fields and sums of such products must fit in.
+pub(crate) const WORDS: usize = 48;
+
+/// Fixed width unsigned integer used in witness arithmetic of
+/// [`crate::rns::Integer`] so that multiplications and reductions don't
+/// allocate. Arithmetic panics if a result doesn't fit in [`WORDS`] or if a
+/// subtraction underflows.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Wide([u64; WORDS]);
+
+impl std::fmt::Debug for Wide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Wide({:#x})", self.to_big())
+    }
+}
+
+impl Wide {
+    pub(crate) fn zero() -> Self {
+        Wide([0; WORDS])
+    }
+
+    /// Returns the number of significant words
+    fn len(&self) -> usize {
+        self.0
+            .iter()
+            .rposition(|word| *word != 0)
+            .map_or(0, |i| i + 1)
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bit length of the value
+    pub(crate) fn bits(&self) -> usize {
+        match self.len() {
+            0 => 0,
+            len => len * 64 - self.0[len - 1].leading_zeros() as usize,
+        }
+    }
+
+    pub(crate) fn from_big(e: &big_uint) -> Self {
+        let digits = e.to_u64_digits();
+        assert!(digits.len() <= WORDS, "value doesn't fit in wide integer");
+        let mut words = [0; WORDS];
+        words[..digits.len()].copy_from_slice(&digits);
+        Wide(words)
+    }
+
+    pub(crate) fn to_big(&self) -> big_uint {
+        big_uint::from_bytes_le(
+            &self.0[..self.len()]
+                .iter()
+                .flat_map(|word| word.to_le_bytes())
+                .collect::<Vec<u8>>(),
+        )
+    }
+
+    /// Adds `value << (64 * idx)` in place
+    fn add_word(&mut self, idx: usize, value: u64) {
+        let mut carry = value;
+        for word in self.0[idx..].iter_mut() {
+            if carry == 0 {
+                return;
+            }
+            let (sum, overflow) = word.overflowing_add(carry);
+            *word = sum;
+            carry = overflow as u64;
+        }
+        assert_eq!(carry, 0, "value doesn't fit in wide integer");
+    }
+
+    /// Computes `limb_0 + limb_1 * 2^bit_len + limb_2 * 2^(2 * bit_len) + ...`
+    /// where limbs may be wider than `bit_len`
+    pub(crate) fn compose<F: PrimeField>(limbs: &[F], bit_len: usize) -> Self {
+        let mut e = Wide::zero();
+        for (i, limb) in limbs.iter().enumerate() {
+            let (start, shift) = ((i * bit_len) / 64, (i * bit_len) % 64);
+            let repr = limb.to_repr();
+            for (j, chunk) in repr.as_ref().chunks(8).enumerate() {
+                let mut word = [0u8; 8];
+                word[..chunk.len()].copy_from_slice(chunk);
+                let word = u64::from_le_bytes(word);
+                if word == 0 {
+                    continue;
+                }
+                e.add_word(start + j, word << shift);
+                if shift != 0 {
+                    e.add_word(start + j + 1, word >> (64 - shift));
+                }
+            }
+        }
+        e
+    }
+
+    /// Returns `bit_len` bits wide limbs of the value starting from the least
+    /// significant bit. Bits above the last limb are discarded.
+    pub(crate) fn decompose<F: PrimeField, const NUMBER_OF_LIMBS: usize>(
+        &self,
+        bit_len: usize,
+    ) -> [F; NUMBER_OF_LIMBS] {
+        assert!(bit_len < F::NUM_BITS as usize);
+        std::array::from_fn(|i| {
+            let mut repr = F::Repr::default();
+            let bytes = repr.as_mut();
+            let offset = i * bit_len;
+            for k in 0..(bit_len + 63) / 64 {
+                let (idx, shift) = (offset / 64 + k, offset % 64);
+                let lo = self.0.get(idx).map_or(0, |word| word >> shift);
+                let hi = match shift {
+                    0 => 0,
+                    _ => self.0.get(idx + 1).map_or(0, |word| word << (64 - shift)),
+                };
+                let mut word = lo | hi;
+                // Clear bits above the limb
+                let remaining = bit_len - 64 * k;
+                if remaining < 64 {
+                    word &= (1 << remaining) - 1;
+                }
+                for (byte, word_byte) in bytes[8 * k..].iter_mut().zip(word.to_le_bytes()) {
+                    *byte = word_byte;
+                }
+            }
+            F::from_repr(repr).unwrap()
+        })
+    }
+
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let mut carry = false;
+        let words = std::array::from_fn(|i| {
+            let (sum, overflow_0) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, overflow_1) = sum.overflowing_add(carry as u64);
+            carry = overflow_0 | overflow_1;
+            sum
+        });
+        assert!(!carry, "value doesn't fit in wide integer");
+        Wide(words)
+    }
+
+    pub(crate) fn sub(&self, other: &Self) -> Self {
+        let mut borrow = false;
+        let words = std::array::from_fn(|i| {
+            let (diff, underflow_0) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, underflow_1) = diff.overflowing_sub(borrow as u64);
+            borrow = underflow_0 | underflow_1;
+            diff
+        });
+        assert!(!borrow, "subtraction underflows");
+        Wide(words)
+    }
+
+    /// Schoolbook multiplication
+    pub(crate) fn mul(&self, other: &Self) -> Self {
+        let (len_a, len_b) = (self.len(), other.len());
+        assert!(len_a + len_b <= WORDS, "value doesn't fit in wide integer");
+        let mut words = [0; WORDS];
+        for (i, a) in self.0[..len_a].iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, b) in other.0[..len_b].iter().enumerate() {
+                let t = (*a as u128) * (*b as u128) + words[i + j] as u128 + carry;
+                words[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            words[i + len_b] = carry as u64;
+        }
+        Wide(words)
+    }
+
+    /// Schoolbook long division following Knuth's Algorithm D. Returns
+    /// quotient and remainder.
+    pub(crate) fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let (m, n) = (self.len(), divisor.len());
+        assert!(n != 0, "division by zero");
+        if m < n {
+            return (Wide::zero(), *self);
+        }
+
+        let mut quotient = [0; WORDS];
+
+        // Single word divisor
+        if n == 1 {
+            let d = divisor.0[0] as u128;
+            let mut rem = 0u128;
+            for (q, word) in quotient[..m].iter_mut().zip(self.0[..m].iter()).rev() {
+                let t = (rem << 64) | *word as u128;
+                *q = (t / d) as u64;
+                rem = t % d;
+            }
+            let mut remainder = Wide::zero();
+            remainder.0[0] = rem as u64;
+            return (Wide(quotient), remainder);
+        }
+
+        // Normalize so that the most significant word of the divisor has its
+        // top bit set
+        let shift = divisor.0[n - 1].leading_zeros();
+        let shl = |words: &[u64], out: &mut [u64]| {
+            let mut carry = 0;
+            for (word, out) in words.iter().zip(out.iter_mut()) {
+                *out = (word << shift) | carry;
+                carry = if shift == 0 { 0 } else { word >> (64 - shift) };
+            }
+            carry
+        };
+        let mut v = [0u64; WORDS];
+        shl(&divisor.0[..n], &mut v[..n]);
+        let mut u = [0u64; WORDS + 1];
+        let carry = shl(&self.0[..m], &mut u[..m]);
+        u[m] = carry;
+
+        let base = 1u128 << 64;
+        for j in (0..=m - n).rev() {
+            // Estimate the quotient word from the top two words
+            let num = ((u[j + n] as u128) << 64) | u[j + n - 1] as u128;
+            let mut q_hat = num / v[n - 1] as u128;
+            let mut r_hat = num % v[n - 1] as u128;
+            while q_hat >= base || q_hat * v[n - 2] as u128 > ((r_hat << 64) | u[j + n - 2] as u128)
+            {
+                q_hat -= 1;
+                r_hat += v[n - 1] as u128;
+                if r_hat >= base {
+                    break;
+                }
+            }
+
+            // Multiply and subtract
+            let mut borrow = 0i128;
+            let mut carry = 0u128;
+            for (i, v_i) in v[..n].iter().enumerate() {
+                let p = q_hat * *v_i as u128 + carry;
+                carry = p >> 64;
+                let t = u[i + j] as i128 - borrow - (p as u64) as i128;
+                u[i + j] = t as u64;
+                borrow = (t < 0) as i128;
+            }
+            let t = u[j + n] as i128 - borrow - carry as i128;
+            u[j + n] = t as u64;
+
+            // Estimation was one too large, add back
+            if t < 0 {
+                q_hat -= 1;
+                let mut carry = 0u128;
+                for (i, v_i) in v[..n].iter().enumerate() {
+                    let t = u[i + j] as u128 + *v_i as u128 + carry;
+                    u[i + j] = t as u64;
+                    carry = t >> 64;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u64);
+            }
+            quotient[j] = q_hat as u64;
+        }
+
+        // Denormalize the remainder
+        let mut remainder = Wide::zero();
+        for (i, word) in remainder.0[..n].iter_mut().enumerate() {
+            *word = match shift {
+                0 => u[i],
+                _ => (u[i] >> shift) | (u[i + 1] << (64 - shift)),
+            };
+        }
+        (Wide(quotient), remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Wide, WORDS};
+    use crate::curves::bn256::Fr;
+    use maingate::{big_to_fe, compose, decompose_big, fe_to_big, halo2::ff::Field};
+    use num_bigint::{BigUint as big_uint, RandomBits};
+    use num_integer::Integer as _;
+    use num_traits::One;
+    use rand::Rng;
+    use rand_core::OsRng;
+
+    fn rand_big(bit_len: u64) -> big_uint {
+        OsRng.sample(RandomBits::new(bit_len))
+    }
+
+    #[test]
+    fn test_wide_arithmetic_against_big_uint() {
+        let bit_lens = [1, 63, 64, 65, 128, 254, 256, 300, 512, 900, 1500];
+        for _ in 0..10 {
+            for a_bit_len in bit_lens {
+                for b_bit_len in bit_lens {
+                    let a = rand_big(a_bit_len);
+                    let b = rand_big(b_bit_len);
+                    let (a_wide, b_wide) = (Wide::from_big(&a), Wide::from_big(&b));
+                    assert_eq!(a_wide.to_big(), a);
+                    assert_eq!(a_wide.bits() as u64, a.bits());
+
+                    assert_eq!(a_wide.add(&b_wide).to_big(), &a + &b);
+                    if a >= b {
+                        assert_eq!(a_wide.sub(&b_wide).to_big(), &a - &b);
+                    }
+                    assert_eq!(a_wide.mul(&b_wide).to_big(), &a * &b);
+
+                    let ab = &a * &b;
+                    for divisor in [&a, &b] {
+                        if divisor.bits() == 0 {
+                            continue;
+                        }
+                        let (q, r) = Wide::from_big(&ab).div_rem(&Wide::from_big(divisor));
+                        let (q_expected, r_expected) = ab.div_rem(divisor);
+                        assert_eq!(q.to_big(), q_expected);
+                        assert_eq!(r.to_big(), r_expected);
+                    }
+                }
+            }
+        }
+
+        // Estimated quotient words that must be corrected
+        let one = &big_uint::one();
+        let a = (one << (64 * 6)) - one;
+        let b = (one << (64 * 3)) - (one << 64) + one;
+        let (q, r) = Wide::from_big(&a).div_rem(&Wide::from_big(&b));
+        assert_eq!((q.to_big(), r.to_big()), a.div_rem(&b));
+
+        let max = (one << (64 * WORDS)) - one;
+        let (q, r) = Wide::from_big(&max).div_rem(&Wide::from_big(&(one << 64)));
+        assert_eq!((q.to_big(), r.to_big()), max.div_rem(&(one << 64)));
+    }
+
+    #[test]
+    fn test_wide_limbs_against_big_uint() {
+        for bit_len in [17, 64, 68, 88, 120] {
+            // Limbs wider than the bit length overlap
+            let limbs = (0..6).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+            let e = Wide::compose(&limbs, bit_len);
+            assert_eq!(
+                e.to_big(),
+                compose(limbs.iter().map(|limb| fe_to_big(*limb)).collect(), bit_len)
+            );
+
+            let e = rand_big(600);
+            let limbs: [Fr; 6] = Wide::from_big(&e).decompose(bit_len);
+            assert_eq!(limbs.to_vec(), decompose_big::<Fr>(e, 6, bit_len));
+        }
+
+        let e = rand_big(60);
+        let limbs: [Fr; 1] = Wide::from_big(&e).decompose(68);
+        assert_eq!(limbs[0], big_to_fe(e));
+    }
+}
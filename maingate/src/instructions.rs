@@ -1000,6 +1000,141 @@ pub trait MainGateInstructions<F: PrimeField, const WIDTH: usize>: Chip<F> {
         Ok(bits)
     }
 
+    /// Assigns a new witness `r = a^exponent` where exponent is a constant
+    /// given as little endian 64 bit words as in `Field::pow`. Multiplications
+    /// follow a sliding window addition chain so that each step costs a single
+    /// row.
+    fn pow_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        exponent: &[u64],
+    ) -> Result<AssignedValue<F>, Error> {
+        let chain = addition_chain(exponent);
+        if chain.is_empty() {
+            // `a^0 = 1`, and for `a^1` chain is also empty
+            return if exponent.iter().all(|word| *word == 0) {
+                self.assign_constant(ctx, F::ONE)
+            } else {
+                Ok(a.clone())
+            };
+        }
+
+        let mut powers = vec![a.clone()];
+        for (i, j) in chain.into_iter() {
+            let power = self.mul(ctx, &powers[i], &powers[j])?;
+            powers.push(power);
+        }
+        Ok(powers.pop().unwrap())
+    }
+
+    /// Assigns a new witness `r = a^e` where `e` is given as little endian
+    /// assigned bits, for example as the output of `to_bits`.
+    fn pow_bits(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+        bits: &[AssignedCondition<F>],
+    ) -> Result<AssignedValue<F>, Error> {
+        // Right to left square and multiply. At each step `a^(2^i)` is
+        // multiplied into the accumulator if `bit_i = 1`, otherwise the
+        // accumulator is multiplied by one.
+        let mut acc: Option<AssignedValue<F>> = None;
+        let mut base = a.clone();
+        for (i, bit) in bits.iter().enumerate() {
+            let factor = self.select_or_assign(ctx, &base, F::ONE, bit)?;
+            acc = Some(match acc {
+                Some(acc) => self.mul(ctx, &acc, &factor)?,
+                None => factor,
+            });
+            if i != bits.len() - 1 {
+                base = self.mul(ctx, &base, &base)?;
+            }
+        }
+        match acc {
+            Some(acc) => Ok(acc),
+            None => self.assign_constant(ctx, F::ONE),
+        }
+    }
+
+    /// Returns a bit flag `c` and a witness `r` such that `r^2 = a` if `c = 1`
+    /// or `r^2 = a * z` if `c = 0` where `z` is the multiplicative generator of
+    /// the field which is a quadratic non residue. Since `a * z` is a square
+    /// only if `a` is a non residue, `c = 0` proves that `a` has no square
+    /// root.
+    fn sqrt(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+    ) -> Result<(AssignedCondition<F>, AssignedValue<F>), Error> {
+        let z = F::MULTIPLICATIVE_GENERATOR;
+
+        let (c, r) = a
+            .value()
+            .map(|a| {
+                Option::<F>::from(a.sqrt())
+                    .map(|r| (F::ONE, r))
+                    .unwrap_or_else(|| {
+                        // Non residue times non residue is a residue
+                        let r = Option::<F>::from((*a * z).sqrt()).unwrap_or(F::ZERO);
+                        (F::ZERO, r)
+                    })
+            })
+            .unzip();
+
+        let c = self.assign_bit(ctx, c)?;
+        let ac = self.mul(ctx, a, &c)?;
+
+        // r^2 = a * (z + (1 - z) * c)
+        // r * r - z * a - (1 - z) * a * c = 0
+
+        // Witness layout:
+        // | A | B | C | D  |
+        // | - | - | - | -- |
+        // | r | r | a | ac |
+
+        let assigned = self.apply(
+            ctx,
+            [
+                Term::unassigned_to_mul(r),
+                Term::unassigned_to_mul(r),
+                Term::Assigned(a, -z),
+                Term::Assigned(&ac, z - F::ONE),
+            ],
+            F::ZERO,
+            CombinationOptionCommon::OneLinerMul.into(),
+        )?;
+        ctx.constrain_equal(assigned[0].cell(), assigned[1].cell())?;
+
+        // Zero is a square so `c = 0` must not be accepted for `a = 0`
+        // t = a - a * c + c
+        // t = 1 if c = 1 and t = a if c = 0
+        let t = self.compose(
+            ctx,
+            &[
+                Term::assigned_to_add(a),
+                Term::assigned_to_sub(&ac),
+                Term::assigned_to_add(&c),
+            ],
+            F::ZERO,
+        )?;
+        self.assert_not_zero(ctx, &t)?;
+
+        let r = assigned[0].clone();
+        Ok((c, r))
+    }
+
+    /// Assigns new bit flag `1` if given value is a quadratic residue
+    /// otherwise assigns `0`. Zero is considered as a square.
+    fn is_square(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &AssignedValue<F>,
+    ) -> Result<AssignedCondition<F>, Error> {
+        let (is_square, _) = self.sqrt(ctx, a)?;
+        Ok(is_square)
+    }
+
     /// Assigns a new witness composed of given array of terms
     /// `result = constant + term_0 + term_1 + ... `
     /// where `term_i = a_i * q_i`
@@ -1187,3 +1322,114 @@ pub trait MainGateInstructions<F: PrimeField, const WIDTH: usize>: Chip<F> {
         Ok(())
     }
 }
+
+/// Returns an addition chain for the given little endian exponent as pairs of
+/// indices where `i`th step multiplies elements at given indices and appends
+/// the result as element `i + 1`. Element `0` is the base and the last element
+/// is the result. Chain is built with sliding windows where window size is
+/// chosen to minimize the number of steps and only used odd powers are
+/// precomputed. An empty chain is returned for `0` and `1` exponents.
+pub fn addition_chain(exponent: &[u64]) -> Vec<(usize, usize)> {
+    let bit = |i: usize| (exponent[i / 64] >> (i % 64)) & 1 == 1;
+    let bit_len = match exponent.iter().rposition(|word| *word != 0) {
+        Some(i) => 64 * (i + 1) - exponent[i].leading_zeros() as usize,
+        None => return vec![],
+    };
+
+    // Split the exponent into windows that start and end with a set bit
+    let windows = |window_size: usize| {
+        let mut windows = vec![];
+        let mut i = bit_len;
+        while i > 0 {
+            if !bit(i - 1) {
+                i -= 1;
+                continue;
+            }
+            let mut j = i.saturating_sub(window_size);
+            while !bit(j) {
+                j += 1;
+            }
+            let value = (j..i)
+                .rev()
+                .fold(0usize, |acc, k| (acc << 1) | bit(k) as usize);
+            // (value, position of the lowest bit of the window)
+            windows.push((value, j));
+            i = j;
+        }
+        windows
+    };
+    // Number of steps is the number of squarings, precomputations and window
+    // multiplications. Squarings follow the lowest bit of the first window
+    let cost = |windows: &[(usize, usize)]| {
+        let max_value = windows.iter().map(|(value, _)| *value).max().unwrap();
+        let precomputation = if max_value > 1 { max_value / 2 + 1 } else { 0 };
+        windows[0].1 + precomputation + windows.len() - 1
+    };
+    let windows = (1..=6)
+        .map(windows)
+        .min_by_key(|windows| cost(windows))
+        .unwrap();
+
+    let mut chain = vec![];
+    // odd_powers[k] is the index of `a^(2k+1)`
+    let mut odd_powers = vec![0];
+    let max_value = windows.iter().map(|(value, _)| *value).max().unwrap();
+    if max_value > 1 {
+        chain.push((0, 0));
+        let square = chain.len();
+        while 2 * odd_powers.len() - 1 < max_value {
+            chain.push((*odd_powers.last().unwrap(), square));
+            odd_powers.push(chain.len());
+        }
+    }
+
+    let (value, mut position) = windows[0];
+    let mut acc = odd_powers[value / 2];
+    for (value, next_position) in windows.into_iter().skip(1) {
+        for _ in next_position..position {
+            chain.push((acc, acc));
+            acc = chain.len();
+        }
+        chain.push((acc, odd_powers[value / 2]));
+        acc = chain.len();
+        position = next_position;
+    }
+    for _ in 0..position {
+        chain.push((acc, acc));
+        acc = chain.len();
+    }
+
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::addition_chain;
+    use num_bigint::{BigUint as big_uint, RandBigInt};
+    use num_traits::One;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_addition_chain() {
+        let exponents = [0u64, 1, 2, 3, 5, 7, 8, 255, 256, 65537]
+            .into_iter()
+            .map(big_uint::from)
+            .chain((0..20).map(|_| OsRng.gen_biguint(256)))
+            .collect::<Vec<_>>();
+        for exponent in exponents {
+            let chain = addition_chain(&exponent.to_u64_digits());
+            let mut elements = vec![big_uint::one()];
+            for (i, j) in chain.iter() {
+                assert!(*i < elements.len() && *j < elements.len());
+                elements.push(&elements[*i] + &elements[*j]);
+            }
+            if exponent.bits() > 0 {
+                assert_eq!(*elements.last().unwrap(), exponent);
+            }
+        }
+        // Plain square and multiply for e = 65537
+        assert_eq!(addition_chain(&[65537]).len(), 17);
+        // Leading zero words are ignored
+        assert_eq!(addition_chain(&[5, 0, 0]), addition_chain(&[5]));
+    }
+}
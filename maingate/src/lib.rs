@@ -10,11 +10,15 @@ use halo2wrong::halo2::circuit::AssignedCell;
 #[macro_use]
 mod instructions;
 mod main_gate;
+mod permutation;
+mod public_input;
 mod range;
 
 pub use halo2wrong::{halo2, utils::*, RegionCtx};
-pub use instructions::{CombinationOptionCommon, MainGateInstructions, Term};
+pub use instructions::{addition_chain, CombinationOptionCommon, MainGateInstructions, Term};
 pub use main_gate::*;
+pub use permutation::*;
+pub use public_input::*;
 pub use range::*;
 
 #[cfg(test)]
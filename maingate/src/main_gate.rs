@@ -534,7 +534,7 @@ mod tests {
     use halo2wrong::halo2::ff::PrimeField;
     use halo2wrong::utils::{big_to_fe, decompose};
     use halo2wrong::RegionCtx;
-    use rand_core::OsRng;
+    use rand_core::{OsRng, RngCore};
     use std::marker::PhantomData;
 
     #[derive(Clone)]
@@ -1572,4 +1572,104 @@ mod tests {
         };
         assert_eq!(prover.verify(), Ok(()));
     }
+
+    #[derive(Default)]
+    struct TestCircuitPowSqrt<F: PrimeField> {
+        _marker: PhantomData<F>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuitPowSqrt<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = config.main_gate();
+
+            let rand = || -> F { F::random(OsRng) };
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // pow with constant exponents
+                    let a = rand();
+                    let a_assigned = main_gate.assign_value(ctx, Value::known(a))?;
+                    let exponents: Vec<Vec<u64>> = vec![
+                        vec![0],
+                        vec![1],
+                        vec![2],
+                        vec![5],
+                        vec![0xffff],
+                        vec![OsRng.next_u64()],
+                        (0..4).map(|_| OsRng.next_u64()).collect(),
+                    ];
+                    for exponent in exponents.iter() {
+                        let r_0 = main_gate.pow_constant(ctx, &a_assigned, exponent)?;
+                        let r_1 = main_gate.assign_value(ctx, Value::known(a.pow(exponent)))?;
+                        main_gate.assert_equal(ctx, &r_0, &r_1)?;
+                    }
+
+                    // pow with assigned exponent
+                    let e = OsRng.next_u64();
+                    let e_assigned = main_gate.assign_value(ctx, Value::known(F::from(e)))?;
+                    let bits = main_gate.to_bits(ctx, &e_assigned, 64)?;
+                    let r_0 = main_gate.pow_bits(ctx, &a_assigned, &bits)?;
+                    let r_1 = main_gate.assign_value(ctx, Value::known(a.pow([e])))?;
+                    main_gate.assert_equal(ctx, &r_0, &r_1)?;
+
+                    // square root of a square
+                    let a = rand().square();
+                    let a = main_gate.assign_value(ctx, Value::known(a))?;
+                    let (is_square, r) = main_gate.sqrt(ctx, &a)?;
+                    main_gate.assert_one(ctx, &is_square)?;
+                    let r_square = main_gate.mul(ctx, &r, &r)?;
+                    main_gate.assert_equal(ctx, &r_square, &a)?;
+
+                    // non residue
+                    let a = rand().square() * F::MULTIPLICATIVE_GENERATOR;
+                    let a = main_gate.assign_value(ctx, Value::known(a))?;
+                    let is_square = main_gate.is_square(ctx, &a)?;
+                    main_gate.assert_zero(ctx, &is_square)?;
+
+                    // zero is a square
+                    let a = main_gate.assign_value(ctx, Value::known(F::ZERO))?;
+                    let is_square = main_gate.is_square(ctx, &a)?;
+                    main_gate.assert_one(ctx, &is_square)?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_pow_sqrt() {
+        const K: u32 = 12;
+        let circuit = TestCircuitPowSqrt::<Fp> {
+            _marker: PhantomData::<Fp>,
+        };
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(K, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+    }
 }
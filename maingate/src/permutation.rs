@@ -0,0 +1,480 @@
+use super::main_gate::{MainGate, MainGateConfig};
+use super::range::{RangeChip, RangeConfig, RangeInstructions};
+use crate::halo2::circuit::{Chip, Layouter, Value};
+use crate::halo2::plonk::{
+    Advice, Challenge, Column, ConstraintSystem, Error, FirstPhase, SecondPhase, Selector,
+};
+use crate::halo2::poly::Rotation;
+use crate::instructions::{MainGateInstructions, Term};
+use crate::AssignedValue;
+use halo2wrong::halo2::ff::PrimeField;
+use halo2wrong::utils::fe_to_big;
+use halo2wrong::RegionCtx;
+
+/// Permutation gate configuration
+#[derive(Clone, Debug)]
+pub struct PermutationConfig {
+    range_config: RangeConfig,
+    z: Column<Advice>,
+    s_product: Selector,
+    gamma: Challenge,
+}
+
+/// ['PermutationChip'] proves that a vector of assigned values is a
+/// permutation of another one. It is a grand product argument where the
+/// running product is accumulated in a second phase column as
+/// `z_{i+1} * (b_i + gamma) = z_i * (a_i + gamma)` and `gamma` is a challenge
+/// drawn after main gate columns are committed. Sortedness is proven with
+/// range checked differences of consecutive values.
+#[derive(Clone, Debug)]
+pub struct PermutationChip<F: PrimeField> {
+    config: PermutationConfig,
+    main_gate: MainGate<F>,
+    range_chip: RangeChip<F>,
+    gamma: Value<F>,
+}
+
+impl<F: PrimeField> Chip<F> for PermutationChip<F> {
+    type Config = PermutationConfig;
+    type Loaded = ();
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Instructions for permutation and sorting arguments
+pub trait PermutationInstructions<F: PrimeField>: Chip<F> {
+    /// Enforces that `b` is a permutation of `a`
+    fn assert_permutation(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &[AssignedValue<F>],
+        b: &[AssignedValue<F>],
+    ) -> Result<(), Error>;
+
+    /// Assigns `values` in the order given by `permutation` such that the
+    /// result at `i` is `values[permutation[i]]` and proves that result is a
+    /// permutation of the input
+    fn permute(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        values: &[AssignedValue<F>],
+        permutation: Value<Vec<usize>>,
+    ) -> Result<Vec<AssignedValue<F>>, Error>;
+
+    /// Enforces that values are in ascending order where each value is
+    /// expected to fit in `bit_len` bits. If `strict` is set equal values are
+    /// not allowed.
+    fn assert_sorted(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        values: &[AssignedValue<F>],
+        limb_bit_len: usize,
+        bit_len: usize,
+        strict: bool,
+    ) -> Result<(), Error>;
+
+    /// Assigns the sorted version of given values and proves that it is a
+    /// sorted permutation of the input. If `strict` is set the sorted values
+    /// must be distinct.
+    fn sort(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        values: &[AssignedValue<F>],
+        limb_bit_len: usize,
+        bit_len: usize,
+        strict: bool,
+    ) -> Result<Vec<AssignedValue<F>>, Error>;
+
+    /// Enforces that there are no duplicates in given values
+    fn assert_unique(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        values: &[AssignedValue<F>],
+        limb_bit_len: usize,
+        bit_len: usize,
+    ) -> Result<(), Error> {
+        self.sort(ctx, values, limb_bit_len, bit_len, true)?;
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> PermutationInstructions<F> for PermutationChip<F> {
+    fn assert_permutation(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        a: &[AssignedValue<F>],
+        b: &[AssignedValue<F>],
+    ) -> Result<(), Error> {
+        assert_eq!(a.len(), b.len());
+        if a.is_empty() {
+            return Ok(());
+        }
+        let &MainGateConfig {
+            a: column_a,
+            b: column_b,
+            ..
+        } = &self.config.range_config.main_gate_config;
+
+        // Grand product starts from and must end at one
+        let one = self.main_gate.assign_constant(ctx, F::ONE)?;
+
+        // Witness layout:
+        // | A   | B   | Z       |
+        // | --- | --- | ------- |
+        // | a_0 | b_0 | z_0 = 1 |
+        // | a_1 | b_1 | z_1     |
+        // | ... | ... | ...     |
+        // | -   | -   | z_n = 1 |
+
+        let mut z = Value::known(F::ONE);
+        for (i, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+            let a_copy = ctx.assign_advice(|| "a", column_a, a.value().copied())?;
+            ctx.constrain_equal(a.cell(), a_copy.cell())?;
+            let b_copy = ctx.assign_advice(|| "b", column_b, b.value().copied())?;
+            ctx.constrain_equal(b.cell(), b_copy.cell())?;
+
+            let z_cur = ctx.assign_advice(|| "z", self.config.z, z)?;
+            if i == 0 {
+                ctx.constrain_equal(z_cur.cell(), one.cell())?;
+            }
+            ctx.enable(self.config.s_product)?;
+
+            z = z
+                .zip(a.value())
+                .zip(b.value())
+                .zip(self.gamma)
+                .map(|(((z, a), b), gamma)| {
+                    // Division by zero happens with negligible probability
+                    z * (*a + gamma) * (*b + gamma).invert().unwrap_or(F::ZERO)
+                });
+            ctx.next();
+        }
+        let z_last = ctx.assign_advice(|| "z", self.config.z, z)?;
+        ctx.constrain_equal(z_last.cell(), one.cell())?;
+        ctx.next();
+
+        Ok(())
+    }
+
+    fn permute(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        values: &[AssignedValue<F>],
+        permutation: Value<Vec<usize>>,
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        let permuted = permutation
+            .zip(
+                values
+                    .iter()
+                    .map(|value| value.value().copied())
+                    .collect::<Value<Vec<F>>>(),
+            )
+            .map(|(permutation, values)| {
+                assert_eq!(permutation.len(), values.len());
+                permutation.iter().map(|i| values[*i]).collect::<Vec<_>>()
+            })
+            .transpose_vec(values.len());
+
+        let permuted = permuted
+            .into_iter()
+            .map(|value| self.main_gate.assign_value(ctx, value))
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.assert_permutation(ctx, values, &permuted)?;
+        Ok(permuted)
+    }
+
+    fn assert_sorted(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        values: &[AssignedValue<F>],
+        limb_bit_len: usize,
+        bit_len: usize,
+        strict: bool,
+    ) -> Result<(), Error> {
+        if values.is_empty() {
+            return Ok(());
+        }
+        // First value is ranged and each difference adds at most `bit_len` bits
+        // so that the largest value must not wrap around the modulus
+        let max_bit_len = bit_len + (usize::BITS - values.len().leading_zeros()) as usize;
+        assert!(max_bit_len < F::NUM_BITS as usize);
+
+        let first =
+            self.range_chip
+                .assign(ctx, values[0].value().copied(), limb_bit_len, bit_len)?;
+        self.main_gate.assert_equal(ctx, &first, &values[0])?;
+
+        // next - prev - strict = dif
+        // where dif is in [0, 2^bit_len)
+        let strict = if strict { F::ONE } else { F::ZERO };
+        for window in values.windows(2) {
+            let (prev, next) = (&window[0], &window[1]);
+            let dif = next
+                .value()
+                .zip(prev.value())
+                .map(|(next, prev)| *next - *prev - strict);
+            let dif = self.range_chip.assign(ctx, dif, limb_bit_len, bit_len)?;
+            self.main_gate.assert_zero_sum(
+                ctx,
+                &[
+                    Term::assigned_to_add(next),
+                    Term::assigned_to_sub(prev),
+                    Term::assigned_to_sub(&dif),
+                ],
+                -strict,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn sort(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        values: &[AssignedValue<F>],
+        limb_bit_len: usize,
+        bit_len: usize,
+        strict: bool,
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        let permutation = values
+            .iter()
+            .map(|value| value.value().copied())
+            .collect::<Value<Vec<F>>>()
+            .map(|values| {
+                let mut permutation = (0..values.len()).collect::<Vec<_>>();
+                permutation.sort_by_key(|i| fe_to_big(values[*i]));
+                permutation
+            });
+        let sorted = self.permute(ctx, values, permutation)?;
+        self.assert_sorted(ctx, &sorted, limb_bit_len, bit_len, strict)?;
+        Ok(sorted)
+    }
+}
+
+impl<F: PrimeField> PermutationChip<F> {
+    /// Given config creates new chip that implements permutation arguments.
+    /// Challenge must be loaded with `load_challenge` in synthesis time before
+    /// using the chip.
+    pub fn new(config: PermutationConfig) -> Self {
+        let main_gate = MainGate::new(config.range_config.main_gate_config.clone());
+        let range_chip = RangeChip::new(config.range_config.clone());
+        Self {
+            config,
+            main_gate,
+            range_chip,
+            gamma: Value::unknown(),
+        }
+    }
+
+    /// Fetches the grand product challenge from the layouter
+    pub fn load_challenge(&mut self, layouter: &impl Layouter<F>) {
+        self.gamma = layouter.get_challenge(self.config.gamma);
+    }
+
+    /// Configures the grand product gate and returns the resulting config.
+    /// Values are copied into first two advice columns of the main gate while
+    /// running product is kept in a new second phase advice column.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        range_config: &RangeConfig,
+    ) -> PermutationConfig {
+        let &MainGateConfig { a, b, .. } = &range_config.main_gate_config;
+
+        let z = meta.advice_column_in(SecondPhase);
+        meta.enable_equality(z);
+        let s_product = meta.selector();
+        let gamma = meta.challenge_usable_after(FirstPhase);
+
+        meta.create_gate("permutation_product", |meta| {
+            let s_product = meta.query_selector(s_product);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let z_next = meta.query_advice(z, Rotation::next());
+            let z = meta.query_advice(z, Rotation::cur());
+            let gamma = meta.query_challenge(gamma);
+
+            vec![s_product * (z_next * (b + gamma.clone()) - z * (a + gamma))]
+        });
+
+        PermutationConfig {
+            range_config: range_config.clone(),
+            z,
+            s_product,
+            gamma,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PermutationChip, PermutationConfig, PermutationInstructions};
+    use crate::curves::pasta::Fp;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::dev::{MockProver, VerifyFailure};
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::main_gate::MainGate;
+    use crate::range::{RangeChip, RangeInstructions};
+    use crate::MainGateInstructions;
+    use halo2wrong::halo2::ff::PrimeField;
+    use halo2wrong::RegionCtx;
+    use rand::seq::SliceRandom;
+    use rand_core::{OsRng, RngCore};
+
+    const LIMB_BIT_LEN: usize = 8;
+    const BIT_LEN: usize = 32;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        permutation_config: PermutationConfig,
+    }
+
+    impl TestCircuitConfig {
+        fn new<F: PrimeField>(meta: &mut ConstraintSystem<F>) -> Self {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            let range_config =
+                RangeChip::<F>::configure(meta, &main_gate_config, vec![LIMB_BIT_LEN], vec![]);
+            let permutation_config = PermutationChip::<F>::configure(meta, &range_config);
+            Self { permutation_config }
+        }
+
+        fn main_gate<F: PrimeField>(&self) -> MainGate<F> {
+            MainGate::<F>::new(
+                self.permutation_config
+                    .range_config
+                    .main_gate_config
+                    .clone(),
+            )
+        }
+
+        fn range_chip<F: PrimeField>(&self) -> RangeChip<F> {
+            RangeChip::<F>::new(self.permutation_config.range_config.clone())
+        }
+
+        fn permutation_chip<F: PrimeField>(&self) -> PermutationChip<F> {
+            PermutationChip::<F>::new(self.permutation_config.clone())
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuit<F: PrimeField> {
+        values: Vec<Value<F>>,
+        permutation: Value<Vec<usize>>,
+        duplicate_free: bool,
+        tampered: bool,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuit<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                values: vec![Value::unknown(); self.values.len()],
+                permutation: Value::unknown(),
+                duplicate_free: self.duplicate_free,
+                tampered: self.tampered,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            TestCircuitConfig::new(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = config.main_gate();
+            let range_chip = config.range_chip();
+            let mut permutation_chip = config.permutation_chip();
+            permutation_chip.load_challenge(&layouter);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let values = self
+                        .values
+                        .iter()
+                        .map(|value| main_gate.assign_value(ctx, *value))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    let shuffled =
+                        permutation_chip.permute(ctx, &values, self.permutation.clone())?;
+                    let sorted_0 =
+                        permutation_chip.sort(ctx, &values, LIMB_BIT_LEN, BIT_LEN, false)?;
+                    let sorted_1 =
+                        permutation_chip.sort(ctx, &shuffled, LIMB_BIT_LEN, BIT_LEN, false)?;
+                    for (sorted_0, sorted_1) in sorted_0.iter().zip(sorted_1.iter()) {
+                        main_gate.assert_equal(ctx, sorted_0, sorted_1)?;
+                    }
+
+                    if self.duplicate_free {
+                        permutation_chip.assert_unique(ctx, &values, LIMB_BIT_LEN, BIT_LEN)?;
+                    }
+
+                    // Shuffled values with a single value changed
+                    if self.tampered {
+                        let mut tampered = shuffled.clone();
+                        tampered[0] = main_gate.add_constant(ctx, &tampered[0], F::ONE)?;
+                        permutation_chip.assert_permutation(ctx, &values, &tampered)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn run(
+        values: Vec<u64>,
+        duplicate_free: bool,
+        tampered: bool,
+    ) -> Result<(), Vec<VerifyFailure>> {
+        let k: u32 = (LIMB_BIT_LEN + 2) as u32;
+        let mut permutation = (0..values.len()).collect::<Vec<_>>();
+        permutation.shuffle(&mut OsRng);
+
+        let circuit = TestCircuit::<Fp> {
+            values: values
+                .into_iter()
+                .map(|value| Value::known(Fp::from(value)))
+                .collect(),
+            permutation: Value::known(permutation),
+            duplicate_free,
+            tampered,
+        };
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        prover.verify()
+    }
+
+    #[test]
+    fn test_permutation_and_sort() {
+        let values = (0..20).map(|_| OsRng.next_u32() as u64).collect::<Vec<_>>();
+        assert_eq!(run(values.clone(), true, false), Ok(()));
+
+        // not a permutation
+        assert!(run(values, false, true).is_err());
+
+        // duplicates are fine for sorting but not for uniqueness
+        let mut values = (0..20).map(|_| OsRng.next_u32() as u64).collect::<Vec<_>>();
+        values[7] = values[3];
+        assert_eq!(run(values.clone(), false, false), Ok(()));
+        assert!(run(values, true, false).is_err());
+    }
+}
@@ -0,0 +1,394 @@
+use super::main_gate::MainGateConfig;
+use crate::halo2::circuit::{Chip, Layouter};
+use crate::halo2::plonk::{Column, ConstraintSystem, Error, Instance};
+use crate::AssignedValue;
+use halo2wrong::halo2::ff::PrimeField;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+/// Public input configuration. First instance column is the one that belongs
+/// to the main gate and the rest are allocated by
+/// [`PublicInputChip::configure`]
+#[derive(Clone, Debug)]
+pub struct PublicInputConfig {
+    instances: Vec<Column<Instance>>,
+}
+
+impl PublicInputConfig {
+    /// Returns instance columns in the order they appear in the instance
+    /// vector
+    pub fn instances(&self) -> &[Column<Instance>] {
+        &self.instances
+    }
+}
+
+/// Position of a named group of public inputs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicInputSlot {
+    column: usize,
+    offset: usize,
+    len: usize,
+}
+
+impl PublicInputSlot {
+    /// Index of the instance column
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// First row of the slot in the instance column
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Number of cells in the slot
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if slot has no cells
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Describes where each public input lives in the instance columns. Same
+/// layout is used both in synthesis time to constrain assigned cells and
+/// off-circuit to build the instance vector so that row numbers are never
+/// tracked by hand.
+#[derive(Clone, Debug)]
+pub struct PublicInputLayout {
+    heights: Vec<usize>,
+    slots: BTreeMap<String, PublicInputSlot>,
+}
+
+impl PublicInputLayout {
+    /// Creates an empty layout spanning given number of instance columns
+    pub fn new(number_of_columns: usize) -> Self {
+        assert!(number_of_columns > 0);
+        Self {
+            heights: vec![0; number_of_columns],
+            slots: BTreeMap::new(),
+        }
+    }
+
+    /// Number of instance columns layout is defined over
+    pub fn number_of_columns(&self) -> usize {
+        self.heights.len()
+    }
+
+    /// Number of rows used in each instance column
+    pub fn heights(&self) -> &[usize] {
+        &self.heights
+    }
+
+    /// Appends a slot of `len` cells to the given column
+    pub fn allocate(&mut self, name: &str, column: usize, len: usize) -> &mut Self {
+        assert!(
+            column < self.heights.len(),
+            "instance column {column} is not in the layout"
+        );
+        let slot = PublicInputSlot {
+            column,
+            offset: self.heights[column],
+            len,
+        };
+        let existing = self.slots.insert(name.to_string(), slot);
+        assert!(
+            existing.is_none(),
+            "public input slot {name} is already allocated"
+        );
+        self.heights[column] += len;
+        self
+    }
+
+    /// Allocates a slot for a single native field element
+    pub fn value(&mut self, name: &str, column: usize) -> &mut Self {
+        self.allocate(name, column, 1)
+    }
+
+    /// Allocates a slot for limbs of a non native integer
+    pub fn integer(&mut self, name: &str, column: usize, number_of_limbs: usize) -> &mut Self {
+        self.allocate(name, column, number_of_limbs)
+    }
+
+    /// Allocates a slot for limbs of `x` and `y` coordinates of a point in
+    /// that order
+    pub fn point(&mut self, name: &str, column: usize, number_of_limbs: usize) -> &mut Self {
+        self.allocate(name, column, 2 * number_of_limbs)
+    }
+
+    /// Returns the slot allocated with the given name
+    pub fn slot(&self, name: &str) -> Option<PublicInputSlot> {
+        self.slots.get(name).copied()
+    }
+
+    fn expect_slot(&self, name: &str, len: usize) -> PublicInputSlot {
+        let slot = self
+            .slot(name)
+            .unwrap_or_else(|| panic!("public input slot {name} is not allocated"));
+        assert_eq!(
+            slot.len, len,
+            "public input slot {name} expects {} values",
+            slot.len
+        );
+        slot
+    }
+
+    /// Starts building the off-circuit instance vector for this layout
+    pub fn public_inputs<F: PrimeField>(&self) -> PublicInputs<'_, F> {
+        PublicInputs {
+            layout: self,
+            values: self
+                .heights
+                .iter()
+                .map(|height| vec![F::ZERO; *height])
+                .collect(),
+        }
+    }
+}
+
+/// Off-circuit counterpart of [`PublicInputLayout`] which places values into
+/// the instance vector. Slots that are not set are left as zero.
+#[derive(Clone, Debug)]
+pub struct PublicInputs<'a, F: PrimeField> {
+    layout: &'a PublicInputLayout,
+    values: Vec<Vec<F>>,
+}
+
+impl<'a, F: PrimeField> PublicInputs<'a, F> {
+    /// Writes values into the named slot. For non native integers values are
+    /// expected to be limbs as in `Integer::limbs` and for points as in
+    /// `Point::public`.
+    pub fn set(&mut self, name: &str, values: &[F]) -> &mut Self {
+        let slot = self.layout.expect_slot(name, values.len());
+        self.values[slot.column][slot.offset..slot.offset + slot.len].copy_from_slice(values);
+        self
+    }
+
+    /// Returns instance vector to be given to the prover and the verifier
+    pub fn instances(&self) -> Vec<Vec<F>> {
+        self.values.clone()
+    }
+}
+
+/// ['PublicInputChip'] constrains assigned values to the instance columns
+/// following a [`PublicInputLayout`]
+#[derive(Clone, Debug)]
+pub struct PublicInputChip<F: PrimeField> {
+    config: PublicInputConfig,
+    layout: PublicInputLayout,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> Chip<F> for PublicInputChip<F> {
+    type Config = PublicInputConfig;
+    type Loaded = ();
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> PublicInputChip<F> {
+    /// Given config and layout creates new chip
+    pub fn new(config: PublicInputConfig, layout: PublicInputLayout) -> Self {
+        assert!(layout.number_of_columns() <= config.instances.len());
+        Self {
+            config,
+            layout,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns layout of public inputs
+    pub fn layout(&self) -> &PublicInputLayout {
+        &self.layout
+    }
+
+    /// Reuses the instance column of the main gate and allocates more
+    /// instance columns up to `number_of_columns`. Instance vector must follow
+    /// the order of column allocation.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        main_gate_config: &MainGateConfig,
+        number_of_columns: usize,
+    ) -> PublicInputConfig {
+        assert!(number_of_columns > 0);
+        let instances = std::iter::once(main_gate_config.instance)
+            .chain((1..number_of_columns).map(|_| {
+                let instance = meta.instance_column();
+                meta.enable_equality(instance);
+                instance
+            }))
+            .collect();
+        PublicInputConfig { instances }
+    }
+
+    /// Constrains given values to be equal to the public inputs in the named
+    /// slot
+    pub fn expose(
+        &self,
+        mut layouter: impl Layouter<F>,
+        name: &str,
+        values: &[AssignedValue<F>],
+    ) -> Result<(), Error> {
+        let slot = self.layout.expect_slot(name, values.len());
+        let column = self.config.instances[slot.column];
+        for (i, value) in values.iter().enumerate() {
+            layouter.constrain_instance(value.cell(), column, slot.offset + i)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PublicInputChip, PublicInputConfig, PublicInputLayout};
+    use crate::curves::pasta::Fp;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::dev::MockProver;
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::main_gate::{MainGate, MainGateConfig};
+    use crate::MainGateInstructions;
+    use halo2wrong::halo2::ff::{Field, PrimeField};
+    use halo2wrong::RegionCtx;
+    use rand_core::OsRng;
+
+    const NUMBER_OF_COLUMNS: usize = 2;
+    const NUMBER_OF_LIMBS: usize = 4;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+        public_input_config: PublicInputConfig,
+    }
+
+    fn layout() -> PublicInputLayout {
+        let mut layout = PublicInputLayout::new(NUMBER_OF_COLUMNS);
+        layout
+            .value("a", 0)
+            .integer("integer", 1, NUMBER_OF_LIMBS)
+            .value("b", 1)
+            .point("point", 0, NUMBER_OF_LIMBS);
+        layout
+    }
+
+    #[derive(Clone, Debug)]
+    struct TestCircuit<F: PrimeField> {
+        a: Value<F>,
+        b: Value<F>,
+        integer: Vec<Value<F>>,
+        point: Vec<Value<F>>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuit<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                a: Value::unknown(),
+                b: Value::unknown(),
+                integer: vec![Value::unknown(); NUMBER_OF_LIMBS],
+                point: vec![Value::unknown(); 2 * NUMBER_OF_LIMBS],
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            let public_input_config =
+                PublicInputChip::<F>::configure(meta, &main_gate_config, NUMBER_OF_COLUMNS);
+            TestCircuitConfig {
+                main_gate_config,
+                public_input_config,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<F>::new(config.main_gate_config);
+            let public_input_chip = PublicInputChip::<F>::new(config.public_input_config, layout());
+
+            let (a, b, integer, point) = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let mut assign = |values: &[Value<F>]| {
+                        values
+                            .iter()
+                            .map(|value| main_gate.assign_value(ctx, *value))
+                            .collect::<Result<Vec<_>, Error>>()
+                    };
+                    Ok((
+                        assign(&[self.a])?,
+                        assign(&[self.b])?,
+                        assign(&self.integer)?,
+                        assign(&self.point)?,
+                    ))
+                },
+            )?;
+
+            // order of exposure does not matter
+            public_input_chip.expose(layouter.namespace(|| "point"), "point", &point)?;
+            public_input_chip.expose(layouter.namespace(|| "b"), "b", &b)?;
+            public_input_chip.expose(layouter.namespace(|| "integer"), "integer", &integer)?;
+            public_input_chip.expose(layouter.namespace(|| "a"), "a", &a)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_public_input_layout() {
+        const K: u32 = 6;
+
+        let rand = |n: usize| (0..n).map(|_| Fp::random(OsRng)).collect::<Vec<_>>();
+        let (a, b) = (Fp::random(OsRng), Fp::random(OsRng));
+        let (integer, point) = (rand(NUMBER_OF_LIMBS), rand(2 * NUMBER_OF_LIMBS));
+
+        let circuit = TestCircuit::<Fp> {
+            a: Value::known(a),
+            b: Value::known(b),
+            integer: integer.iter().map(|e| Value::known(*e)).collect(),
+            point: point.iter().map(|e| Value::known(*e)).collect(),
+        };
+
+        let layout = layout();
+        let instances = layout
+            .public_inputs()
+            .set("integer", &integer)
+            .set("a", &[a])
+            .set("point", &point)
+            .set("b", &[b])
+            .instances();
+        assert_eq!(instances[0].len(), 1 + 2 * NUMBER_OF_LIMBS);
+        assert_eq!(instances[1].len(), NUMBER_OF_LIMBS + 1);
+
+        let prover = match MockProver::run(K, &circuit, instances) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+
+        // swapped slots must fail
+        let instances = layout
+            .public_inputs()
+            .set("integer", &integer)
+            .set("a", &[b])
+            .set("point", &point)
+            .set("b", &[a])
+            .instances();
+        let prover = match MockProver::run(K, &circuit, instances) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert!(prover.verify().is_err());
+    }
+}
@@ -23,7 +23,7 @@ pub const NUMBER_OF_LOOKUP_LIMBS: usize = 4;
 /// Range gate configuration
 #[derive(Clone, Debug)]
 pub struct RangeConfig {
-    main_gate_config: MainGateConfig,
+    pub(crate) main_gate_config: MainGateConfig,
     bit_len_tag: BTreeMap<usize, usize>,
     t_tag: TableColumn,
     t_value: TableColumn,
@@ -0,0 +1,13 @@
+//! `halo2wrong-rsa` implements constraints for RSA signature verification
+
+#![deny(missing_debug_implementations)]
+#![deny(missing_docs)]
+
+/// Constraints for RSA PKCS#1 v1.5 signature verification
+pub mod rsa;
+
+pub(crate) use integer::halo2;
+pub(crate) use integer::maingate;
+
+#[cfg(test)]
+use halo2::halo2curves as curves;
@@ -0,0 +1,534 @@
+use crate::halo2;
+use crate::maingate;
+use halo2::circuit::{Layouter, Value};
+use halo2::ff::PrimeField;
+use halo2::plonk::Error;
+use integer::{AssignedBigUint, BigUintChip, IntegerConfig};
+use maingate::{
+    big_to_fe, decompose_big, power_of_two, AssignedCondition, AssignedValue, MainGateConfig,
+    MainGateInstructions, RangeConfig, RangeInstructions, RegionCtx, Term,
+};
+use num_bigint::BigUint as big_uint;
+use num_traits::{One, Zero};
+
+/// DER encoded `DigestInfo` prefix of SHA-256 digests
+pub const SHA256_DIGEST_INFO: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+/// Configuration of [`RsaChip`]
+#[derive(Clone, Debug)]
+pub struct RsaConfig {
+    main_gate_config: MainGateConfig,
+    range_config: RangeConfig,
+}
+
+impl RsaConfig {
+    /// Creates a new [`RsaConfig`] from range and main gate configurations
+    pub fn new(range_config: RangeConfig, main_gate_config: MainGateConfig) -> Self {
+        Self {
+            range_config,
+            main_gate_config,
+        }
+    }
+
+    /// Configuration of the underlying integer chips
+    pub fn integer_chip_config(&self) -> IntegerConfig {
+        IntegerConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+    }
+}
+
+/// Public exponent of an RSA key
+#[derive(Clone, Debug)]
+pub enum RsaPublicExponent<N: PrimeField> {
+    /// Exponent that is fixed in the circuit such as 65537
+    Fixed(big_uint),
+    /// Exponent given as assigned bits in little endian order
+    Variable(Vec<AssignedCondition<N>>),
+}
+
+/// Assigned RSA public key
+#[derive(Clone, Debug)]
+pub struct AssignedRsaPublicKey<N: PrimeField, const BIT_LEN_LIMB: usize> {
+    /// Modulus `n` of the key
+    pub modulus: AssignedBigUint<N, BIT_LEN_LIMB>,
+    /// Public exponent `e` of the key
+    pub exponent: RsaPublicExponent<N>,
+}
+
+/// Chip for RSA operations over `NUMBER_OF_LIMBS * BIT_LEN_LIMB` bit moduli
+#[derive(Clone, Debug)]
+pub struct RsaChip<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>(
+    BigUintChip<N, BIT_LEN_LIMB>,
+);
+
+impl<N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    RsaChip<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Creates a new [`RsaChip`] on top of a [`BigUintChip`]
+    pub fn new(big_uint_chip: BigUintChip<N, BIT_LEN_LIMB>) -> Self {
+        Self(big_uint_chip)
+    }
+
+    /// Getter for [`BigUintChip`]
+    pub fn big_uint_chip(&self) -> &BigUintChip<N, BIT_LEN_LIMB> {
+        &self.0
+    }
+
+    /// Assigns modulus as a witness
+    pub fn assign_modulus(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        modulus: Value<big_uint>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        self.0.assign_integer(ctx, modulus, NUMBER_OF_LIMBS)
+    }
+
+    /// Assigns signature as a witness. It is checked to be less than the
+    /// modulus in verification
+    pub fn assign_signature(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        signature: Value<big_uint>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        self.0.assign_integer(ctx, signature, NUMBER_OF_LIMBS)
+    }
+
+    /// Exposes limbs of the assigned modulus as public inputs starting from
+    /// `offset`. Use `RsaChip::modulus_instance` to build the matching public
+    /// inputs off-circuit.
+    pub fn expose_modulus(
+        &self,
+        mut layouter: impl Layouter<N>,
+        modulus: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        offset: usize,
+    ) -> Result<(), Error> {
+        let main_gate = self.0.main_gate();
+        for (i, limb) in modulus.limbs().iter().enumerate() {
+            main_gate.expose_public(
+                layouter.namespace(|| "modulus limbs"),
+                limb.into(),
+                offset + i,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Limbs of the modulus to be set as public inputs
+    pub fn modulus_instance(modulus: &big_uint) -> Vec<N> {
+        decompose_big(modulus.clone(), NUMBER_OF_LIMBS, BIT_LEN_LIMB)
+    }
+
+    /// Computes `base^exponent mod modulus` for an exponent that is fixed in
+    /// the circuit. Square and multiply starts from the most significant bit so
+    /// that `e = 65537` costs 16 squarings and a single multiplication. `base`
+    /// is expected to be less than the modulus.
+    pub fn pow_mod_fixed(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        base: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        exponent: &big_uint,
+        modulus: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        assert!(!exponent.is_zero());
+        let mut acc = base.clone();
+        for i in (0..exponent.bits() - 1).rev() {
+            acc = self.0.mul_mod(ctx, &acc, &acc, modulus)?;
+            if exponent.bit(i) {
+                acc = self.0.mul_mod(ctx, &acc, base, modulus)?;
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Computes `base^exponent mod modulus` where exponent is given as
+    /// assigned bits in little endian order. Each bit costs a squaring and a
+    /// multiplication. `base` is expected to be less than the modulus.
+    pub fn pow_mod(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        base: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        exponent: &[AssignedCondition<N>],
+        modulus: &AssignedBigUint<N, BIT_LEN_LIMB>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        assert!(!exponent.is_empty());
+        let one = self.0.assign_constant(ctx, big_uint::one())?;
+        let mut acc: Option<AssignedBigUint<N, BIT_LEN_LIMB>> = None;
+        for bit in exponent.iter().rev() {
+            acc = Some(match acc {
+                None => self.0.select(ctx, base, &one, bit)?,
+                Some(acc) => {
+                    let squared = self.0.mul_mod(ctx, &acc, &acc, modulus)?;
+                    let multiplied = self.0.mul_mod(ctx, &squared, base, modulus)?;
+                    self.0.select(ctx, &multiplied, &squared, bit)?
+                }
+            });
+        }
+        Ok(acc.unwrap())
+    }
+
+    /// Raises base to the public exponent of the key
+    pub fn pow_mod_public_exponent(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        base: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        public_key: &AssignedRsaPublicKey<N, BIT_LEN_LIMB>,
+    ) -> Result<AssignedBigUint<N, BIT_LEN_LIMB>, Error> {
+        match &public_key.exponent {
+            RsaPublicExponent::Fixed(exponent) => {
+                self.pow_mod_fixed(ctx, base, exponent, &public_key.modulus)
+            }
+            RsaPublicExponent::Variable(exponent) => {
+                self.pow_mod(ctx, base, exponent, &public_key.modulus)
+            }
+        }
+    }
+
+    /// Verifies a PKCS#1 v1.5 signature of the given digest. Encoded message
+    /// is expected to be
+    /// `0x00 || 0x01 || 0xff..ff || 0x00 || digest_info || digest`
+    /// where `digest_info` is the DER encoded algorithm prefix such as
+    /// `SHA256_DIGEST_INFO`. `digest` is in big endian bytes and each byte is
+    /// range checked here. Modulus is constrained to be exactly
+    /// `NUMBER_OF_LIMBS * BIT_LEN_LIMB / 8` bytes long, that is its top byte is
+    /// non zero, so that the length of the padding string is determined by the
+    /// key.
+    pub fn verify_pkcs1v15(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        public_key: &AssignedRsaPublicKey<N, BIT_LEN_LIMB>,
+        signature: &AssignedBigUint<N, BIT_LEN_LIMB>,
+        digest_info: &[u8],
+        digest: &[AssignedValue<N>],
+    ) -> Result<(), Error> {
+        assert_eq!(BIT_LEN_LIMB % 8, 0, "limbs must be byte aligned");
+        let bytes_per_limb = BIT_LEN_LIMB / 8;
+        let number_of_bytes = NUMBER_OF_LIMBS * bytes_per_limb;
+        let t_len = digest_info.len() + digest.len();
+        // Padding string must be at least 8 bytes
+        assert!(number_of_bytes >= t_len + 11, "modulus is too short");
+        let main_gate = self.0.main_gate();
+        let modulus = &public_key.modulus;
+        assert_eq!(modulus.number_of_limbs(), NUMBER_OF_LIMBS);
+        assert!(modulus.is_normalized());
+
+        // 1. check that the top byte of n is non zero. Limbs of n are in
+        // `[0, 2^BIT_LEN_LIMB)` so it is enough to range check
+        // `top_limb - 2^(BIT_LEN_LIMB - 8)`
+        let top_limb = modulus.limbs()[NUMBER_OF_LIMBS - 1].as_ref();
+        let top_byte_offset = power_of_two::<N>(BIT_LEN_LIMB - 8);
+        let shifted = self.0.range_chip().assign(
+            ctx,
+            top_limb.value().map(|limb| *limb - top_byte_offset),
+            BigUintChip::<N, BIT_LEN_LIMB>::sublimb_bit_len(),
+            BIT_LEN_LIMB,
+        )?;
+        main_gate.assert_zero_sum(
+            ctx,
+            &[
+                Term::assigned_to_add(&shifted),
+                Term::assigned_to_sub(top_limb),
+            ],
+            top_byte_offset,
+        )?;
+
+        // 2. check that digest is given in bytes
+        for byte in digest.iter() {
+            main_gate.to_bits(ctx, byte, 8)?;
+        }
+
+        // 3. check 0 <= s < n
+        self.0.assert_less_than(ctx, signature, modulus)?;
+
+        // 4. em = s^e (mod n)
+        let em = self.pow_mod_public_exponent(ctx, signature, public_key)?;
+        let em = self.0.normalize(ctx, &em)?;
+        assert_eq!(em.number_of_limbs(), NUMBER_OF_LIMBS);
+
+        // 5. check em against the padded digest. Limbs are normalized so each
+        // limb must be equal to the composition of its bytes
+        let prefix = [0x00, 0x01]
+            .into_iter()
+            .chain(std::iter::repeat(0xff).take(number_of_bytes - t_len - 3))
+            .chain(std::iter::once(0x00))
+            .chain(digest_info.iter().cloned())
+            .collect::<Vec<u8>>();
+        for (i, limb) in em.limbs().iter().enumerate() {
+            let mut terms = vec![Term::assigned_to_sub(limb.as_ref())];
+            let mut constant = big_uint::zero();
+            for j in 0..bytes_per_limb {
+                // Position of the byte in big endian encoding
+                let position = number_of_bytes - 1 - (i * bytes_per_limb + j);
+                match prefix.get(position) {
+                    Some(byte) => constant += big_uint::from(*byte) << (8 * j),
+                    None => terms.push(Term::Assigned(
+                        &digest[position - prefix.len()],
+                        power_of_two(8 * j),
+                    )),
+                }
+            }
+            main_gate.assert_zero_sum(ctx, &terms, big_to_fe(constant))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssignedRsaPublicKey, RsaChip, RsaConfig, RsaPublicExponent, SHA256_DIGEST_INFO};
+    use crate::curves::bn256::Fr;
+    use crate::halo2;
+    use crate::maingate;
+    use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use halo2::ff::PrimeField;
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use integer::BigUintChip;
+    use maingate::{
+        mock_prover_verify, mock_prover_verify_fails, MainGate, MainGateInstructions, RangeChip,
+        RangeInstructions, RegionCtx,
+    };
+    use num_bigint::BigUint as big_uint;
+    use num_traits::Num;
+    use std::marker::PhantomData;
+
+    const BIT_LEN_LIMB: usize = 64;
+    const NUMBER_OF_LIMBS: usize = 32;
+
+    // Keys are generated with `openssl genpkey -algorithm RSA` and message
+    // `halo2wrong` is signed with `openssl dgst -sha256 -sign`
+    const DIGEST: &str = "54856632c8acbe67e843f9bec29fbe5037796a964e01a6f39449a83f647cc8d1";
+
+    const MODULUS_65537: &str = "\
+        c0ed2f6cf39141261f23ec60e9c6119e009593a3adcd1b832418009ae94f1c8c\
+        69fb579ec8a2f1c0762f243f6688c1f42e514e20f804cbb092bdffaf26ca85a2\
+        01bf9b88af0a2abc40e1c7c33e66527a77c19c5a47b3acd6344d9e8bd38b57b5\
+        aec49a9a9578e248c55cacd2f460b7a1965a9f454b6072d392fbc3ecf46d4451\
+        0f547de7286259f017320c1f18cf94ad6d91090e086b5f1247092c2c3583e623\
+        b824c67a0567640b7727a86fcdaba4dfa769b45ef1157559c025305c93ef3fb0\
+        48f4994db4f1f17a5deb0c5ad6a1027930c3cfc3f16d9fd41c064cc418e80a41\
+        381c4490b0a8078bf9b6175c6988f96d655fa7a1f5e9041627f8d7693da642d7";
+    const SIGNATURE_65537: &str = "\
+        75d16caaab41dabf618b7c80d5e1a3cfef7b2368439bc08dc1cd4399f0096911\
+        f6690085d4509c218ea231e3a0601b9bc8f80530d6d3666894333803fd95082a\
+        3d4d45ab40951cbf79c46bca5895b160cef1230251c0da9afb7db418849c3cb3\
+        b88f2578d6206b0e8b5307f3f1ae04666f25cc41cf872881737175cb0bfdbd11\
+        334640a6039110f5bd886e4a679455128ee3be6631d13787b78a068e47ab3707\
+        caea7584b585854610f51132a4c985401c282f90bc53351bffb6c7456d5e51d6\
+        961a899f8a20ab846bd43c07e122757feeec19cf37ffc385e15c638170590371\
+        3f44f36e111f127301026ee13c252cd4c4a3e7f891a5ad5291dd8e42d05756e3";
+
+    const MODULUS_3: &str = "\
+        b597bee0ec70fe4ef61110f2c5c0ae97f97194394e4c8b882a1bf76e8adbbc45\
+        e22a01bb02486eef8058bd320a518990f772562b54e66488212a7876ceb6d219\
+        48e114532a1d309b9ccac168b793f8e96271decbc51b9a5c843d328cf7c35116\
+        a33fb2bc468092a5b0296abccec24e7d3033fe76ef1ea866d9d624dd5e38839a\
+        c153b100a82004eb4a6c8eb2689e75ebb84383ab18b86494974d0acb4bed6185\
+        0538fa2ccf4d924a8e34a51df65d187c583b25e617ffada147fc13ad51797157\
+        04ab64cb9673f877a0da44fc6eedb88e18cfea619b118479dee85b2d9fe4b026\
+        36b5eea515a46302369fbba7a066b8d4ae3db1caa4b28afb8a5b2d253fab11bf";
+    const SIGNATURE_3: &str = "\
+        740a274311baa190018c07345bdea73db65225dc756f1f2fe70901c3c8a63afc\
+        8a547406e94f81dfa708583d38aa42877f9535d4ede3512754d143a4f7a47190\
+        a96921f986911f8a79aec2b4f2b7e46fdb6eeb9f9dee6cc4935b336948f6ea17\
+        5a164eb4560a7b01d96c761562a4ec6d7774b4d05b369a977213a4c316a059a3\
+        98d70945c3d084e9cbab05c234ceb5a3a197a64a1e293682e9ea31645dc2eb66\
+        f4590535c4c8e9052eeaf68ff2deae7980a104cbf2caf8ce2bc16f1671d28e1a\
+        49056e55c1495632246c54ba71a3f91c2d55fa67496d4021cdd451b1dbd7b01e\
+        c0a25c3d13a90ddca1c62f6e66245533e99b140a4bbf3a4e8cfc9cf098031849";
+
+    // 2040 bit modulus which leaves the top byte of a 2048 bit key zero. Its
+    // signature is valid for the padding of a 256 byte modulus
+    const MODULUS_SHORT: &str = "\
+        00daf30488a766f0dc81eb164c16abc94fb91815ade3e3310b2c4a28d16153b9\
+        5724cf7c582028fa0b731a450bff95363ac3ad8a5177939ae071f6efd068c273\
+        023dd35e0c174f80cb7b61fc1dc57e3db8af9894ab4672e5ad2a5d944356d9e5\
+        e826447ab8af22e660c0602da18b13d82e3ff990a8c30ebc351af59a29399cc1\
+        b8086f31e6b87c313242f3588804c49da636e0fd419065592423f98feb5d9e27\
+        8961e5b1a0966a5ff761e77cb7d3616471b45d71ef650a6b577dc39c9b24329c\
+        cd5e82119de273c406f0d3636962c49bc8efa58e52ed8b9924bbca239bbf3fb7\
+        9d1f25cc403e659c1e2543aa9eeac2caf6b26606b43a0362979aa0243a050901";
+    const SIGNATURE_SHORT: &str = "\
+        00ba9490b582a6703cbde11f07b4792ea2d26e1104c03d4561da31278fb836ea\
+        160c2bc2051685d206b8ad5fddca62af98f727f70806152896c7732404dcadbb\
+        a00856b36920645e2f3921755447273b90b70ac087bd50b70d419413415a41d0\
+        4898c9149162f1d70f122d8d4bcefcb70b1723ba4efd266bf5dc76988b224fe2\
+        844ecaaee246aa5bdcd91e7878a8e468642cb0825a8ae84b962031a1498147ee\
+        80d3022fb630268ac573797e71b245928118939acb180e98b107d72be7fa1a8a\
+        1abc336f6db1ce64fd6b7ec085915ba5164354a0c99d11f42a53f796c62164f2\
+        5a1fdd7d5d407e319f69cff19f6ddd38dae0a1c1c5ac64fa56000f0fe0ca7628";
+
+    fn from_hex(e: &str) -> big_uint {
+        big_uint::from_str_radix(e, 16).unwrap()
+    }
+
+    struct TestCircuitRsaVerify<N: PrimeField> {
+        modulus: big_uint,
+        exponent: big_uint,
+        signature: big_uint,
+        digest: Vec<u64>,
+        variable_exponent: bool,
+        public_modulus: bool,
+        _marker: PhantomData<N>,
+    }
+
+    impl<N: PrimeField> Circuit<N> for TestCircuitRsaVerify<N> {
+        type Config = RsaConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let range_config = RangeChip::<N>::configure(
+                meta,
+                &main_gate_config,
+                vec![BigUintChip::<N, BIT_LEN_LIMB>::sublimb_bit_len()],
+                vec![],
+            );
+            RsaConfig::new(range_config, main_gate_config)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let big_uint_chip = BigUintChip::<N, BIT_LEN_LIMB>::new(config.integer_chip_config());
+            let rsa_chip = RsaChip::<N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(big_uint_chip);
+            let main_gate = rsa_chip.big_uint_chip().main_gate();
+
+            let modulus = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let modulus =
+                        rsa_chip.assign_modulus(ctx, Value::known(self.modulus.clone()))?;
+                    let exponent = if self.variable_exponent {
+                        let bits = (0..self.exponent.bits())
+                            .map(|i| {
+                                let bit = N::from(self.exponent.bit(i) as u64);
+                                main_gate.assign_bit(ctx, Value::known(bit))
+                            })
+                            .collect::<Result<Vec<_>, Error>>()?;
+                        RsaPublicExponent::Variable(bits)
+                    } else {
+                        RsaPublicExponent::Fixed(self.exponent.clone())
+                    };
+                    let public_key = AssignedRsaPublicKey {
+                        modulus: modulus.clone(),
+                        exponent,
+                    };
+
+                    let signature =
+                        rsa_chip.assign_signature(ctx, Value::known(self.signature.clone()))?;
+                    let digest = self
+                        .digest
+                        .iter()
+                        .map(|byte| main_gate.assign_value(ctx, Value::known(N::from(*byte))))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    rsa_chip.verify_pkcs1v15(
+                        ctx,
+                        &public_key,
+                        &signature,
+                        &SHA256_DIGEST_INFO,
+                        &digest,
+                    )?;
+                    Ok(modulus)
+                },
+            )?;
+
+            if self.public_modulus {
+                rsa_chip.expose_modulus(layouter.namespace(|| "modulus"), &modulus, 0)?;
+            }
+
+            let range_chip = RangeChip::<N>::new(config.range_config);
+            range_chip.load_table(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    fn circuit(
+        modulus: &str,
+        exponent: u64,
+        signature: &str,
+        variable_exponent: bool,
+        public_modulus: bool,
+    ) -> (TestCircuitRsaVerify<Fr>, Vec<Vec<Fr>>) {
+        let modulus = from_hex(modulus);
+        let instance = if public_modulus {
+            RsaChip::<Fr, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::modulus_instance(&modulus)
+        } else {
+            vec![]
+        };
+        let circuit = TestCircuitRsaVerify::<Fr> {
+            modulus,
+            exponent: big_uint::from(exponent),
+            signature: from_hex(signature),
+            digest: from_hex(DIGEST)
+                .to_bytes_be()
+                .into_iter()
+                .map(|byte| byte as u64)
+                .collect(),
+            variable_exponent,
+            public_modulus,
+            _marker: PhantomData,
+        };
+        (circuit, vec![instance])
+    }
+
+    fn run(
+        modulus: &str,
+        exponent: u64,
+        signature: &str,
+        variable_exponent: bool,
+        public_modulus: bool,
+    ) {
+        let (circuit, instance) = circuit(
+            modulus,
+            exponent,
+            signature,
+            variable_exponent,
+            public_modulus,
+        );
+        mock_prover_verify(&circuit, instance);
+    }
+
+    #[test]
+    fn test_rsa_verify_65537() {
+        run(MODULUS_65537, 65537, SIGNATURE_65537, false, true);
+    }
+
+    #[test]
+    fn test_rsa_verify_general_exponent() {
+        run(MODULUS_3, 3, SIGNATURE_3, false, false);
+        run(MODULUS_3, 3, SIGNATURE_3, true, false);
+    }
+
+    #[test]
+    fn test_rsa_verify_unranged_digest() {
+        // Moving a unit from a byte to the next one keeps the composed limb
+        // intact but the lower byte leaves the byte range
+        let (mut circuit, instance) = circuit(MODULUS_65537, 65537, SIGNATURE_65537, false, false);
+        let last = circuit.digest.len() - 1;
+        assert!(circuit.digest[last - 1] > 0);
+        circuit.digest[last - 1] -= 1;
+        circuit.digest[last] += 256;
+        mock_prover_verify_fails(&circuit, instance);
+    }
+
+    #[test]
+    fn test_rsa_verify_short_modulus() {
+        // Signature is valid for the padding of a full length modulus but the
+        // key is one byte shorter
+        let (circuit, instance) = circuit(MODULUS_SHORT, 65537, SIGNATURE_SHORT, false, false);
+        mock_prover_verify_fails(&circuit, instance);
+    }
+}
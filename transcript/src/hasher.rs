@@ -38,11 +38,24 @@ impl<
         ctx: &mut RegionCtx<'_, F>,
         spec: &Spec<F, T, RATE>,
         main_gate_config: &MainGateConfig,
+    ) -> Result<Self, Error> {
+        Self::new_with_tag(ctx, spec, main_gate_config, F::ZERO)
+    }
+
+    /// Constructs new hasher chip where `tag` is added to the capacity word of
+    /// the initial state. Sponges with different tags are separated while a
+    /// zero tag gives the initial state of `poseidon::Poseidon`
+    pub fn new_with_tag(
+        ctx: &mut RegionCtx<'_, F>,
+        spec: &Spec<F, T, RATE>,
+        main_gate_config: &MainGateConfig,
+        tag: F,
     ) -> Result<Self, Error> {
         let main_gate = MainGate::<_>::new(main_gate_config.clone());
 
-        let initial_state = State::<_, T>::default()
-            .words()
+        let mut initial_state = State::<_, T>::default().words().to_vec();
+        initial_state[0] += tag;
+        let initial_state = initial_state
             .iter()
             .map(|word| main_gate.assign_constant(ctx, *word))
             .collect::<Result<Vec<AssignedValue<F>>, Error>>()?;
@@ -60,6 +73,11 @@ impl<
     pub fn update(&mut self, elements: &[AssignedValue<F>]) {
         self.absorbing.extend_from_slice(elements);
     }
+
+    /// Returns current state of the sponge
+    pub(crate) fn state(&self) -> &AssignedState<F, T> {
+        &self.state
+    }
 }
 
 impl<
@@ -1,10 +1,16 @@
 mod hasher;
+mod merkle;
+mod sparse_merkle;
+mod sponge;
 mod transcript;
 
 pub use ecc;
 pub use ecc::halo2;
 pub use ecc::maingate;
 pub use hasher::HasherChip;
+pub use merkle::MerkleChip;
+pub use sparse_merkle::*;
+pub use sponge::PoseidonChip;
 
 pub use crate::transcript::*;
 
@@ -0,0 +1,359 @@
+use crate::{
+    halo2::{ff::PrimeField, plonk::Error},
+    maingate::{
+        AssignedCondition, AssignedValue, MainGate, MainGateConfig, MainGateInstructions, RegionCtx,
+    },
+    sponge::PoseidonChip,
+};
+use poseidon::Spec;
+
+/// `MerkleChip` constrains Merkle paths of a Poseidon tree where each node is
+/// the hash of its `ARITY` children. Arity can be either 2 or 4.
+///
+/// A path is given from leaf to root as `ARITY - 1` siblings per level and the
+/// little endian bits of the leaf index. Each level consumes `log2(ARITY)`
+/// bits which give the position of the current node among its siblings.
+#[derive(Debug, Clone)]
+pub struct MerkleChip<F: PrimeField, const T: usize, const RATE: usize, const ARITY: usize> {
+    poseidon_chip: PoseidonChip<F, T, RATE>,
+    main_gate_config: MainGateConfig,
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize, const ARITY: usize>
+    MerkleChip<F, T, RATE, ARITY>
+{
+    /// Number of index bits that each level of the tree consumes
+    pub const LEVEL_BITS: usize = ARITY.trailing_zeros() as usize;
+
+    /// Constructs new merkle chip
+    pub fn new(spec: &Spec<F, T, RATE>, main_gate_config: &MainGateConfig) -> Self {
+        assert!(ARITY == 2 || ARITY == 4);
+        Self {
+            poseidon_chip: PoseidonChip::new(spec, main_gate_config),
+            main_gate_config: main_gate_config.clone(),
+        }
+    }
+
+    /// Construct main gate
+    pub fn main_gate(&self) -> MainGate<F> {
+        MainGate::<_>::new(self.main_gate_config.clone())
+    }
+
+    /// Returns the hasher that computes nodes of the tree
+    pub fn poseidon_chip(&self) -> &PoseidonChip<F, T, RATE> {
+        &self.poseidon_chip
+    }
+
+    /// Hashes children of a node
+    pub fn hash_children(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        children: &[AssignedValue<F>],
+    ) -> Result<AssignedValue<F>, Error> {
+        assert_eq!(children.len(), ARITY);
+        self.poseidon_chip.hash(ctx, children)
+    }
+
+    /// Constrains index bits and returns one hot position selectors of each
+    /// level
+    fn positions(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        index_bits: &[AssignedCondition<F>],
+    ) -> Result<Vec<Vec<AssignedCondition<F>>>, Error> {
+        let main_gate = self.main_gate();
+        assert_eq!(index_bits.len() % Self::LEVEL_BITS, 0);
+
+        for bit in index_bits.iter() {
+            main_gate.assert_bit(ctx, bit)?;
+        }
+
+        index_bits
+            .chunks(Self::LEVEL_BITS)
+            .map(|bits| {
+                let mut selectors = vec![main_gate.not(ctx, &bits[0])?, bits[0].clone()];
+                for bit in bits.iter().skip(1) {
+                    let high = selectors
+                        .iter()
+                        .map(|selector| main_gate.mul(ctx, selector, bit))
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let low = selectors
+                        .iter()
+                        .zip(high.iter())
+                        .map(|(selector, high)| main_gate.sub(ctx, selector, high))
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    selectors = low.into_iter().chain(high).collect();
+                }
+                Ok(selectors)
+            })
+            .collect()
+    }
+
+    /// Places the current node among its siblings at the selected position
+    /// and returns the parent node
+    fn parent(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        node: &AssignedValue<F>,
+        siblings: &[AssignedValue<F>],
+        selectors: &[AssignedCondition<F>],
+    ) -> Result<AssignedValue<F>, Error> {
+        let main_gate = self.main_gate();
+        assert_eq!(siblings.len(), ARITY - 1);
+
+        // Child `j` is the current node if it is selected. Otherwise it is
+        // the sibling `j - 1` if the current node is placed before or the
+        // sibling `j` if it is placed after.
+        let mut is_before: Option<AssignedCondition<F>> = None;
+        let children = (0..ARITY)
+            .map(|j| {
+                let sibling = match (j, &is_before) {
+                    (0, _) => siblings[0].clone(),
+                    (j, _) if j == ARITY - 1 => siblings[j - 1].clone(),
+                    (j, Some(is_before)) => {
+                        main_gate.select(ctx, &siblings[j - 1], &siblings[j], is_before)?
+                    }
+                    _ => unreachable!(),
+                };
+                if j + 2 < ARITY {
+                    is_before = Some(match &is_before {
+                        None => selectors[j].clone(),
+                        Some(is_before) => main_gate.add(ctx, is_before, &selectors[j])?,
+                    });
+                }
+                main_gate.select(ctx, node, &sibling, &selectors[j])
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        self.hash_children(ctx, &children)
+    }
+
+    fn root_with_positions(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        leaf: &AssignedValue<F>,
+        siblings: &[Vec<AssignedValue<F>>],
+        positions: &[Vec<AssignedCondition<F>>],
+    ) -> Result<AssignedValue<F>, Error> {
+        assert_eq!(siblings.len(), positions.len());
+        siblings
+            .iter()
+            .zip(positions.iter())
+            .try_fold(leaf.clone(), |node, (siblings, selectors)| {
+                self.parent(ctx, &node, siblings, selectors)
+            })
+    }
+
+    /// Computes the root of the tree from a leaf and its path
+    pub fn compute_root(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        leaf: &AssignedValue<F>,
+        siblings: &[Vec<AssignedValue<F>>],
+        index_bits: &[AssignedCondition<F>],
+    ) -> Result<AssignedValue<F>, Error> {
+        let positions = self.positions(ctx, index_bits)?;
+        self.root_with_positions(ctx, leaf, siblings, &positions)
+    }
+
+    /// Constrains that the leaf is included in the tree with the given root
+    pub fn verify_inclusion(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        root: &AssignedValue<F>,
+        leaf: &AssignedValue<F>,
+        siblings: &[Vec<AssignedValue<F>>],
+        index_bits: &[AssignedCondition<F>],
+    ) -> Result<(), Error> {
+        let computed = self.compute_root(ctx, leaf, siblings, index_bits)?;
+        self.main_gate().assert_equal(ctx, &computed, root)
+    }
+
+    /// Constrains that the old leaf is included in the tree with the old root
+    /// and returns the root of the tree where the old leaf is replaced with
+    /// the new leaf at the same path
+    pub fn verify_update(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        old_root: &AssignedValue<F>,
+        old_leaf: &AssignedValue<F>,
+        new_leaf: &AssignedValue<F>,
+        siblings: &[Vec<AssignedValue<F>>],
+        index_bits: &[AssignedCondition<F>],
+    ) -> Result<AssignedValue<F>, Error> {
+        let positions = self.positions(ctx, index_bits)?;
+        let computed = self.root_with_positions(ctx, old_leaf, siblings, &positions)?;
+        self.main_gate().assert_equal(ctx, &computed, old_root)?;
+        self.root_with_positions(ctx, new_leaf, siblings, &positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MerkleChip;
+    use crate::curves::bn256::Fr;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::ff::{Field, PrimeField};
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::maingate::mock_prover_verify;
+    use crate::maingate::{MainGate, MainGateConfig, MainGateInstructions, RegionCtx};
+    use paste::paste;
+    use poseidon::{Poseidon, Spec};
+    use rand::Rng;
+    use rand_core::OsRng;
+
+    const R_F: usize = 8;
+    const R_P: usize = 57;
+    const DEPTH: usize = 4;
+
+    fn native_root<const T: usize, const RATE: usize, const ARITY: usize>(
+        leaf: Fr,
+        siblings: &[Vec<Fr>],
+        index: usize,
+    ) -> Fr {
+        siblings
+            .iter()
+            .enumerate()
+            .fold(leaf, |node, (level, siblings)| {
+                let position = (index / ARITY.pow(level as u32)) % ARITY;
+                let mut children = siblings.clone();
+                children.insert(position, node);
+                let mut hasher = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+                hasher.update(&children);
+                hasher.squeeze()
+            })
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+    }
+
+    struct TestCircuit<F: PrimeField, const T: usize, const RATE: usize, const ARITY: usize> {
+        spec: Spec<F, T, RATE>,
+        old_root: Value<F>,
+        new_root: Value<F>,
+        old_leaf: Value<F>,
+        new_leaf: Value<F>,
+        siblings: Vec<Vec<Value<F>>>,
+        index: Value<F>,
+    }
+
+    impl<F: PrimeField, const T: usize, const RATE: usize, const ARITY: usize> Circuit<F>
+        for TestCircuit<F, T, RATE, ARITY>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<F>::new(config.main_gate_config.clone());
+            let merkle_chip =
+                MerkleChip::<F, T, RATE, ARITY>::new(&self.spec, &config.main_gate_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let old_root = main_gate.assign_value(ctx, self.old_root)?;
+                    let new_root = main_gate.assign_value(ctx, self.new_root)?;
+                    let old_leaf = main_gate.assign_value(ctx, self.old_leaf)?;
+                    let new_leaf = main_gate.assign_value(ctx, self.new_leaf)?;
+                    let siblings = self
+                        .siblings
+                        .iter()
+                        .map(|siblings| {
+                            siblings
+                                .iter()
+                                .map(|sibling| main_gate.assign_value(ctx, *sibling))
+                                .collect::<Result<Vec<_>, Error>>()
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let index = main_gate.assign_value(ctx, self.index)?;
+                    let number_of_bits = DEPTH * MerkleChip::<F, T, RATE, ARITY>::LEVEL_BITS;
+                    let index_bits = main_gate.to_bits(ctx, &index, number_of_bits)?;
+
+                    merkle_chip.verify_inclusion(
+                        ctx,
+                        &old_root,
+                        &old_leaf,
+                        &siblings,
+                        &index_bits,
+                    )?;
+                    let root = merkle_chip.verify_update(
+                        ctx,
+                        &old_root,
+                        &old_leaf,
+                        &new_leaf,
+                        &siblings,
+                        &index_bits,
+                    )?;
+                    main_gate.assert_equal(ctx, &root, &new_root)?;
+                    merkle_chip.verify_inclusion(
+                        ctx,
+                        &new_root,
+                        &new_leaf,
+                        &siblings,
+                        &index_bits,
+                    )?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    macro_rules! test {
+        ($T:expr, $RATE:expr, $ARITY:expr) => {
+            paste! {
+                #[test]
+                fn [<test_merkle_chip_ $T _ $RATE _ $ARITY>]() {
+                    let spec = Spec::<Fr, $T, $RATE>::new(R_F, R_P);
+                    let siblings = (0..DEPTH)
+                        .map(|_| (0..$ARITY - 1).map(|_| Fr::random(OsRng)).collect::<Vec<_>>())
+                        .collect::<Vec<_>>();
+                    let index = OsRng.gen_range(0..($ARITY as usize).pow(DEPTH as u32));
+                    let old_leaf = Fr::random(OsRng);
+                    let new_leaf = Fr::random(OsRng);
+                    let old_root = native_root::<$T, $RATE, $ARITY>(old_leaf, &siblings, index);
+                    let new_root = native_root::<$T, $RATE, $ARITY>(new_leaf, &siblings, index);
+
+                    let circuit = TestCircuit::<Fr, $T, $RATE, $ARITY> {
+                        spec,
+                        old_root: Value::known(old_root),
+                        new_root: Value::known(new_root),
+                        old_leaf: Value::known(old_leaf),
+                        new_leaf: Value::known(new_leaf),
+                        siblings: siblings
+                            .iter()
+                            .map(|siblings| siblings.iter().map(|e| Value::known(*e)).collect())
+                            .collect(),
+                        index: Value::known(Fr::from(index as u64)),
+                    };
+                    let instance = vec![vec![]];
+                    mock_prover_verify(&circuit, instance);
+                }
+            }
+        };
+    }
+
+    test!(3, 2, 2);
+    test!(5, 4, 2);
+    test!(5, 4, 4);
+}
@@ -0,0 +1,576 @@
+use crate::{
+    halo2::{circuit::Value, ff::PrimeField, plonk::Error},
+    maingate::{
+        decompose, fe_to_big, AssignedCondition, AssignedValue, MainGate, MainGateConfig,
+        MainGateInstructions, RegionCtx, Term,
+    },
+    sponge::PoseidonChip,
+};
+use poseidon::{Poseidon, Spec};
+
+/// Native Poseidon sparse merkle tree with `depth` bit keys. Subtrees with a
+/// single leaf are compressed into the leaf itself so that a leaf is placed at
+/// the shortest prefix of its key that is unique in the tree. Key bits are
+/// consumed in little endian order starting from the root.
+///
+/// * Empty subtree is `0`
+/// * Leaf is `H(key, value, 1)`
+/// * Internal node is `H(left, right)`
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree<F: PrimeField, const T: usize, const RATE: usize> {
+    depth: usize,
+    r_f: usize,
+    r_p: usize,
+    leaves: Vec<(F, F)>,
+}
+
+/// Path of a key in a sparse merkle tree
+#[derive(Debug, Clone)]
+pub struct SparseMerkleProof<F: PrimeField> {
+    /// Siblings of the nodes on the path from the root down to the terminal
+    /// node. Padded with empty subtrees upto the depth of the tree.
+    pub siblings: Vec<F>,
+    /// Level of the terminal node that is either an empty subtree or a leaf
+    pub level: usize,
+    /// Key and value of the terminal leaf or `None` if the subtree is empty
+    pub leaf: Option<(F, F)>,
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> SparseMerkleTree<F, T, RATE> {
+    /// Creates an empty tree
+    pub fn new(depth: usize, r_f: usize, r_p: usize) -> Self {
+        assert!(depth < F::NUM_BITS as usize);
+        Self {
+            depth,
+            r_f,
+            r_p,
+            leaves: vec![],
+        }
+    }
+
+    fn hash(&self, inputs: &[F]) -> F {
+        let mut hasher = Poseidon::<F, T, RATE>::new(self.r_f, self.r_p);
+        hasher.update(inputs);
+        hasher.squeeze()
+    }
+
+    fn leaf_hash(&self, key: F, value: F) -> F {
+        self.hash(&[key, value, F::ONE])
+    }
+
+    fn bit(&self, key: F, level: usize) -> bool {
+        decompose::<F>(key, self.depth, 1)[level] == F::ONE
+    }
+
+    fn subtree(&self, leaves: &[&(F, F)], level: usize) -> F {
+        match leaves {
+            [] => F::ZERO,
+            [(key, value)] => self.leaf_hash(*key, *value),
+            _ => {
+                let (right, left): (Vec<_>, Vec<_>) = leaves
+                    .iter()
+                    .copied()
+                    .partition(|(key, _)| self.bit(*key, level));
+                let left = self.subtree(&left, level + 1);
+                let right = self.subtree(&right, level + 1);
+                self.hash(&[left, right])
+            }
+        }
+    }
+
+    /// Depth of the tree that is also the bit length of keys
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the value of the key if it is in the tree
+    pub fn get(&self, key: F) -> Option<F> {
+        self.leaves
+            .iter()
+            .find(|(leaf_key, _)| *leaf_key == key)
+            .map(|(_, value)| *value)
+    }
+
+    /// Inserts a new leaf or updates the value of an existing key
+    pub fn insert(&mut self, key: F, value: F) {
+        assert!(fe_to_big(key).bits() as usize <= self.depth);
+        match self
+            .leaves
+            .iter_mut()
+            .find(|(leaf_key, _)| *leaf_key == key)
+        {
+            Some(leaf) => leaf.1 = value,
+            None => self.leaves.push((key, value)),
+        }
+    }
+
+    /// Computes the root of the tree
+    pub fn root(&self) -> F {
+        let leaves = self.leaves.iter().collect::<Vec<_>>();
+        self.subtree(&leaves, 0)
+    }
+
+    /// Generates the path of the key. It is a membership proof if the key is
+    /// in the tree and a non-membership proof otherwise.
+    pub fn proof(&self, key: F) -> SparseMerkleProof<F> {
+        assert!(fe_to_big(key).bits() as usize <= self.depth);
+        let mut leaves = self.leaves.iter().collect::<Vec<_>>();
+        let mut siblings = vec![];
+        let mut level = 0;
+        while leaves.len() > 1 {
+            let bit = self.bit(key, level);
+            let (path, other): (Vec<_>, Vec<_>) = leaves
+                .into_iter()
+                .partition(|(leaf_key, _)| self.bit(*leaf_key, level) == bit);
+            siblings.push(self.subtree(&other, level + 1));
+            leaves = path;
+            level += 1;
+        }
+        siblings.resize(self.depth, F::ZERO);
+
+        SparseMerkleProof {
+            siblings,
+            level,
+            leaf: leaves.first().map(|leaf| **leaf),
+        }
+    }
+}
+
+/// Assigned path of a key in a sparse merkle tree
+#[derive(Debug, Clone)]
+pub struct AssignedSparseMerkleProof<F: PrimeField> {
+    siblings: Vec<AssignedValue<F>>,
+    // One hot flags of the terminal level from the root to the bottom
+    levels: Vec<AssignedCondition<F>>,
+    // Flags for levels above the terminal node
+    above: Vec<AssignedCondition<F>>,
+    is_empty: AssignedCondition<F>,
+    leaf_key: AssignedValue<F>,
+    leaf_value: AssignedValue<F>,
+}
+
+/// `SparseMerkleChip` constrains membership, non-membership and insertion
+/// proofs of a [`SparseMerkleTree`]. Path of a key is derived from its bits so
+/// keys are range constrained to the depth of the tree.
+#[derive(Debug, Clone)]
+pub struct SparseMerkleChip<F: PrimeField, const T: usize, const RATE: usize> {
+    poseidon_chip: PoseidonChip<F, T, RATE>,
+    main_gate_config: MainGateConfig,
+    depth: usize,
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> SparseMerkleChip<F, T, RATE> {
+    /// Constructs new sparse merkle chip for trees with given depth
+    pub fn new(spec: &Spec<F, T, RATE>, main_gate_config: &MainGateConfig, depth: usize) -> Self {
+        assert!(depth < F::NUM_BITS as usize);
+        Self {
+            poseidon_chip: PoseidonChip::new(spec, main_gate_config),
+            main_gate_config: main_gate_config.clone(),
+            depth,
+        }
+    }
+
+    /// Construct main gate
+    pub fn main_gate(&self) -> MainGate<F> {
+        MainGate::<_>::new(self.main_gate_config.clone())
+    }
+
+    /// Depth of the tree
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Decomposes the key into path bits
+    pub fn key_bits(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        key: &AssignedValue<F>,
+    ) -> Result<Vec<AssignedCondition<F>>, Error> {
+        self.main_gate().to_bits(ctx, key, self.depth)
+    }
+
+    /// Hashes a leaf
+    pub fn leaf_hash(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        key: &AssignedValue<F>,
+        value: &AssignedValue<F>,
+    ) -> Result<AssignedValue<F>, Error> {
+        let one = self.main_gate().assign_constant(ctx, F::ONE)?;
+        self.poseidon_chip
+            .hash(ctx, &[key.clone(), value.clone(), one])
+    }
+
+    /// Assigns the path and constrains that terminal level is unique and
+    /// siblings below the terminal node are empty
+    pub fn assign_proof(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        proof: Value<&SparseMerkleProof<F>>,
+    ) -> Result<AssignedSparseMerkleProof<F>, Error> {
+        let main_gate = self.main_gate();
+
+        let siblings = (0..self.depth)
+            .map(|i| main_gate.assign_value(ctx, proof.map(|proof| proof.siblings[i])))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let levels = (0..=self.depth)
+            .map(|i| {
+                let flag = proof.map(|proof| F::from((proof.level == i) as u64));
+                main_gate.assign_bit(ctx, flag)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let terms = levels.iter().map(Term::assigned_to_add).collect::<Vec<_>>();
+        main_gate.assert_zero_sum(ctx, &terms, -F::ONE)?;
+
+        // above_i = sum_{j > i} level_j
+        let mut above = vec![levels[self.depth].clone()];
+        for level in levels.iter().skip(1).rev().skip(1) {
+            let next = main_gate.add(ctx, above.last().unwrap(), level)?;
+            above.push(next);
+        }
+        above.reverse();
+
+        for (sibling, above) in siblings.iter().zip(above.iter()) {
+            let below = main_gate.not(ctx, above)?;
+            let must_be_zero = main_gate.mul(ctx, sibling, &below)?;
+            main_gate.assert_zero(ctx, &must_be_zero)?;
+        }
+
+        let is_empty =
+            main_gate.assign_bit(ctx, proof.map(|proof| F::from(proof.leaf.is_none() as u64)))?;
+        let (leaf_key, leaf_value) = proof
+            .map(|proof| proof.leaf.unwrap_or((F::ZERO, F::ZERO)))
+            .unzip();
+        let leaf_key = main_gate.assign_value(ctx, leaf_key)?;
+        let leaf_value = main_gate.assign_value(ctx, leaf_value)?;
+
+        Ok(AssignedSparseMerkleProof {
+            siblings,
+            levels,
+            above,
+            is_empty,
+            leaf_key,
+            leaf_value,
+        })
+    }
+
+    // Returns `(left, right)` children where `bit` is the position of the
+    // node on the path
+    fn order(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        node: &AssignedValue<F>,
+        sibling: &AssignedValue<F>,
+        bit: &AssignedCondition<F>,
+    ) -> Result<[AssignedValue<F>; 2], Error> {
+        let main_gate = self.main_gate();
+        let left = main_gate.select(ctx, sibling, node, bit)?;
+        let right = main_gate.select(ctx, node, sibling, bit)?;
+        Ok([left, right])
+    }
+
+    fn root_from_terminal(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        terminal: &AssignedValue<F>,
+        bits: &[AssignedCondition<F>],
+        proof: &AssignedSparseMerkleProof<F>,
+    ) -> Result<AssignedValue<F>, Error> {
+        let main_gate = self.main_gate();
+        let mut node = terminal.clone();
+        for i in (0..self.depth).rev() {
+            let children = self.order(ctx, &node, &proof.siblings[i], &bits[i])?;
+            let parent = self.poseidon_chip.hash(ctx, &children)?;
+            node = main_gate.select(ctx, terminal, &parent, &proof.levels[i])?;
+        }
+        Ok(node)
+    }
+
+    /// Constrains that the key is in the tree with the given value
+    pub fn verify_membership(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        root: &AssignedValue<F>,
+        key: &AssignedValue<F>,
+        value: &AssignedValue<F>,
+        proof: &AssignedSparseMerkleProof<F>,
+    ) -> Result<(), Error> {
+        let bits = self.key_bits(ctx, key)?;
+        let leaf = self.leaf_hash(ctx, key, value)?;
+        let computed = self.root_from_terminal(ctx, &leaf, &bits, proof)?;
+        self.main_gate().assert_equal(ctx, &computed, root)
+    }
+
+    fn verify_non_membership_with_bits(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        root: &AssignedValue<F>,
+        key: &AssignedValue<F>,
+        bits: &[AssignedCondition<F>],
+        proof: &AssignedSparseMerkleProof<F>,
+    ) -> Result<AssignedValue<F>, Error> {
+        let main_gate = self.main_gate();
+        let is_leaf = main_gate.not(ctx, &proof.is_empty)?;
+
+        // Terminal node is either an empty subtree or a leaf with another key
+        let leaf = self.leaf_hash(ctx, &proof.leaf_key, &proof.leaf_value)?;
+        let terminal = main_gate.select_or_assign(ctx, &leaf, F::ZERO, &is_leaf)?;
+        let dif = main_gate.sub(ctx, key, &proof.leaf_key)?;
+        let dif = main_gate.select_or_assign(ctx, &dif, F::ONE, &is_leaf)?;
+        main_gate.assert_not_zero(ctx, &dif)?;
+
+        let computed = self.root_from_terminal(ctx, &terminal, bits, proof)?;
+        main_gate.assert_equal(ctx, &computed, root)?;
+
+        Ok(leaf)
+    }
+
+    /// Constrains that the key is not in the tree. Path of the key ends either
+    /// at an empty subtree or at a leaf of another key.
+    pub fn verify_non_membership(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        root: &AssignedValue<F>,
+        key: &AssignedValue<F>,
+        proof: &AssignedSparseMerkleProof<F>,
+    ) -> Result<(), Error> {
+        let bits = self.key_bits(ctx, key)?;
+        self.verify_non_membership_with_bits(ctx, root, key, &bits, proof)?;
+        Ok(())
+    }
+
+    /// Constrains that the key is not in the tree and returns the root of the
+    /// tree after the key is inserted with the given value. If the path ends
+    /// at a leaf of another key both leaves are placed under the node where
+    /// their keys diverge.
+    pub fn insert(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        root: &AssignedValue<F>,
+        key: &AssignedValue<F>,
+        value: &AssignedValue<F>,
+        proof: &AssignedSparseMerkleProof<F>,
+    ) -> Result<AssignedValue<F>, Error> {
+        let main_gate = self.main_gate();
+        let bits = self.key_bits(ctx, key)?;
+        let old_leaf = self.verify_non_membership_with_bits(ctx, root, key, &bits, proof)?;
+        let old_bits = self.key_bits(ctx, &proof.leaf_key)?;
+        let is_leaf = main_gate.not(ctx, &proof.is_empty)?;
+
+        // Diverging level is the first level where bits of keys differ. It is
+        // only flagged if the terminal node is a leaf.
+        let differs = bits
+            .iter()
+            .zip(old_bits.iter())
+            .map(|(bit, old_bit)| bit.value().zip(old_bit.value()).map(|(a, b)| a != b))
+            .collect::<Value<Vec<bool>>>();
+        let diverging_level = is_leaf.value().zip(differs).map(|(is_leaf, differs)| {
+            if *is_leaf == F::ONE {
+                differs.iter().position(|differs| *differs)
+            } else {
+                None
+            }
+        });
+        let diverges = (0..self.depth)
+            .map(|i| {
+                let flag = diverging_level.map(|level| F::from((level == Some(i)) as u64));
+                main_gate.assign_bit(ctx, flag)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let terms = diverges
+            .iter()
+            .map(Term::assigned_to_add)
+            .chain(std::iter::once(Term::assigned_to_sub(&is_leaf)))
+            .collect::<Vec<_>>();
+        main_gate.assert_zero_sum(ctx, &terms, F::ZERO)?;
+
+        // Keys share the same prefix until the diverging level which must be
+        // at or below the terminal node
+        let mut before = is_leaf.clone();
+        for (((diverges, above), bit), old_bit) in diverges
+            .iter()
+            .zip(proof.above.iter())
+            .zip(bits.iter())
+            .zip(old_bits.iter())
+        {
+            let must_be_zero = main_gate.mul(ctx, diverges, above)?;
+            main_gate.assert_zero(ctx, &must_be_zero)?;
+
+            before = main_gate.sub(ctx, &before, diverges)?;
+            let dif = main_gate.sub(ctx, bit, old_bit)?;
+            let must_be_zero = main_gate.mul(ctx, &before, &dif)?;
+            main_gate.assert_zero(ctx, &must_be_zero)?;
+
+            let sum = main_gate.add_with_constant(ctx, bit, old_bit, -F::ONE)?;
+            let must_be_zero = main_gate.mul(ctx, diverges, &sum)?;
+            main_gate.assert_zero(ctx, &must_be_zero)?;
+        }
+
+        // New leaf replaces the empty terminal node or old and new leaves
+        // become children of the diverging node
+        let new_leaf = self.leaf_hash(ctx, key, value)?;
+        let mut node = new_leaf.clone();
+        for i in (0..self.depth).rev() {
+            let child = main_gate.select(ctx, &new_leaf, &node, &diverges[i])?;
+            let sibling = main_gate.select(ctx, &old_leaf, &proof.siblings[i], &diverges[i])?;
+            let children = self.order(ctx, &child, &sibling, &bits[i])?;
+            let parent = self.poseidon_chip.hash(ctx, &children)?;
+            let replaces = main_gate.mul(ctx, &proof.levels[i], &proof.is_empty)?;
+            node = main_gate.select(ctx, &new_leaf, &parent, &replaces)?;
+        }
+
+        Ok(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SparseMerkleChip, SparseMerkleProof, SparseMerkleTree};
+    use crate::curves::bn256::Fr;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::ff::PrimeField;
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::maingate::mock_prover_verify;
+    use crate::maingate::{MainGate, MainGateConfig, MainGateInstructions, RegionCtx};
+    use poseidon::Spec;
+    use rand::Rng;
+    use rand_core::OsRng;
+
+    const R_F: usize = 8;
+    const R_P: usize = 57;
+    const T: usize = 3;
+    const RATE: usize = 2;
+    const DEPTH: usize = 16;
+
+    #[derive(Clone)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+    }
+
+    struct TestCircuit<F: PrimeField> {
+        spec: Spec<F, T, RATE>,
+        root: Value<F>,
+        member: Option<(Value<F>, Value<F>, Value<SparseMerkleProof<F>>)>,
+        key: Value<F>,
+        value: Value<F>,
+        proof: Value<SparseMerkleProof<F>>,
+        new_root: Value<F>,
+    }
+
+    impl<F: PrimeField> Circuit<F> for TestCircuit<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<F>::new(config.main_gate_config.clone());
+            let chip =
+                SparseMerkleChip::<F, T, RATE>::new(&self.spec, &config.main_gate_config, DEPTH);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let root = main_gate.assign_value(ctx, self.root)?;
+
+                    if let Some((key, value, proof)) = &self.member {
+                        let key = main_gate.assign_value(ctx, *key)?;
+                        let value = main_gate.assign_value(ctx, *value)?;
+                        let proof = chip.assign_proof(ctx, proof.as_ref())?;
+                        chip.verify_membership(ctx, &root, &key, &value, &proof)?;
+                    }
+
+                    let key = main_gate.assign_value(ctx, self.key)?;
+                    let value = main_gate.assign_value(ctx, self.value)?;
+                    let proof = chip.assign_proof(ctx, self.proof.as_ref())?;
+                    chip.verify_non_membership(ctx, &root, &key, &proof)?;
+                    let new_root = chip.insert(ctx, &root, &key, &value, &proof)?;
+                    let expected = main_gate.assign_value(ctx, self.new_root)?;
+                    main_gate.assert_equal(ctx, &new_root, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    fn run_test(keys: &[u64], key: u64) {
+        let mut tree = SparseMerkleTree::<Fr, T, RATE>::new(DEPTH, R_F, R_P);
+        for key in keys.iter() {
+            tree.insert(Fr::from(*key), Fr::from(OsRng.gen::<u64>()));
+        }
+        let root = tree.root();
+
+        let member = keys.first().map(|member| {
+            let member = Fr::from(*member);
+            let value = tree.get(member).unwrap();
+            let proof = tree.proof(member);
+            assert_eq!(proof.leaf, Some((member, value)));
+            (
+                Value::known(member),
+                Value::known(value),
+                Value::known(proof),
+            )
+        });
+
+        let key = Fr::from(key);
+        let value = Fr::from(OsRng.gen::<u64>());
+        assert!(tree.get(key).is_none());
+        let proof = tree.proof(key);
+        tree.insert(key, value);
+        let new_root = tree.root();
+
+        let circuit = TestCircuit::<Fr> {
+            spec: Spec::new(R_F, R_P),
+            root: Value::known(root),
+            member,
+            key: Value::known(key),
+            value: Value::known(value),
+            proof: Value::known(proof),
+            new_root: Value::known(new_root),
+        };
+        let instance = vec![vec![]];
+        mock_prover_verify(&circuit, instance);
+    }
+
+    #[test]
+    fn test_sparse_merkle_chip() {
+        // Empty tree
+        run_test(&[], 6);
+        // Single leaf at the root
+        run_test(&[1], 6);
+        // Path of 0 ends at an empty subtree
+        run_test(&[1, 3, 5], 0);
+        // Path of 7 ends at the leaf of 3 and diverges at the third bit
+        run_test(&[1, 3, 5], 7);
+        // Path of 9 ends at the leaf of 1 and diverges at the fourth bit
+        run_test(&[1, 3, 5], 9);
+
+        let keys = (0..8)
+            .map(|_| OsRng.gen_range(0..1u64 << DEPTH))
+            .collect::<std::collections::BTreeSet<_>>();
+        let key = loop {
+            let key = OsRng.gen_range(0..1u64 << DEPTH);
+            if !keys.contains(&key) {
+                break key;
+            }
+        };
+        run_test(&keys.into_iter().collect::<Vec<_>>(), key);
+    }
+}
@@ -0,0 +1,508 @@
+use crate::{
+    halo2::{ff::PrimeField, plonk::Error},
+    hasher::HasherChip,
+    maingate::{
+        AssignedCondition, AssignedValue, MainGate, MainGateConfig, MainGateInstructions,
+        RegionCtx, Term,
+    },
+};
+use poseidon::Spec;
+
+// Limb parameters of `HasherChip` are only meaningful for the transcript and
+// are not used by the permutation
+type Sponge<F, const T: usize, const RATE: usize> = HasherChip<F, 0, 0, T, RATE>;
+
+// Capacity tags of the hashing modes. Fixed length hashing is left untagged
+// to stay equal to `poseidon::Poseidon`
+const VAR_LEN_TAG: u64 = 1;
+const TO_FIELD_TAG: u64 = 2;
+
+/// `PoseidonChip` constrains Poseidon hashes of native field elements. Each
+/// hash starts from a fresh sponge and follows the padding of
+/// `poseidon::Poseidon`. Fixed length hashes are equal to the off-circuit
+/// hasher while variable length and hash to field modes start from a sponge
+/// with their own capacity tag so that modes never collide with each other.
+#[derive(Debug, Clone)]
+pub struct PoseidonChip<F: PrimeField, const T: usize, const RATE: usize> {
+    spec: Spec<F, T, RATE>,
+    main_gate_config: MainGateConfig,
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> PoseidonChip<F, T, RATE> {
+    /// Constructs new poseidon chip
+    pub fn new(spec: &Spec<F, T, RATE>, main_gate_config: &MainGateConfig) -> Self {
+        Self {
+            spec: spec.clone(),
+            main_gate_config: main_gate_config.clone(),
+        }
+    }
+
+    /// Construct main gate
+    pub fn main_gate(&self) -> MainGate<F> {
+        MainGate::<_>::new(self.main_gate_config.clone())
+    }
+
+    fn sponge(&self, ctx: &mut RegionCtx<'_, F>, tag: u64) -> Result<Sponge<F, T, RATE>, Error> {
+        HasherChip::new_with_tag(ctx, &self.spec, &self.main_gate_config, F::from(tag))
+    }
+
+    fn squeeze(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        tag: u64,
+        inputs: &[AssignedValue<F>],
+        number_of_outputs: usize,
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        let mut sponge = self.sponge(ctx, tag)?;
+        sponge.update(inputs);
+        (0..number_of_outputs)
+            .map(|_| sponge.hash(ctx))
+            .collect::<Result<Vec<_>, Error>>()
+    }
+
+    /// Hashes fixed number of inputs. Equivalent of `update(inputs)` and a
+    /// single `squeeze()` of the off-circuit hasher
+    pub fn hash(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        inputs: &[AssignedValue<F>],
+    ) -> Result<AssignedValue<F>, Error> {
+        let mut outputs = self.hash_with_outputs(ctx, inputs, 1)?;
+        Ok(outputs.swap_remove(0))
+    }
+
+    /// Hashes fixed number of inputs and squeezes `number_of_outputs`
+    /// elements. Each further output costs a permutation as in consecutive
+    /// `squeeze()` calls of the off-circuit hasher
+    pub fn hash_with_outputs(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        inputs: &[AssignedValue<F>],
+        number_of_outputs: usize,
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        self.squeeze(ctx, 0, inputs, number_of_outputs)
+    }
+
+    /// Hashes given inputs into `number_of_outputs` field elements under the
+    /// domain tag. Domain is absorbed before the inputs so that hashes of
+    /// different domains are separated and the sponge is tagged so that
+    /// results differ from `hash([domain] ++ inputs)`.
+    pub fn hash_to_field(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        domain: F,
+        inputs: &[AssignedValue<F>],
+        number_of_outputs: usize,
+    ) -> Result<Vec<AssignedValue<F>>, Error> {
+        let domain = self.main_gate().assign_constant(ctx, domain)?;
+        let inputs = std::iter::once(domain)
+            .chain(inputs.iter().cloned())
+            .collect::<Vec<_>>();
+        self.squeeze(ctx, TO_FIELD_TAG, &inputs, number_of_outputs)
+    }
+
+    /// Hashes first `len` elements of `inputs` where `len` is a witness that
+    /// can be at most `inputs.len()`. Length is absorbed as the first element
+    /// and the sponge is tagged for variable length mode, so result is equal
+    /// to hashing `[len] ++ inputs[..len]` with that tag but never to
+    /// `hash([len] ++ inputs[..len])`. Cost is always the cost of hashing
+    /// `inputs.len() + 1` elements.
+    pub fn hash_var(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        inputs: &[AssignedValue<F>],
+        len: &AssignedValue<F>,
+    ) -> Result<AssignedValue<F>, Error> {
+        let main_gate = self.main_gate();
+
+        // Message is `[len] ++ inputs` and its length is `len + 1`
+        let message_len = main_gate.add_constant(ctx, len, F::ONE)?;
+        let message = std::iter::once(len.clone())
+            .chain(inputs.iter().cloned())
+            .collect::<Vec<_>>();
+        let number_of_chunks = message.len() / RATE + 1;
+        let number_of_positions = number_of_chunks * RATE;
+
+        // Only one position is flagged and that is where the padding mark goes.
+        // Flags are evaluated upto the maximum message length and the sum of
+        // flags constrains `1 <= len + 1 <= inputs.len() + 1`
+        let is_end = (0..number_of_positions)
+            .map(|i| {
+                if i == 0 || i > message.len() {
+                    main_gate.assign_constant(ctx, F::ZERO)
+                } else {
+                    let dif = main_gate.add_constant(ctx, &message_len, -F::from(i as u64))?;
+                    main_gate.is_zero(ctx, &dif)
+                }
+            })
+            .collect::<Result<Vec<AssignedCondition<F>>, Error>>()?;
+        let terms = is_end.iter().map(Term::assigned_to_add).collect::<Vec<_>>();
+        main_gate.assert_zero_sum(ctx, &terms, -F::ONE)?;
+
+        // Padded message where inactive elements are zeroed and the end mark
+        // is placed right after the last active element
+        // word_i = message_i * is_active_i + is_end_i
+        let mut is_active = main_gate.assign_constant(ctx, F::ONE)?;
+        let words = is_end
+            .iter()
+            .enumerate()
+            .map(|(i, is_end)| {
+                if i > 0 {
+                    is_active = main_gate.sub(ctx, &is_active, is_end)?;
+                }
+                match message.get(i) {
+                    Some(element) => main_gate.mul_add(ctx, element, &is_active, is_end),
+                    None => Ok(is_end.clone()),
+                }
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Permute all chunks and select the state right after the chunk that
+        // contains the end mark
+        let mut sponge = self.sponge(ctx, VAR_LEN_TAG)?;
+        let mut selected: Option<Vec<AssignedValue<F>>> = None;
+        for (chunk, is_end) in words.chunks(RATE).zip(is_end.chunks(RATE)) {
+            sponge.permutation(ctx, chunk.to_vec())?;
+            let terms = is_end.iter().map(Term::assigned_to_add).collect::<Vec<_>>();
+            let is_last = main_gate.compose(ctx, &terms, F::ZERO)?;
+            selected = Some(match selected {
+                None => sponge
+                    .state()
+                    .0
+                    .iter()
+                    .map(|word| main_gate.mul(ctx, &is_last, word))
+                    .collect::<Result<Vec<_>, Error>>()?,
+                Some(selected) => sponge
+                    .state()
+                    .0
+                    .iter()
+                    .zip(selected.iter())
+                    .map(|(word, selected)| main_gate.mul_add(ctx, &is_last, word, selected))
+                    .collect::<Result<Vec<_>, Error>>()?,
+            });
+        }
+
+        Ok(selected.unwrap()[1].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PoseidonChip, TO_FIELD_TAG, VAR_LEN_TAG};
+    use crate::curves::bn256::Fr;
+    use crate::halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
+    use crate::halo2::ff::{Field, PrimeField};
+    use crate::halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use crate::maingate::mock_prover_verify;
+    use crate::maingate::{MainGate, MainGateConfig, MainGateInstructions, RegionCtx};
+    use paste::paste;
+    use poseidon::{Poseidon, Spec, State};
+    use rand::Rng;
+    use rand_core::OsRng;
+
+    const R_F: usize = 8;
+    const R_P: usize = 57;
+    const DOMAIN: u64 = 0xdead;
+    const NUMBER_OF_OUTPUTS: usize = 3;
+
+    // Off-circuit sponge with tagged capacity. Follows the optimized
+    // permutation of the chip and with zero tag it is checked to be equal to
+    // `poseidon::Poseidon`
+    fn reference_hash<F: PrimeField, const T: usize, const RATE: usize>(
+        spec: &Spec<F, T, RATE>,
+        tag: u64,
+        inputs: &[F],
+        number_of_outputs: usize,
+    ) -> Vec<F> {
+        let sbox = |word: F| word.square().square() * word;
+        let sbox_full = |state: &mut [F; T], constants: &[F; T]| {
+            for (word, constant) in state.iter_mut().zip(constants.iter()) {
+                *word = sbox(*word) + constant;
+            }
+        };
+        let apply_mds = |state: &mut [F; T], mds: &[[F; T]; T]| {
+            let words = *state;
+            for (word, row) in state.iter_mut().zip(mds.iter()) {
+                *word = row
+                    .iter()
+                    .zip(words.iter())
+                    .fold(F::ZERO, |acc, (e, word)| acc + *e * word);
+            }
+        };
+        let permutation = |state: &mut [F; T], inputs: &[F]| {
+            let r_f = spec.r_f() / 2;
+            let mds = spec.mds_matrices().mds().rows();
+            let pre_sparse_mds = spec.mds_matrices().pre_sparse_mds().rows();
+            let constants = spec.constants().start();
+
+            state[0] += constants[0][0];
+            for (i, word) in state.iter_mut().enumerate().skip(1) {
+                *word += constants[0][i];
+                match i.cmp(&(inputs.len() + 1)) {
+                    std::cmp::Ordering::Less => *word += inputs[i - 1],
+                    std::cmp::Ordering::Equal => *word += F::ONE,
+                    std::cmp::Ordering::Greater => {}
+                }
+            }
+            for constants in constants.iter().skip(1).take(r_f - 1) {
+                sbox_full(state, constants);
+                apply_mds(state, &mds);
+            }
+            sbox_full(state, constants.last().unwrap());
+            apply_mds(state, &pre_sparse_mds);
+
+            for (constant, sparse_mds) in spec
+                .constants()
+                .partial()
+                .iter()
+                .zip(spec.mds_matrices().sparse_matrices().iter())
+            {
+                state[0] = sbox(state[0]) + constant;
+                let word_0 = state[0];
+                let first = sparse_mds
+                    .row()
+                    .iter()
+                    .zip(state.iter())
+                    .fold(F::ZERO, |acc, (e, word)| acc + *e * word);
+                for (word, e) in state.iter_mut().skip(1).zip(sparse_mds.col_hat().iter()) {
+                    *word += *e * word_0;
+                }
+                state[0] = first;
+            }
+
+            for constants in spec.constants().end().iter() {
+                sbox_full(state, constants);
+                apply_mds(state, &mds);
+            }
+            sbox_full(state, &[F::ZERO; T]);
+            apply_mds(state, &mds);
+        };
+
+        let mut state: [F; T] = State::<F, T>::default()
+            .words()
+            .to_vec()
+            .try_into()
+            .unwrap();
+        state[0] += F::from(tag);
+        let mut absorbing = inputs.to_vec();
+        (0..number_of_outputs)
+            .map(|_| {
+                let mut padding_offset = 0;
+                for chunk in absorbing.chunks(RATE) {
+                    padding_offset = RATE - chunk.len();
+                    permutation(&mut state, chunk);
+                }
+                if padding_offset == 0 {
+                    permutation(&mut state, &[]);
+                }
+                absorbing.clear();
+                state[1]
+            })
+            .collect()
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+    }
+
+    struct TestCircuit<F: PrimeField, const T: usize, const RATE: usize> {
+        spec: Spec<F, T, RATE>,
+        inputs: Vec<Value<F>>,
+        len: Value<F>,
+        expected_fixed: Value<F>,
+        expected_var: Value<F>,
+        expected_to_field: Vec<Value<F>>,
+    }
+
+    impl<F: PrimeField, const T: usize, const RATE: usize> Circuit<F> for TestCircuit<F, T, RATE> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<F>::new(config.main_gate_config.clone());
+            let poseidon_chip = PoseidonChip::new(&self.spec, &config.main_gate_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let inputs = self
+                        .inputs
+                        .iter()
+                        .map(|input| main_gate.assign_value(ctx, *input))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    let hash = poseidon_chip.hash(ctx, &inputs)?;
+                    let expected = main_gate.assign_value(ctx, self.expected_fixed)?;
+                    main_gate.assert_equal(ctx, &hash, &expected)?;
+
+                    let len = main_gate.assign_value(ctx, self.len)?;
+                    let hash = poseidon_chip.hash_var(ctx, &inputs, &len)?;
+                    let expected = main_gate.assign_value(ctx, self.expected_var)?;
+                    main_gate.assert_equal(ctx, &hash, &expected)?;
+
+                    let outputs = poseidon_chip.hash_to_field(
+                        ctx,
+                        F::from(DOMAIN),
+                        &inputs,
+                        NUMBER_OF_OUTPUTS,
+                    )?;
+                    for (output, expected) in outputs.iter().zip(self.expected_to_field.iter()) {
+                        let expected = main_gate.assign_value(ctx, *expected)?;
+                        main_gate.assert_equal(ctx, output, &expected)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    struct TestCircuitModes<F: PrimeField, const T: usize, const RATE: usize> {
+        spec: Spec<F, T, RATE>,
+        inputs: Vec<Value<F>>,
+    }
+
+    impl<F: PrimeField, const T: usize, const RATE: usize> Circuit<F> for TestCircuitModes<F, T, RATE> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<F>::new(config.main_gate_config.clone());
+            let poseidon_chip = PoseidonChip::new(&self.spec, &config.main_gate_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let len = F::from(self.inputs.len() as u64);
+                    let len_assigned = main_gate.assign_constant(ctx, len)?;
+                    let inputs = self
+                        .inputs
+                        .iter()
+                        .map(|input| main_gate.assign_value(ctx, *input))
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    // Without separation all three would hash `[len] ++ inputs`
+                    let message = std::iter::once(len_assigned.clone())
+                        .chain(inputs.iter().cloned())
+                        .collect::<Vec<_>>();
+                    let fixed = poseidon_chip.hash(ctx, &message)?;
+                    let var = poseidon_chip.hash_var(ctx, &inputs, &len_assigned)?;
+                    let to_field = poseidon_chip.hash_to_field(ctx, len, &inputs, 1)?;
+
+                    main_gate.assert_not_equal(ctx, &fixed, &var)?;
+                    main_gate.assert_not_equal(ctx, &fixed, &to_field[0])?;
+                    main_gate.assert_not_equal(ctx, &var, &to_field[0])?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    macro_rules! test {
+        ($T:expr, $RATE:expr) => {
+            paste! {
+                #[test]
+                fn [<test_poseidon_chip_ $T _ $RATE>]() {
+                    for number_of_inputs in 0..2 * $T {
+                        let spec = Spec::<Fr, $T, $RATE>::new(R_F, R_P);
+                        let inputs: Vec<Fr> = (0..number_of_inputs)
+                            .map(|_| Fr::random(OsRng))
+                            .collect::<Vec<Fr>>();
+
+                        let mut hasher = Poseidon::<Fr, $T, $RATE>::new(R_F, R_P);
+                        hasher.update(&inputs[..]);
+                        let expected_fixed = hasher.squeeze();
+
+                        let mut hasher = Poseidon::<Fr, $T, $RATE>::new(R_F, R_P);
+                        hasher.update(&inputs[..]);
+                        let outputs = (0..NUMBER_OF_OUTPUTS)
+                            .map(|_| hasher.squeeze())
+                            .collect::<Vec<_>>();
+                        assert_eq!(reference_hash(&spec, 0, &inputs, NUMBER_OF_OUTPUTS), outputs);
+
+                        let len = OsRng.gen_range(0..=number_of_inputs);
+                        let message = std::iter::once(Fr::from(len as u64))
+                            .chain(inputs[..len].iter().cloned())
+                            .collect::<Vec<_>>();
+                        let expected_var = reference_hash(&spec, VAR_LEN_TAG, &message, 1)[0];
+
+                        let message = std::iter::once(Fr::from(DOMAIN))
+                            .chain(inputs.iter().cloned())
+                            .collect::<Vec<_>>();
+                        let expected_to_field =
+                            reference_hash(&spec, TO_FIELD_TAG, &message, NUMBER_OF_OUTPUTS)
+                                .into_iter()
+                                .map(Value::known)
+                                .collect();
+
+                        let circuit = TestCircuit::<Fr, $T, $RATE> {
+                            spec,
+                            inputs: inputs.into_iter().map(Value::known).collect(),
+                            len: Value::known(Fr::from(len as u64)),
+                            expected_fixed: Value::known(expected_fixed),
+                            expected_var: Value::known(expected_var),
+                            expected_to_field,
+                        };
+                        let instance = vec![vec![]];
+                        mock_prover_verify(&circuit, instance);
+                    }
+                }
+
+                #[test]
+                fn [<test_poseidon_chip_modes_ $T _ $RATE>]() {
+                    for number_of_inputs in 0..2 * $T {
+                        let spec = Spec::<Fr, $T, $RATE>::new(R_F, R_P);
+                        let inputs = (0..number_of_inputs)
+                            .map(|_| Value::known(Fr::random(OsRng)))
+                            .collect::<Vec<_>>();
+                        let circuit = TestCircuitModes::<Fr, $T, $RATE> { spec, inputs };
+                        let instance = vec![vec![]];
+                        mock_prover_verify(&circuit, instance);
+                    }
+                }
+            }
+        };
+    }
+
+    test!(3, 2);
+    test!(5, 4);
+    test!(9, 8);
+}